@@ -0,0 +1,220 @@
+//! RPC methods of the xode staking pallet.
+//! The responses are built from the `XodeStakingApi` runtime API so that front-ends
+//! can get the staking information in a single call instead of decoding the raw storages.
+
+use std::{marker::PhantomData, sync::Arc};
+
+use jsonrpsee::{
+	core::RpcResult,
+	proc_macros::rpc,
+	types::error::{ErrorObject, ErrorObjectOwned},
+};
+use serde::{Deserialize, Serialize};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_runtime::traits::Block as BlockT;
+
+use pallet_xode_staking::{runtime_api::XodeStakingApi as XodeStakingRuntimeApi, CandidateSummary};
+use xode_runtime::{AccountId, Balance, BlockNumber};
+
+/// Error code of a failed runtime API call.
+const RUNTIME_ERROR: i32 = 1;
+
+/// A ranked proposed candidate.
+///
+/// Balances are encoded as strings since they exceed the JSON safe integer range.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CandidateRanking {
+	/// Position in the sorted proposed candidates, starting at one.
+	pub rank: u32,
+	/// Candidate account.
+	pub who: AccountId,
+	/// Bond of the candidate.
+	pub bond: String,
+	/// Sum of the delegations of the candidate.
+	pub total_stake: String,
+	/// Commission (percent) shared to the delegators.
+	pub commission: u8,
+	/// Status of the candidate (Offline, Online, Waiting, Queuing or Authoring).
+	pub status: String,
+	/// The candidate is offline.
+	pub offline: bool,
+	/// The candidate is leaving.
+	pub leaving: bool,
+	/// Last block authored by the candidate.
+	pub last_authored: BlockNumber,
+}
+
+impl From<CandidateSummary<AccountId, Balance, BlockNumber>> for CandidateRanking {
+	fn from(summary: CandidateSummary<AccountId, Balance, BlockNumber>) -> Self {
+		let info = summary.info;
+		Self {
+			rank: summary.rank,
+			who: info.who,
+			bond: info.bond.to_string(),
+			total_stake: info.total_stake.to_string(),
+			commission: info.commission,
+			status: format!("{:?}", info.status),
+			offline: info.offline,
+			leaving: info.leaving,
+			last_authored: info.last_authored,
+		}
+	}
+}
+
+/// A delegation of an account to a candidate.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DelegationEntry {
+	/// Candidate account.
+	pub candidate: AccountId,
+	/// Stake delegated to the candidate.
+	pub stake: String,
+}
+
+/// All the delegations of an account.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DelegationSummary {
+	/// Delegator account.
+	pub delegator: AccountId,
+	/// Sum of all the delegations.
+	pub total_stake: String,
+	/// Delegations per candidate.
+	pub delegations: Vec<DelegationEntry>,
+}
+
+/// Blocks authored by a collator in a session.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AuthoredBlocksEntry {
+	/// Session index.
+	pub session: u32,
+	/// Number of blocks authored in the session.
+	pub blocks: u32,
+}
+
+/// Blocks authored in a session by a collator.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SessionAuthoredBlocksEntry {
+	/// Collator account.
+	pub who: AccountId,
+	/// Number of blocks authored by the collator.
+	pub blocks: u32,
+}
+
+/// The `xodeStaking` RPC methods.
+#[rpc(server)]
+pub trait XodeStakingApi<BlockHash> {
+	/// Proposed candidates sorted by rank.
+	#[method(name = "xodeStaking_candidates")]
+	fn candidates(&self, at: Option<BlockHash>) -> RpcResult<Vec<CandidateRanking>>;
+
+	/// Candidates waiting for the next session.
+	#[method(name = "xodeStaking_waitingCandidates")]
+	fn waiting_candidates(&self, at: Option<BlockHash>) -> RpcResult<Vec<AccountId>>;
+
+	/// Candidates that have authored in the current session.
+	#[method(name = "xodeStaking_actualAuthors")]
+	fn actual_authors(&self, at: Option<BlockHash>) -> RpcResult<Vec<AccountId>>;
+
+	/// Delegations of an account across all candidates.
+	#[method(name = "xodeStaking_delegations")]
+	fn delegations(&self, delegator: AccountId, at: Option<BlockHash>) -> RpcResult<DelegationSummary>;
+
+	/// Blocks authored by a collator for the sessions kept in the history.
+	#[method(name = "xodeStaking_authoredBlocks")]
+	fn authored_blocks(&self, who: AccountId, at: Option<BlockHash>) -> RpcResult<Vec<AuthoredBlocksEntry>>;
+
+	/// Blocks authored by every collator in a session kept in the history.
+	#[method(name = "xodeStaking_sessionAuthoredBlocks")]
+	fn session_authored_blocks(&self, session: u32, at: Option<BlockHash>) -> RpcResult<Vec<SessionAuthoredBlocksEntry>>;
+}
+
+/// Provides the `xodeStaking` RPC methods.
+pub struct XodeStaking<C, Block> {
+	client: Arc<C>,
+	_marker: PhantomData<Block>,
+}
+
+impl<C, Block> XodeStaking<C, Block> {
+	/// Create a new instance of the `xodeStaking` RPC handler.
+	pub fn new(client: Arc<C>) -> Self {
+		Self { client, _marker: Default::default() }
+	}
+}
+
+impl<C, Block> XodeStakingApiServer<<Block as BlockT>::Hash> for XodeStaking<C, Block>
+where
+	Block: BlockT,
+	C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+	C::Api: XodeStakingRuntimeApi<Block, AccountId, Balance, BlockNumber>,
+{
+	fn candidates(&self, at: Option<Block::Hash>) -> RpcResult<Vec<CandidateRanking>> {
+		let at_hash = at.unwrap_or_else(|| self.client.info().best_hash);
+		let candidates = self.client.runtime_api().candidates(at_hash).map_err(runtime_error_into_rpc_err)?;
+		Ok(candidates.into_iter().map(CandidateRanking::from).collect())
+	}
+
+	fn waiting_candidates(&self, at: Option<Block::Hash>) -> RpcResult<Vec<AccountId>> {
+		let at_hash = at.unwrap_or_else(|| self.client.info().best_hash);
+		self.client.runtime_api().waiting_candidates(at_hash).map_err(runtime_error_into_rpc_err)
+	}
+
+	fn actual_authors(&self, at: Option<Block::Hash>) -> RpcResult<Vec<AccountId>> {
+		let at_hash = at.unwrap_or_else(|| self.client.info().best_hash);
+		self.client.runtime_api().actual_authors(at_hash).map_err(runtime_error_into_rpc_err)
+	}
+
+	fn delegations(&self, delegator: AccountId, at: Option<Block::Hash>) -> RpcResult<DelegationSummary> {
+		let at_hash = at.unwrap_or_else(|| self.client.info().best_hash);
+		let delegations = self
+			.client
+			.runtime_api()
+			.delegations_of(at_hash, delegator.clone())
+			.map_err(runtime_error_into_rpc_err)?;
+
+		let total_stake: Balance = delegations.iter().fold(0, |acc, (_, stake)| acc.saturating_add(*stake));
+		Ok(DelegationSummary {
+			delegator,
+			total_stake: total_stake.to_string(),
+			delegations: delegations
+				.into_iter()
+				.map(|(candidate, stake)| DelegationEntry { candidate, stake: stake.to_string() })
+				.collect(),
+		})
+	}
+
+	fn authored_blocks(&self, who: AccountId, at: Option<Block::Hash>) -> RpcResult<Vec<AuthoredBlocksEntry>> {
+		let at_hash = at.unwrap_or_else(|| self.client.info().best_hash);
+		let authored_blocks = self
+			.client
+			.runtime_api()
+			.authored_blocks_of(at_hash, who)
+			.map_err(runtime_error_into_rpc_err)?;
+		Ok(authored_blocks
+			.into_iter()
+			.map(|(session, blocks)| AuthoredBlocksEntry { session, blocks })
+			.collect())
+	}
+
+	fn session_authored_blocks(&self, session: u32, at: Option<Block::Hash>) -> RpcResult<Vec<SessionAuthoredBlocksEntry>> {
+		let at_hash = at.unwrap_or_else(|| self.client.info().best_hash);
+		let authored_blocks = self
+			.client
+			.runtime_api()
+			.session_authored_blocks(at_hash, session)
+			.map_err(runtime_error_into_rpc_err)?;
+		Ok(authored_blocks
+			.into_iter()
+			.map(|(who, blocks)| SessionAuthoredBlocksEntry { who, blocks })
+			.collect())
+	}
+}
+
+/// Converts a runtime API error into an RPC error.
+fn runtime_error_into_rpc_err(err: impl std::fmt::Debug) -> ErrorObjectOwned {
+	ErrorObject::owned(RUNTIME_ERROR, "Runtime error", Some(format!("{:?}", err)))
+}
@@ -10,12 +10,17 @@ mod benchmarks {
   use super::*;
   #[cfg(test)]
   use crate::pallet::Pallet as XodeStaking;
-  use sp_runtime::traits::Zero;
+  use sp_runtime::{traits::Zero, Perbill, Percent};
   use frame_system::RawOrigin;
   use frame_support:: {
-    traits::Currency,
+    traits::{fungible::{Inspect, Mutate, MutateHold}, EnsureOrigin, Get},
+    weights::Weight,
+    BoundedVec,
     assert_ok
   };
+  use scale_info::prelude::vec;
+  use codec::{Decode, Encode};
+  use sp_runtime::RuntimeAppPublic;
  
   pub(super) fn assert_last_event<T: Config>(generic_event: <T as Config>::RuntimeEvent) {
     frame_system::Pallet::<T>::assert_last_event(generic_event.into());
@@ -23,9 +28,30 @@ mod benchmarks {
 
   pub(super) fn initial_config<T: Config>() {
     let candidate_account: T::AccountId = account("candidate", 0, 0);
-    ProposedCandidates::<T>::try_mutate(|candidates| {
-      candidates.try_push(CandidateInfo {
-        who: candidate_account,
+    ProposedCandidates::<T>::insert(&candidate_account, CandidateInfo {
+      who: candidate_account.clone(),
+      bond: Zero::zero(),
+      total_stake: Zero::zero(),
+      last_updated: frame_system::Pallet::<T>::block_number(),
+      last_authored: frame_system::Pallet::<T>::block_number(),
+      leaving: false,
+      offline: false,
+      commission: 0,
+      status: Status::Online,
+      status_level: 0,
+    });
+    XodeStaking::<T>::sort_proposed_candidate(candidate_account).expect("Failed to add initial candidate");
+    fill_sorted_candidates::<T>();
+  }
+
+  /// Fill the sorted candidates index up to the maximum proposed candidates (worst case of the index
+  /// read and written by the calls), leaving room for the candidates registered by the benchmark.
+  pub(super) fn fill_sorted_candidates<T: Config>() {
+    let count = SortedCandidates::<T>::decode_len().unwrap_or_default() as u32;
+    for i in count..T::MaxProposedCandidates::get().saturating_sub(2) {
+      let candidate_account: T::AccountId = account("filler", i, 0);
+      ProposedCandidates::<T>::insert(&candidate_account, CandidateInfo {
+        who: candidate_account.clone(),
         bond: Zero::zero(),
         total_stake: Zero::zero(),
         last_updated: frame_system::Pallet::<T>::block_number(),
@@ -35,13 +61,14 @@ mod benchmarks {
         commission: 0,
         status: Status::Online,
         status_level: 0,
-      })
-    }).expect("Failed to add initial candidate");
+      });
+      XodeStaking::<T>::sort_proposed_candidate(candidate_account).expect("Failed to add filler candidate");
+    }
   }
 
 	pub(super) fn set_free_balance<T: Config>(receiver: T::AccountId) -> BalanceOf<T> {
 		let balance_to_fund: BalanceOf<T> = create_balance::<T>();
-    T::StakingCurrency::make_free_balance_be(&receiver, balance_to_fund * 2u32.into());
+    T::StakingCurrency::set_balance(&receiver, balance_to_fund * 2u32.into());
 		return balance_to_fund;
 	}
 
@@ -49,8 +76,28 @@ mod benchmarks {
 		return T::StakingCurrency::minimum_balance() * 20_000_000u32.into();
 	}
 
+  /// Fill the delegations of a candidate with the minimum stake so that the next new delegation
+  /// kicks the lowest delegator (worst case).  Returns the total stake of the candidate.
+  pub(super) fn fill_delegations<T: Config>(candidate: T::AccountId) -> BalanceOf<T> {
+    let stake: BalanceOf<T> = T::MinDelegation::get().max(T::StakingCurrency::minimum_balance());
+    let mut total_stake: BalanceOf<T> = Zero::zero();
+    for i in 0..T::MaxProposedCandidateDelegates::get() {
+      let delegator: T::AccountId = account("delegator", i, 0);
+      set_free_balance::<T>(delegator.clone());
+      assert_ok!(XodeStaking::<T>::stake_candidate(
+        RawOrigin::Signed(delegator).into(),
+        candidate.clone(),
+        stake
+      ));
+      total_stake += stake;
+    }
+    assert!(Delegations::<T>::get(&candidate).unwrap().is_full());
+    total_stake - stake
+  }
+
   #[benchmark]
   fn register_candidate() {
+    fill_sorted_candidates::<T>();
     let caller: T::AccountId = whitelisted_caller();
 
     #[extrinsic_call]
@@ -58,7 +105,7 @@ mod benchmarks {
 
     assert_last_event::<T>(
       Event::<T>::ProposedCandidateAdded {
-        _proposed_candidate: caller.clone(),
+        who: caller.clone(),
       }
       .into(),
     );
@@ -82,7 +129,9 @@ mod benchmarks {
 
     assert_last_event::<T>(
       Event::<T>::ProposedCandidateBonded {
-        _proposed_candidate: caller.clone(),
+        who: caller.clone(),
+        old: Zero::zero(),
+        new: new_bond,
       }
       .into(),
     );
@@ -106,7 +155,9 @@ mod benchmarks {
 
     assert_last_event::<T>(
       Event::<T>::ProposedCandidateCommissionSet {
-        _proposed_candidate: caller.clone(),
+        who: caller.clone(),
+        old: 0,
+        new: commission,
       }
       .into(),
     );
@@ -134,7 +185,7 @@ mod benchmarks {
     // log::info!("Last emitted event: {:?}", emitted_event);
     // assert_last_event::<T>(
     //  Event::<T>::WaitingCandidateAdded {
-    //    _waiting_candidate: caller.clone(),
+    //    who: caller.clone(),
     //  }
     //  .into(),
     // );
@@ -146,7 +197,7 @@ mod benchmarks {
 
     assert_last_event::<T>(
       Event::<T>::ProposedCandidateLeft {
-        _proposed_candidate: caller.clone(),
+        who: caller.clone(),
       }
       .into(),
     );
@@ -161,14 +212,20 @@ mod benchmarks {
 
     let amount_to_stake: BalanceOf<T> = set_free_balance::<T>(delegator.clone());
 
-    assert!(ProposedCandidates::<T>::get().iter().any(|c| c.who == candidate));
+    assert!(ProposedCandidates::<T>::contains_key(&candidate));
+
+    // Worst case, the candidate is full and the lowest delegator is kicked
+    let remaining_stake = fill_delegations::<T>(candidate.clone());
 
     #[extrinsic_call]
     _(RawOrigin::Signed(delegator.clone()), candidate.clone(), amount_to_stake);
 
     assert_last_event::<T>(
       Event::<T>::DelegationAdded {
-          _delegator: delegator.clone(),
+        who: delegator.clone(),
+        candidate: candidate.clone(),
+        amount: amount_to_stake,
+        total_stake: remaining_stake + amount_to_stake,
       }
       .into(),
     );
@@ -201,7 +258,10 @@ mod benchmarks {
 
     assert_last_event::<T>(
       Event::<T>::DelegationRevoked {
-        _delegator: delegator.clone(),
+        who: delegator.clone(),
+        candidate: candidate.clone(),
+        amount: amount_to_stake,
+        total_stake: Zero::zero(),
       }
       .into(),
     );
@@ -215,16 +275,14 @@ mod benchmarks {
 
 		set_free_balance::<T>(candidate.clone());
 
-		assert!(ProposedCandidates::<T>::get()
-			.iter()
-			.any(|c| c.who == candidate));
+		assert!(ProposedCandidates::<T>::contains_key(&candidate));
 
 		#[extrinsic_call]
 		_(RawOrigin::Signed(candidate.clone()));
 
 		assert_last_event::<T>(
 			Event::<T>::ProposedCandidateOffline {
-				_proposed_candidate: candidate,
+				who: candidate,
 			}
 			.into(),
 		);
@@ -238,16 +296,20 @@ mod benchmarks {
 
 		set_free_balance::<T>(candidate.clone());
 
-		assert!(ProposedCandidates::<T>::get()
-			.iter()
-			.any(|c| c.who == candidate));
+		assert!(ProposedCandidates::<T>::contains_key(&candidate));
+
+		// Only a candidate bonded above the minimum bond can be online
+		assert_ok!(XodeStaking::<T>::bond_candidate(
+			RawOrigin::Signed(candidate.clone()).into(),
+			create_balance::<T>()
+		));
 
 		#[extrinsic_call]
 		_(RawOrigin::Signed(candidate.clone()));
 
 		assert_last_event::<T>(
 			Event::<T>::ProposedCandidateOnline {
-				_proposed_candidate: candidate,
+				who: candidate,
 			}
 			.into(),
 		);
@@ -264,18 +326,338 @@ mod benchmarks {
       RawOrigin::Signed(caller.clone()).into()
     ));
 
-    let new_bond: BalanceOf<T> = create_balance::<T>();
-
     #[extrinsic_call]
-    _(RawOrigin::Signed(caller.clone()), new_bond);
+    _(RawOrigin::Signed(caller.clone()));
 
     assert_last_event::<T>(
       Event::<T>::ProposedCandidateBondCorrected {
-        _proposed_candidate: caller.clone(),
+        who: caller.clone(),
+        old: Zero::zero(),
+        released: Zero::zero(),
+      }
+      .into(),
+    );
+  }
+
+  #[benchmark]
+  fn withdraw_unbonded() {
+    let caller: T::AccountId = whitelisted_caller();
+    let balance: BalanceOf<T> = set_free_balance::<T>(caller.clone());
+
+    // Worst case, the requests are full, all matured and from different candidates
+    let max_requests = T::MaxUnbondingRequests::get();
+    let request_amount: BalanceOf<T> = balance / max_requests.into();
+    let mut amount: BalanceOf<T> = Zero::zero();
+    for i in 0..max_requests {
+      let (reason, candidate) = if i == 0 {
+        (HoldReason::CandidateBond, caller.clone())
+      } else {
+        (HoldReason::Delegation, account("candidate", i, 0))
+      };
+      assert_ok!(T::StakingCurrency::hold(&reason.into(), &caller, request_amount));
+      assert_ok!(XodeStaking::<T>::schedule_unbonding(caller.clone(), candidate, request_amount));
+      amount += request_amount;
+    }
+    let release_block = UnbondingRequests::<T>::get(&caller)[0].release_block;
+    frame_system::Pallet::<T>::set_block_number(release_block);
+
+    #[extrinsic_call]
+    _(RawOrigin::Signed(caller.clone()));
+
+    assert_last_event::<T>(
+      Event::<T>::UnbondedWithdrawn {
+        who: caller.clone(),
+        amount,
+      }
+      .into(),
+    );
+  }
+
+  #[benchmark]
+  fn add_desired_candidate() -> Result<(), BenchmarkError> {
+    let origin = <T as Config>::UpdateOrigin::try_successful_origin()
+      .map_err(|_| BenchmarkError::Weightless)?;
+    let who: T::AccountId = account("desired", 0, 0);
+
+    #[extrinsic_call]
+    _(origin as T::RuntimeOrigin, who.clone());
+
+    assert_last_event::<T>(Event::<T>::DesiredCandidateAdded { who }.into());
+    Ok(())
+  }
+
+  #[benchmark]
+  fn remove_desired_candidate() -> Result<(), BenchmarkError> {
+    let origin = <T as Config>::UpdateOrigin::try_successful_origin()
+      .map_err(|_| BenchmarkError::Weightless)?;
+    let who: T::AccountId = account("desired", 0, 0);
+    assert_ok!(XodeStaking::<T>::insert_desired_candidate(who.clone()));
+
+    #[extrinsic_call]
+    _(origin as T::RuntimeOrigin, who.clone());
+
+    assert_last_event::<T>(Event::<T>::DesiredCandidateRemoved { who }.into());
+    Ok(())
+  }
+
+  #[benchmark]
+  fn swap_desired_candidate() -> Result<(), BenchmarkError> {
+    let origin = <T as Config>::UpdateOrigin::try_successful_origin()
+      .map_err(|_| BenchmarkError::Weightless)?;
+    let old: T::AccountId = account("desired", 0, 0);
+    let new: T::AccountId = account("desired", 1, 0);
+    assert_ok!(XodeStaking::<T>::insert_desired_candidate(old.clone()));
+
+    #[extrinsic_call]
+    _(origin as T::RuntimeOrigin, old.clone(), new.clone());
+
+    assert_last_event::<T>(Event::<T>::DesiredCandidateSwapped { old, new }.into());
+    Ok(())
+  }
+
+  #[benchmark]
+  fn decrease_delegation() {
+    fill_sorted_candidates::<T>();
+    let delegator: T::AccountId = whitelisted_caller();
+    let candidate: T::AccountId = account("candidate", 1, 0);
+
+    let amount_to_stake: BalanceOf<T> = set_free_balance::<T>(delegator.clone());
+
+    assert_ok!(XodeStaking::<T>::register_candidate(
+      RawOrigin::Signed(candidate.clone()).into()
+    ));
+
+    assert_ok!(XodeStaking::<T>::stake_candidate(
+      RawOrigin::Signed(delegator.clone()).into(),
+      candidate.clone(),
+      amount_to_stake
+    ));
+
+    let amount: BalanceOf<T> = amount_to_stake / 2u32.into();
+
+    #[extrinsic_call]
+    _(RawOrigin::Signed(delegator.clone()), candidate.clone(), amount);
+
+    assert_last_event::<T>(
+      Event::<T>::DelegationDecreased {
+        who: delegator,
+        candidate,
+        old: amount_to_stake,
+        new: amount_to_stake - amount,
+        total_stake: amount_to_stake - amount,
+      }
+      .into(),
+    );
+  }
+
+  #[benchmark]
+  fn redelegate() {
+    fill_sorted_candidates::<T>();
+    let delegator: T::AccountId = whitelisted_caller();
+    let from: T::AccountId = account("candidate", 1, 0);
+    let to: T::AccountId = account("candidate", 2, 0);
+
+    let amount_to_stake: BalanceOf<T> = set_free_balance::<T>(delegator.clone());
+
+    assert_ok!(XodeStaking::<T>::register_candidate(RawOrigin::Signed(from.clone()).into()));
+    assert_ok!(XodeStaking::<T>::register_candidate(RawOrigin::Signed(to.clone()).into()));
+
+    assert_ok!(XodeStaking::<T>::stake_candidate(
+      RawOrigin::Signed(delegator.clone()).into(),
+      from.clone(),
+      amount_to_stake
+    ));
+
+    let amount: BalanceOf<T> = amount_to_stake / 2u32.into();
+
+    // Worst case, the new candidate is full and the lowest delegator is kicked
+    let remaining_stake = fill_delegations::<T>(to.clone());
+
+    #[extrinsic_call]
+    _(RawOrigin::Signed(delegator.clone()), from.clone(), to.clone(), amount);
+
+    assert_last_event::<T>(
+      Event::<T>::Redelegated {
+        who: delegator,
+        from,
+        to,
+        amount,
+        from_total_stake: amount_to_stake - amount,
+        to_total_stake: remaining_stake + amount,
+      }
+      .into(),
+    );
+  }
+
+  #[benchmark]
+  fn release_delegation() {
+    let delegator: T::AccountId = whitelisted_caller();
+    let candidate: T::AccountId = account("candidate", 1, 0);
+
+    let amount_to_stake: BalanceOf<T> = set_free_balance::<T>(delegator.clone());
+
+    assert_ok!(XodeStaking::<T>::register_candidate(
+      RawOrigin::Signed(candidate.clone()).into()
+    ));
+
+    assert_ok!(XodeStaking::<T>::stake_candidate(
+      RawOrigin::Signed(delegator.clone()).into(),
+      candidate.clone(),
+      amount_to_stake
+    ));
+
+    assert_ok!(XodeStaking::<T>::remove_proposed_candidate(candidate.clone()));
+
+    #[block]
+    {
+      XodeStaking::<T>::release_delegations(Weight::MAX);
+    }
+
+    assert_last_event::<T>(
+      Event::<T>::DelegationReleased {
+        who: delegator,
+        candidate,
+        amount: amount_to_stake,
       }
       .into(),
     );
-  }  
+  }
+
+  #[benchmark]
+  fn schedule_commission_change() {
+    let caller: T::AccountId = whitelisted_caller();
+
+    assert_ok!(XodeStaking::<T>::register_candidate(
+      RawOrigin::Signed(caller.clone()).into()
+    ));
+
+    let commission: u8 = 1;
+    let apply_session = pallet_session::CurrentIndex::<T>::get() + T::CommissionChangeDelay::get();
+
+    #[extrinsic_call]
+    _(RawOrigin::Signed(caller.clone()), commission);
+
+    assert_last_event::<T>(
+      Event::<T>::CommissionChangeScheduled {
+        who: caller,
+        old: 0,
+        new: commission,
+        apply_session,
+      }
+      .into(),
+    );
+  }
+
+  #[benchmark]
+  fn set_candidate_metadata() {
+    let caller: T::AccountId = whitelisted_caller();
+    set_free_balance::<T>(caller.clone());
+
+    assert_ok!(XodeStaking::<T>::register_candidate(
+      RawOrigin::Signed(caller.clone()).into()
+    ));
+
+    // Worst case, all the fields are at the maximum length
+    let field: BoundedVec<u8, T::MaxMetadataLength> = vec![b'x'; T::MaxMetadataLength::get() as usize]
+      .try_into()
+      .expect("Field within the maximum length");
+
+    #[extrinsic_call]
+    _(RawOrigin::Signed(caller.clone()), field.clone(), field.clone(), field.clone(), field);
+
+    assert!(CandidateMetadata::<T>::contains_key(&caller));
+  }
+
+  #[benchmark]
+  fn set_inflation() -> Result<(), BenchmarkError> {
+    let origin = <T as Config>::UpdateOrigin::try_successful_origin()
+      .map_err(|_| BenchmarkError::Weightless)?;
+    let annual_inflation = Perbill::from_percent(5);
+    let issuance_cap: BalanceOf<T> = create_balance::<T>();
+
+    #[extrinsic_call]
+    _(origin as T::RuntimeOrigin, annual_inflation, issuance_cap);
+
+    assert_last_event::<T>(Event::<T>::InflationSet { annual_inflation, issuance_cap }.into());
+    Ok(())
+  }
+
+  #[benchmark]
+  fn claim_rewards() {
+    let delegator: T::AccountId = whitelisted_caller();
+    let candidate: T::AccountId = account("candidate", 1, 0);
+
+    let amount_to_stake: BalanceOf<T> = set_free_balance::<T>(delegator.clone());
+    set_free_balance::<T>(candidate.clone());
+
+    assert_ok!(XodeStaking::<T>::register_candidate(
+      RawOrigin::Signed(candidate.clone()).into()
+    ));
+    assert_ok!(XodeStaking::<T>::set_commission_of_candidate(
+      RawOrigin::Signed(candidate.clone()).into(),
+      100
+    ));
+    assert_ok!(XodeStaking::<T>::stake_candidate(
+      RawOrigin::Signed(delegator.clone()).into(),
+      candidate.clone(),
+      amount_to_stake
+    ));
+
+    let reward: BalanceOf<T> = create_balance::<T>();
+    let _ = XodeStaking::<T>::reward_author(candidate.clone(), reward);
+
+    #[extrinsic_call]
+    _(RawOrigin::Signed(delegator.clone()), candidate.clone());
+
+    assert!(PendingRewards::<T>::get(&delegator, &candidate).is_zero());
+  }
+
+  #[benchmark]
+  fn set_auto_compound() {
+    let delegator: T::AccountId = whitelisted_caller();
+    let candidate: T::AccountId = account("candidate", 1, 0);
+
+    let amount_to_stake: BalanceOf<T> = set_free_balance::<T>(delegator.clone());
+    set_free_balance::<T>(candidate.clone());
+
+    assert_ok!(XodeStaking::<T>::register_candidate(
+      RawOrigin::Signed(candidate.clone()).into()
+    ));
+    assert_ok!(XodeStaking::<T>::stake_candidate(
+      RawOrigin::Signed(delegator.clone()).into(),
+      candidate.clone(),
+      amount_to_stake
+    ));
+
+    #[extrinsic_call]
+    _(RawOrigin::Signed(delegator.clone()), candidate.clone(), Percent::from_percent(50));
+
+    let delegations = Delegations::<T>::get(&candidate).unwrap();
+    assert_eq!(delegations[0].auto_compound, Percent::from_percent(50));
+  }
+
+  #[benchmark]
+  fn heartbeat() {
+    let collator: T::AccountId = account("collator", 0, 0);
+    let key = <T as pallet_aura::Config>::AuthorityId::generate_pair(None);
+    pallet_aura::Authorities::<T>::put(BoundedVec::truncate_from(vec![key.clone()]));
+    let validator = <T as pallet_session::Config>::ValidatorId::decode(&mut collator.encode().as_slice())
+      .expect("The validator id is the account id");
+    pallet_session::Validators::<T>::put(vec![validator]);
+
+    let session_index = pallet_session::CurrentIndex::<T>::get();
+    let payload = Heartbeat {
+      block_number: frame_system::Pallet::<T>::block_number(),
+      session_index,
+      authority_index: 0,
+    };
+    let signature = key.sign(&payload.encode()).expect("The key is in the keystore");
+
+    #[extrinsic_call]
+    _(RawOrigin::None, payload, signature);
+
+    assert!(ReceivedHeartbeats::<T>::contains_key(session_index, &collator));
+  }
 
-  impl_benchmark_test_suite!(XodeStaking, crate::mock::new_test_ext(), crate::mock::Test);
+  impl_benchmark_test_suite!(XodeStaking, crate::mock::benchmark_ext(), crate::mock::Test);
 }
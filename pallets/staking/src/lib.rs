@@ -28,6 +28,9 @@
 
 pub use pallet::*;
 
+/// Log target of the pallet
+pub const LOG_TARGET: &str = "runtime::xode-staking";
+
 #[cfg(test)]
 mod mock;
 
@@ -41,6 +44,10 @@ mod tests_audits;
 pub mod weights;
 pub use weights::*;
 
+pub mod migrations;
+
+pub mod runtime_api;
+
 #[cfg(feature = "runtime-benchmarks")]
 mod benchmarking;
 
@@ -50,20 +57,43 @@ pub mod pallet {
 	use frame_support::dispatch::DispatchResult;
 	use frame_support::{dispatch::DispatchResultWithPostInfo, pallet_prelude::*, };
 	use frame_system::pallet_prelude::*;
-	use sp_runtime::traits::Zero;
+	use sp_runtime::traits::{Zero, MaybeSerializeDeserialize};
 	use sp_runtime::Saturating;
 	use scale_info::prelude::vec::Vec;
-	use scale_info::prelude::vec;
-	use hex::decode;
 	use frame_support::PalletId;
+	use frame_support::storage::with_storage_layer;
 	
 	// Sessions
 	use pallet_session::SessionManager;
+	use frame_support::traits::EstimateNextSessionRotation;
 	use sp_staking::SessionIndex;
 
-	use frame_support::traits::{Currency, ReservableCurrency};
+	use frame_support::traits::{
+		OnUnbalanced,
+		fungible::{self, Inspect, InspectHold, Mutate, MutateHold, BalancedHold},
+		tokens::{Precision, Preservation},
+	};
+	use sp_runtime::traits::AccountIdConversion;
+	use sp_runtime::{FixedPointNumber, FixedU128, SaturatedConversion};
+	use sp_runtime::{Perbill, Percent};
+	use core::cmp::Ordering;
+
+	// Heartbeats
+	use sp_runtime::RuntimeAppPublic;
+	use sp_runtime::offchain::storage::StorageValueRef;
+	use frame_system::offchain::{SendTransactionTypes, SubmitTransaction};
 
-	pub type BalanceOf<T> = <<T as Config>::StakingCurrency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+	pub type BalanceOf<T> = <<T as Config>::StakingCurrency as fungible::Inspect<<T as frame_system::Config>::AccountId>>::Balance;
+	pub type CreditOf<T> = fungible::Credit<<T as frame_system::Config>::AccountId, <T as Config>::StakingCurrency>;
+
+	/// The in-code storage version.
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(5);
+
+	/// Offchain storage prefix of the last heartbeat sent per authority index.
+	const HEARTBEAT_STORAGE_PREFIX: &[u8] = b"xode-staking::heartbeat::";
+
+	/// Blocks to wait before resending a heartbeat that has not been received yet.
+	const HEARTBEAT_RESEND_PERIOD: u32 = 10;
 
 	/// Runtime configuration
 	#[pallet::config]
@@ -71,7 +101,8 @@ pub mod pallet {
 		pallet_aura::Config + 
 		pallet_authorship::Config + 
 		pallet_session::Config + 
-		frame_system::Config 
+		frame_system::Config +
+		SendTransactionTypes<Call<Self>>
 	{
 		/// Because this pallet emits events, it depends on the runtime's definition of an event.
 		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
@@ -85,25 +116,107 @@ pub mod pallet {
 		/// The maximum proposed candidate delegates
 		type MaxProposedCandidateDelegates: Get<u32>;
 
-		/// Xaver nodes that will be always present: vec!["",""]
-		type XaverNodes: Get<&'static [&'static str]>;
+		/// The maximum candidates a delegator can stake
+		#[pallet::constant]
+		type MaxDelegationsPerDelegator: Get<u32>;
+
+		/// The minimum stake of a delegation that is not fully un-staked
+		#[pallet::constant]
+		type MinDelegation: Get<BalanceOf<Self>>;
+
+		/// The maximum delegations of removed candidates released per block
+		#[pallet::constant]
+		type MaxDelegationReleasesPerBlock: Get<u32>;
+
+		/// The overarching hold reason.
+		type RuntimeHoldReason: From<HoldReason>;
 
-		/// The staking currency trait.
-		type StakingCurrency: ReservableCurrency<Self::AccountId>;
+		/// The staking currency trait (bonds and delegations are held).
+		type StakingCurrency: fungible::Inspect<Self::AccountId, Balance: MaybeSerializeDeserialize> + 
+			fungible::Mutate<Self::AccountId> + 
+			fungible::MutateHold<Self::AccountId, Reason = Self::RuntimeHoldReason> + 
+			fungible::BalancedHold<Self::AccountId>;
 
 		/// The staking's pallet id, used for deriving its pot account ID.
 		#[pallet::constant]
 		type PalletId: Get<PalletId>;
 
-		/// Use to monitor staling candidate
-		type MaxStalingPeriod: Get<BlockNumberFor<Self>>;
+		/// The maximum slots a collator can miss in a session before it is considered offline
+		#[pallet::constant]
+		type MaxMissedSlots: Get<u32>;
 
 		/// Minimum bond of proposed candidate
 		type MinProposedCandidateBond: Get<BalanceOf<Self>>;
+
+		/// Number of sessions a bond decrease or an un-stake must wait before it can be withdrawn
+		#[pallet::constant]
+		type UnbondingDelay: Get<SessionIndex>;
+
+		/// The maximum pending unbonding requests per account
+		#[pallet::constant]
+		type MaxUnbondingRequests: Get<u32>;
+
+		/// Number of sessions a scheduled commission change must wait before it is applied
+		#[pallet::constant]
+		type CommissionChangeDelay: Get<SessionIndex>;
+
+		/// The maximum commission change (in percent) for every session of the commission change delay
+		#[pallet::constant]
+		type MaxCommissionChangePerSession: Get<u8>;
+
+		/// The fraction of the bond slashed from an offline candidate, multiplied by the number of offences
+		#[pallet::constant]
+		type SlashFraction: Get<Perbill>;
+
+		/// The number of sessions without offences after which the offences of a candidate are reset
+		#[pallet::constant]
+		type SlashOffencesResetPeriod: Get<SessionIndex>;
+
+		/// The base deposit held for storing the metadata of a candidate
+		#[pallet::constant]
+		type MetadataDepositBase: Get<BalanceOf<Self>>;
+
+		/// The additional deposit held per byte of the metadata of a candidate
+		#[pallet::constant]
+		type MetadataDepositPerByte: Get<BalanceOf<Self>>;
+
+		/// The maximum length (in bytes) of each metadata field
+		#[pallet::constant]
+		type MaxMetadataLength: Get<u32>;
+
+		/// Number of blocks in a year, used to derive the block inflation from the annual inflation
+		#[pallet::constant]
+		type BlocksPerYear: Get<u32>;
+
+		/// Number of sessions the authored blocks of the collators are kept
+		#[pallet::constant]
+		type AuthoredBlocksHistoryDepth: Get<SessionIndex>;
+
+		/// Handler for the slashed funds (e.g. the treasury)
+		type OnSlash: OnUnbalanced<CreditOf<Self>>;
+
+		/// Origin that manages the desired candidates (e.g. the technical committee)
+		type UpdateOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// The priority of the unsigned heartbeat transactions
+		#[pallet::constant]
+		type HeartbeatPriority: Get<TransactionPriority>;
 	}
 
 	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T>(_);
+
+	/// A reason for the pallet placing a hold on funds
+	#[pallet::composite_enum]
+	pub enum HoldReason {
+		/// The funds are held as the bond of a proposed candidate
+		CandidateBond,
+		/// The funds are held as a delegation (stake) to a proposed candidate
+		Delegation,
+		/// The funds are held as the storage deposit of the candidate metadata
+		CandidateMetadata,
+	}
 	
 	#[derive(PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug, scale_info::TypeInfo, MaxEncodedLen, PartialOrd)]
 	pub enum Status {
@@ -135,6 +248,13 @@ pub mod pallet {
 		pub status_level: u8,
 	}
 
+	/// Candidate summary (Candidate info with its rank in the sorted proposed candidates)
+	#[derive(PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug, scale_info::TypeInfo)]
+	pub struct CandidateSummary<AccountId, Balance, BlockNumber> {
+		pub rank: u32,
+		pub info: CandidateInfo<AccountId, Balance, BlockNumber>,
+	}
+
 	impl<AccountId, Balance, BlockNumber> Default for CandidateInfo<AccountId, Balance, BlockNumber>
 	where
 		AccountId: Default,
@@ -157,11 +277,36 @@ pub mod pallet {
 		}
 	}
 		
+	/// Candidate score (An entry of the sorted candidates index)
+	/// Note:
+	/// 	The score is the bond plus the total stake of the candidate.  The offline flag and the
+	/// 	last updated block are kept for the ordering (see sort_proposed_candidate).
+	#[derive(PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug, scale_info::TypeInfo, MaxEncodedLen)]
+	pub struct CandidateScore<AccountId, Balance, BlockNumber> {
+		pub who: AccountId,
+		pub score: Balance,
+		pub offline: bool,
+		pub last_updated: BlockNumber,
+	}
+		
 	/// Proposed candidates 
 	#[pallet::storage]
-	pub type ProposedCandidates<T: Config> = StorageValue<
+	pub type ProposedCandidates<T: Config> = StorageMap<
+		_,
+		Twox64Concat,
+		T::AccountId,
+		CandidateInfo<T::AccountId, BalanceOf<T>, BlockNumberFor<T>>,
+		OptionQuery,
+	>;
+
+	/// Sorted candidates (The ordered index of the proposed candidates used for the selection)
+	/// Note:
+	/// 	The index is a single bounded value, it is read and written whole by every call that
+	/// 	re-positions a candidate (bounded by MaxProposedCandidates, see the weights).
+	#[pallet::storage]
+	pub type SortedCandidates<T: Config> = StorageValue<
 		_,
-		BoundedVec<CandidateInfo<T::AccountId, BalanceOf<T>, BlockNumberFor<T>>, T::MaxCandidates>,
+		BoundedVec<CandidateScore<T::AccountId, BalanceOf<T>, BlockNumberFor<T>>, T::MaxProposedCandidates>,
 		ValueQuery,
 	>;
 
@@ -178,6 +323,7 @@ pub mod pallet {
 	pub struct Delegation<AccountId, Balance> {
 		pub delegator: AccountId,
 		pub stake: Balance,
+		pub auto_compound: Percent,
 	}
 
 	/// Delegations
@@ -190,14 +336,339 @@ pub mod pallet {
 		OptionQuery
 	>;
 
+	/// Delegator stake info (The reverse of a delegation)
+	#[derive(PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug, scale_info::TypeInfo, MaxEncodedLen,)]
+	pub struct CandidateStake<AccountId, Balance> {
+		pub candidate: AccountId,
+		pub stake: Balance,
+	}
+
+	/// Delegator state (The candidates staked by a delegator, kept in sync with the delegations)
+	#[pallet::storage]
+	pub type DelegatorState<T: Config> = StorageMap<
+		_,
+		Twox64Concat,
+		T::AccountId,
+		BoundedVec<CandidateStake<T::AccountId, BalanceOf<T>>, T::MaxDelegationsPerDelegator>,
+		ValueQuery
+	>;
+
+	/// Releasing candidates (Removed candidates whose delegations are still to be released, with the block removed)
+	#[pallet::storage]
+	pub type ReleasingCandidates<T: Config> = StorageMap<_, Twox64Concat, T::AccountId, BlockNumberFor<T>, OptionQuery>;
+
+	/// Releasing cursor (The last releasing candidate visited, the next release resumes after it)
+	#[pallet::storage]
+	pub type ReleasingCursor<T: Config> = StorageValue<_, T::AccountId, OptionQuery>;
+
+	/// Unbonding request info
+	/// Note:
+	/// 	1. The candidate is the account where the amount was bonded or staked.  If the candidate
+	/// 	   is the same as the owner of the request, the amount came from a bond decrease.
+	/// 	2. If redelegated_to is set, the amount was redelegated and is still held as the delegation
+	/// 	   to that candidate.  The request only keeps the amount slashable by the previous candidate,
+	/// 	   nothing is released once it matures.
+	#[derive(PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug, scale_info::TypeInfo, MaxEncodedLen,)]
+	pub struct UnbondingRequest<AccountId, Balance, BlockNumber> {
+		pub candidate: AccountId,
+		pub amount: Balance,
+		pub release_block: BlockNumber,
+		pub redelegated_to: Option<AccountId>,
+	}
+
+	/// Unbonding requests (The amounts remain held until withdrawn)
+	#[pallet::storage]
+	pub type UnbondingRequests<T: Config> = StorageMap<
+		_,
+		Twox64Concat,
+		T::AccountId,
+		BoundedVec<UnbondingRequest<T::AccountId, BalanceOf<T>, BlockNumberFor<T>>, T::MaxUnbondingRequests>,
+		ValueQuery
+	>;
+
+	/// Unbonding accounts (The accounts with pending unbonding requests from a candidate)
+	/// Note:
+	/// 	Indexes the unbonding requests by candidate so that slashing a candidate only reads the
+	/// 	requests from that candidate.
+	#[pallet::storage]
+	pub type UnbondingAccounts<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		T::AccountId,
+		Twox64Concat,
+		T::AccountId,
+		(),
+		OptionQuery,
+	>;
+
+	/// Candidate metadata info (Shown by explorers instead of the bare address)
+	#[derive(PartialEqNoBound, EqNoBound, CloneNoBound, Encode, Decode, RuntimeDebugNoBound, scale_info::TypeInfo, MaxEncodedLen,)]
+	#[scale_info(skip_type_params(T))]
+	#[codec(mel_bound())]
+	pub struct CandidateMetadataInfo<T: Config> {
+		pub display_name: BoundedVec<u8, T::MaxMetadataLength>,
+		pub url: BoundedVec<u8, T::MaxMetadataLength>,
+		pub contact: BoundedVec<u8, T::MaxMetadataLength>,
+		pub p2p_address: BoundedVec<u8, T::MaxMetadataLength>,
+		pub deposit: BalanceOf<T>,
+	}
+
+	/// Candidate metadata (Set by the proposed candidates, removed together with the candidate)
+	#[pallet::storage]
+	pub type CandidateMetadata<T: Config> = StorageMap<
+		_,
+		Twox64Concat,
+		T::AccountId,
+		CandidateMetadataInfo<T>,
+		OptionQuery,
+	>;
+
+	/// Reward per stake (The accumulated rewards of the delegators of a candidate per unit of stake)
+	#[pallet::storage]
+	pub type RewardPerStake<T: Config> = StorageMap<_, Twox64Concat, T::AccountId, FixedU128, ValueQuery>;
+
+	/// Reward checkpoints (The reward per stake of a candidate when the stake of a delegator was last settled)
+	#[pallet::storage]
+	pub type RewardCheckpoints<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		T::AccountId,
+		Twox64Concat,
+		T::AccountId,
+		FixedU128,
+		ValueQuery,
+	>;
+
+	/// Pending rewards (The settled rewards of a delegator per candidate, waiting to be claimed)
+	#[pallet::storage]
+	pub type PendingRewards<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		T::AccountId,
+		Twox64Concat,
+		T::AccountId,
+		BalanceOf<T>,
+		ValueQuery,
+	>;
+
+	/// Inflation info
+	/// Note:
+	/// 	1. The annual inflation is a fraction of the total issuance, minted every block in equal parts
+	/// 	   to the author of the block.
+	/// 	2. The mint of a block never exceeds the annual inflation divided by the blocks per year nor
+	/// 	   the remaining room below the issuance cap, hence the total issuance never exceeds the cap.
+	/// 	   Nothing is minted once the total issuance reaches the issuance cap (zero disables the
+	/// 	   inflation).
+	#[derive(PartialEq, Eq, Clone, Default, Encode, Decode, RuntimeDebug, scale_info::TypeInfo, MaxEncodedLen,)]
+	pub struct InflationInfo<Balance> {
+		pub annual_inflation: Perbill,
+		pub issuance_cap: Balance,
+	}
+
+	/// Inflation (Adjusted by the update origin)
+	#[pallet::storage]
+	pub type Inflation<T: Config> = StorageValue<_, InflationInfo<BalanceOf<T>>, ValueQuery>;
+
+	/// Session inflation (Minted in the current session, reported once at the end of the session)
+	#[pallet::storage]
+	pub type SessionInflation<T: Config> = StorageValue<_, BalanceOf<T>, ValueQuery>;
+
+	/// Pending commission info
+	#[derive(PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug, scale_info::TypeInfo, MaxEncodedLen,)]
+	pub struct PendingCommission {
+		pub commission: u8,
+		pub apply_session: SessionIndex,
+	}
+
+	/// Pending commissions (Scheduled commission changes, applied at the end of the apply session)
+	#[pallet::storage]
+	pub type PendingCommissions<T: Config> = StorageMap<_, Twox64Concat, T::AccountId, PendingCommission, OptionQuery>;
+
+	/// Commission change queue (The candidates with a pending commission change, keyed by the apply session)
+	#[pallet::storage]
+	pub type CommissionChangeQueue<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		SessionIndex,
+		Twox64Concat,
+		T::AccountId,
+		(),
+		OptionQuery
+	>;
+
+	/// Slash offences (Number of times a candidate has been slashed, used to escalate the penalty)
+	#[pallet::storage]
+	pub type SlashOffences<T: Config> = StorageMap<_, Twox64Concat, T::AccountId, u32, ValueQuery>;
+
+	/// Last slash session (The session of the last offence of a candidate, used to reset the offences)
+	#[pallet::storage]
+	pub type LastSlashSession<T: Config> = StorageMap<_, Twox64Concat, T::AccountId, SessionIndex, OptionQuery>;
+
 	/// Actual Authors are restarted every session
 	#[pallet::storage]
 	pub type ActualAuthors<T: Config> = StorageValue<_, BoundedVec<T::AccountId, T::MaxCandidates>, ValueQuery>;
-		
-	/// Next block number storage
+
+	/// Compounding candidates (Actual authors of an ended session whose delegations are still to be
+	/// compounded, with the index of the next delegation to compound)
+	#[pallet::storage]
+	pub type CompoundingCandidates<T: Config> = StorageMap<_, Twox64Concat, T::AccountId, u32, OptionQuery>;
+
+	/// Authored blocks per session and collator
+	/// Note:
+	/// 	Sessions older than the history depth are pruned at the end of every session.
+	#[pallet::storage]
+	pub type AuthoredBlocks<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		SessionIndex,
+		Twox64Concat,
+		T::AccountId,
+		u32,
+		ValueQuery
+	>;
+
+	/// Earliest authored session (The first session of the authored blocks history not yet pruned)
+	#[pallet::storage]
+	pub type EarliestAuthoredSession<T: Config> = StorageValue<_, SessionIndex, ValueQuery>;
+
+	/// Last noted slot (The slots between the last noted slot and the current slot were missed)
+	#[pallet::storage]
+	pub type LastSlot<T: Config> = StorageValue<_, u64, ValueQuery>;
+
+	/// Missed slots per session and collator
+	/// Note:
+	/// 	The expected author of a slot follows the Aura round robin (the slot modulo the number of
+	/// 	authorities).  Pruned together with the authored blocks.
+	#[pallet::storage]
+	pub type MissedSlots<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		SessionIndex,
+		Twox64Concat,
+		T::AccountId,
+		u32,
+		ValueQuery
+	>;
+
+	/// Heartbeat info
+	/// Note:
+	/// 	Signed by the collator with its Aura session key.  The authority index is the position of
+	/// 	the key in the Aura authorities, which is also the position of the collator in the session
+	/// 	validators.
+	#[derive(PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug, scale_info::TypeInfo, MaxEncodedLen,)]
+	pub struct Heartbeat<BlockNumber> {
+		pub block_number: BlockNumber,
+		pub session_index: SessionIndex,
+		pub authority_index: u32,
+	}
+
+	/// Received heartbeats per session and collator (The block when the heartbeat was received)
+	/// Note:
+	/// 	The heartbeats of a session are cleared at the end of the session, after slashing.
 	#[pallet::storage]
-    #[pallet::getter(fn next_block_number)]
-    pub type NextBlockNumber<T: Config> = StorageValue<_, BlockNumberFor<T>, OptionQuery>;
+	pub type ReceivedHeartbeats<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		SessionIndex,
+		Twox64Concat,
+		T::AccountId,
+		BlockNumberFor<T>,
+		OptionQuery
+	>;
+
+	/// ==============
+	/// Genesis Config
+	/// ==============
+	#[pallet::genesis_config]
+	#[derive(frame_support::DefaultNoBound)]
+	pub struct GenesisConfig<T: Config> {
+		/// Desired candidates (Xaver nodes), they are wait listed ahead of the proposed candidates
+		pub desired_candidates: Vec<T::AccountId>,
+		/// Pre-registered proposed candidates: (account, bond, commission)
+		pub proposed_candidates: Vec<(T::AccountId, BalanceOf<T>, u8)>,
+		/// Initial delegations: (delegator, candidate, stake)
+		pub delegations: Vec<(T::AccountId, T::AccountId, BalanceOf<T>)>,
+	}
+
+	#[pallet::genesis_build]
+	impl<T: Config> BuildGenesisConfig for GenesisConfig<T> {
+		/// Build the genesis storage
+		/// Note:
+		/// 	1. The desired candidates are added to the desired and waiting candidates.
+		/// 	2. The bond of the proposed candidates and the stake of the delegations are held,
+		/// 	   hence the accounts must be endowed in the balances genesis.
+		/// 	3. Any invalid input panics, the chain specification must be fixed.  The delegations are
+		/// 	   checked the same way as the stake extrinsic (non-zero, minimum delegation and maximum
+		/// 	   delegations of a delegator).
+		fn build(&self) {
+			for desired_candidate in self.desired_candidates.iter() {
+				Pallet::<T>::insert_desired_candidate(desired_candidate.clone())
+					.expect("Genesis desired candidate is duplicated or exceeds the maximum");
+				Pallet::<T>::add_waiting_candidate(desired_candidate.clone())
+					.expect("Genesis desired candidate is duplicated or exceeds the maximum");
+			}
+
+			for (who, bond, commission) in self.proposed_candidates.iter() {
+				assert!(*commission <= 100, "Genesis proposed candidate commission must be from 0 to 100");
+				assert!(
+					bond.is_zero() || *bond > T::MinProposedCandidateBond::get(),
+					"Genesis proposed candidate bond must exceed the minimum bond"
+				);
+				assert!(!ProposedCandidates::<T>::contains_key(who), "Genesis proposed candidate is duplicated");
+
+				T::StakingCurrency::hold(&HoldReason::CandidateBond.into(), who, *bond)
+					.expect("Genesis proposed candidate has insufficient balance for the bond");
+				ProposedCandidates::<T>::insert(who, CandidateInfo {
+					who: who.clone(),
+					bond: *bond,
+					total_stake: Zero::zero(),
+					last_updated: Zero::zero(),
+					last_authored: Zero::zero(),
+					leaving: false,
+					offline: false,
+					commission: *commission,
+					status: Status::Online,
+					status_level: 0,
+				});
+				Pallet::<T>::sort_proposed_candidate(who.clone())
+					.expect("Genesis proposed candidates exceed the maximum");
+			}
+
+			for (delegator, candidate, stake) in self.delegations.iter() {
+				assert!(delegator != candidate, "Genesis delegation to self is not allowed");
+				assert!(
+					ProposedCandidates::<T>::contains_key(candidate),
+					"Genesis delegation candidate is not a proposed candidate"
+				);
+				assert!(!stake.is_zero(), "Genesis delegation stake must not be zero");
+				assert!(
+					Pallet::<T>::delegator_stake(delegator, candidate).saturating_add(*stake) >= T::MinDelegation::get(),
+					"Genesis delegation stake must be at least the minimum delegation"
+				);
+				assert!(
+					!Pallet::<T>::delegator_stake(delegator, candidate).is_zero() ||
+					DelegatorState::<T>::decode_len(delegator).unwrap_or_default() < T::MaxDelegationsPerDelegator::get() as usize,
+					"Genesis delegations exceed the maximum delegations of a delegator"
+				);
+
+				T::StakingCurrency::hold(&HoldReason::Delegation.into(), delegator, *stake)
+					.expect("Genesis delegator has insufficient balance for the stake");
+				let total = Delegations::<T>::try_mutate(candidate, |delegations| {
+					let delegations = delegations.get_or_insert_with(Default::default);
+					if let Some(delegation) = delegations.iter_mut().find(|d| d.delegator == *delegator) {
+						delegation.stake = delegation.stake.saturating_add(*stake);
+						Ok(delegation.stake)
+					} else {
+						delegations.try_push(Delegation { delegator: delegator.clone(), stake: *stake, auto_compound: Percent::zero() }).map(|_| *stake)
+					}
+				}).expect("Genesis delegations exceed the maximum delegations of a candidate");
+				Pallet::<T>::set_delegator_stake(delegator.clone(), candidate.clone(), total)
+					.expect("Genesis delegations exceed the maximum delegations of a delegator");
+				Pallet::<T>::total_stake_proposed_candidate(candidate.clone())
+					.expect("Genesis delegation candidate is not a proposed candidate");
+			}
+		}
+	}
 
 
 	/// ====================
@@ -206,27 +677,55 @@ pub mod pallet {
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	pub enum Event<T: Config> {
-		InvulernableAdded { _invulnerable: T::AccountId, },
-
-		DesiredCandidateAdded { _desired_candidate: T::AccountId, },
-
-		TreasuryAccountRetrieved { _treasury: T::AccountId, _data: T::AccountData, },
-
-		ProposedCandidateAdded  { _proposed_candidate: T::AccountId, },
-		ProposedCandidateBonded  { _proposed_candidate: T::AccountId, },
-		ProposedCandidateLeft  { _proposed_candidate: T::AccountId, },
-		ProposedCandidateRemoved { _proposed_candidate: T::AccountId, },
-		ProposedCandidateTotalStake { _proposed_candidate: T::AccountId, },
-		ProposedCandidateCommissionSet { _proposed_candidate: T::AccountId, },
-		ProposedCandidateOffline { _proposed_candidate: T::AccountId, },
-		ProposedCandidateOnline { _proposed_candidate: T::AccountId, },
-		ProposedCandidateBondCorrected { _proposed_candidate: T::AccountId, },
-
-		WaitingCandidateAdded { _waiting_candidate: T::AccountId, },
-		WaitingCandidateRemoved { _waiting_candidate: T::AccountId, },
-
-		DelegationAdded { _delegator: T::AccountId, },
-		DelegationRevoked { _delegator: T::AccountId, },
+		InvulernableAdded { who: T::AccountId, },
+
+		DesiredCandidateAdded { who: T::AccountId, },
+		DesiredCandidateRemoved { who: T::AccountId, },
+		DesiredCandidateSwapped { old: T::AccountId, new: T::AccountId, },
+
+		TreasuryAccountRetrieved { treasury: T::AccountId, data: T::AccountData, },
+
+		ProposedCandidateAdded { who: T::AccountId, },
+		ProposedCandidateBonded { who: T::AccountId, old: BalanceOf<T>, new: BalanceOf<T>, },
+		ProposedCandidateLeft { who: T::AccountId, },
+		ProposedCandidateRemoved { who: T::AccountId, },
+		ProposedCandidateTotalStake { who: T::AccountId, total_stake: BalanceOf<T>, },
+		ProposedCandidateCommissionSet { who: T::AccountId, old: u8, new: u8, },
+		ProposedCandidateOffline { who: T::AccountId, },
+		ProposedCandidateOnline { who: T::AccountId, },
+		ProposedCandidateBondCorrected { who: T::AccountId, old: BalanceOf<T>, released: BalanceOf<T>, },
+
+		WaitingCandidateAdded { who: T::AccountId, },
+		WaitingCandidateRemoved { who: T::AccountId, },
+
+		DelegationAdded { who: T::AccountId, candidate: T::AccountId, amount: BalanceOf<T>, total_stake: BalanceOf<T>, },
+		DelegationRevoked { who: T::AccountId, candidate: T::AccountId, amount: BalanceOf<T>, total_stake: BalanceOf<T>, },
+		DelegationIncreased { who: T::AccountId, candidate: T::AccountId, old: BalanceOf<T>, new: BalanceOf<T>, total_stake: BalanceOf<T>, },
+		DelegationDecreased { who: T::AccountId, candidate: T::AccountId, old: BalanceOf<T>, new: BalanceOf<T>, total_stake: BalanceOf<T>, },
+		Redelegated { who: T::AccountId, from: T::AccountId, to: T::AccountId, amount: BalanceOf<T>, from_total_stake: BalanceOf<T>, to_total_stake: BalanceOf<T>, },
+		DelegationReleased { who: T::AccountId, candidate: T::AccountId, amount: BalanceOf<T>, },
+		DelegationKicked { who: T::AccountId, candidate: T::AccountId, amount: BalanceOf<T>, },
+		CommissionChangeScheduled { who: T::AccountId, old: u8, new: u8, apply_session: SessionIndex, },
+		CommissionChangeApplied { who: T::AccountId, old: u8, new: u8, },
+		CommissionChangeDiscarded { who: T::AccountId, old: u8, new: u8, },
+		CandidateMetadataSet { who: T::AccountId, deposit: BalanceOf<T>, },
+		CandidateMetadataCleared { who: T::AccountId, deposit: BalanceOf<T>, },
+		InflationSet { annual_inflation: Perbill, issuance_cap: BalanceOf<T>, },
+		InflationMinted { session: SessionIndex, amount: BalanceOf<T>, },
+		RewardsClaimed { who: T::AccountId, candidate: T::AccountId, amount: BalanceOf<T>, },
+		AutoCompoundSet { who: T::AccountId, candidate: T::AccountId, auto_compound: Percent, },
+		DelegationCompounded { who: T::AccountId, candidate: T::AccountId, amount: BalanceOf<T>, },
+
+		UnbondingScheduled { who: T::AccountId, candidate: T::AccountId, amount: BalanceOf<T>, release_block: BlockNumberFor<T>, },
+		UnbondedWithdrawn { who: T::AccountId, amount: BalanceOf<T>, },
+
+		CandidateSlashed { who: T::AccountId, amount: BalanceOf<T>, },
+		DelegatorSlashed { who: T::AccountId, candidate: T::AccountId, amount: BalanceOf<T>, },
+		UnbondingSlashed { who: T::AccountId, candidate: T::AccountId, amount: BalanceOf<T>, },
+
+		SessionRotated { session: SessionIndex, selected: Vec<T::AccountId>, removed: Vec<T::AccountId>, },
+
+		HeartbeatReceived { who: T::AccountId, session: SessionIndex, },
 	}
 
 	/// ======
@@ -240,6 +739,7 @@ pub mod pallet {
 
 		DesiredCandidateAlreadyExist,
 		DesiredCandidateMaxExceeded,
+		DesiredCandidateNotFound,
 
 		CandidateAlreadyExist,
 		CandidateMaxExceeded,
@@ -255,6 +755,10 @@ pub mod pallet {
 		ProposedCandidateStillWaiting,
 		ProposedCandidateStillQueuing,
 		ProposedCandidateInsufficientBond,
+		ProposedCandidateStillReleasing,
+		ProposedCandidateCommissionNotScheduled,
+		ProposedCandidateCommissionChangeExceeded,
+		ProposedCandidateInsufficientDeposit,
 
 		WaitingCandidateAlreadyExist,
 		WaitingCandidateMaxExceeded,
@@ -268,11 +772,26 @@ pub mod pallet {
 		DelegationDelegatorDoesNotExist,
 		DelegationsDoesNotExist,
 		DelegationsMaxExceeded,
+		DelegatorStateMaxExceeded,
+		DelegationInvalidAmount,
+		DelegationInsufficientStake,
+		DelegationBelowMinimum,
+		DelegationSameCandidate,
+
+		RewardsNothingToClaim,
+
+		UnbondingRequestsMaxExceeded,
+		UnbondingRequestsNoneMatured,
 
 		ActualAuthorsAlreadyExist,
 		ActualAuthorsMaxExceeded,
 
 		AuraAuthorityMember,
+
+		HeartbeatInvalidSession,
+		HeartbeatInvalidAuthority,
+		HeartbeatInvalidSignature,
+		HeartbeatAlreadyReceived,
 	}
 
 	/// =====
@@ -281,29 +800,44 @@ pub mod pallet {
 	#[pallet::hooks]
 	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
 		fn on_initialize(_current_block: BlockNumberFor<T>) -> Weight {
-			// Get the author
+			// The missed slots, then the inflation (Inflation and TotalIssuance)
+			let mut weight = Self::note_missed_slots();
+			weight = weight.saturating_add(T::DbWeight::get().reads(2));
+			let reward = Self::block_inflation();
+
+			// Get the author (Author and Digest, the author is cached)
+			weight = weight.saturating_add(T::DbWeight::get().reads_writes(2, 1));
 			if let Some(author) = pallet_authorship::Pallet::<T>::author() {
+				// ProposedCandidates, ActualAuthors, CurrentIndex and AuthoredBlocks
+				weight = weight.saturating_add(T::DbWeight::get().reads_writes(4, 3));
 				let _ = Self::authored_proposed_candidate(author.clone());
 				let _ = Self::add_author(author.clone());
+				Self::note_authored_block(author.clone());
+
+				if !reward.is_zero() {
+					weight = weight.saturating_add(Self::reward_author(author, reward));
+				}
 			}
 
-			// Get the block number
-			match NextBlockNumber::<T>::get() {
-				Some(_next_block) => {
-					// Todo, since this is replaced by session
+			weight
+		}
 
-					// Weights covers authoring
-					T::DbWeight::get().reads_writes(2, 2)
-				}
-				None => {
-					// At block zero
-					Self::add_xaver_nodes();
+		fn on_idle(_current_block: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+			let weight = Self::release_delegations(remaining_weight);
+			weight.saturating_add(Self::compound_rewards(remaining_weight.saturating_sub(weight)))
+		}
 
-					// Weights covers desired and waiting from xaver node setup (14 nodes)
-					T::DbWeight::get().reads_writes(14, 28)
-				}
+		fn offchain_worker(current_block: BlockNumberFor<T>) {
+			// Only the collators send heartbeats
+			if sp_io::offchain::is_validator() {
+				Self::send_heartbeats(current_block);
 			}
 		}
+
+		#[cfg(feature = "try-runtime")]
+		fn try_state(_current_block: BlockNumberFor<T>) -> Result<(), sp_runtime::TryRuntimeError> {
+			Self::do_try_state()
+		}
 	}
 
 	/// ===============
@@ -317,9 +851,10 @@ pub mod pallet {
 		#[pallet::weight(<weights::SubstrateWeight<T> as WeightInfo>::register_candidate())]
 		pub fn register_candidate(origin: OriginFor<T>) -> DispatchResultWithPostInfo {
 			let who = ensure_signed(origin)?;
-            ensure!(!ProposedCandidates::<T>::get().iter().any(|c| c.who == who), Error::<T>::ProposedCandidateAlreadyExist);
+            ensure!(!ProposedCandidates::<T>::contains_key(&who), Error::<T>::ProposedCandidateAlreadyExist);
+            ensure!(!ReleasingCandidates::<T>::contains_key(&who), Error::<T>::ProposedCandidateStillReleasing);
             ensure!(
-                ProposedCandidates::<T>::get().len() < T::MaxProposedCandidates::get() as usize,
+                SortedCandidates::<T>::decode_len().unwrap_or_default() < T::MaxProposedCandidates::get() as usize,
                 Error::<T>::ProposedCandidateMaxExceeded
             );
             let candidate_info = CandidateInfo {
@@ -334,11 +869,9 @@ pub mod pallet {
 				status: Status::Online,	
 				status_level: 0,
             };
-            ProposedCandidates::<T>::try_mutate(|candidates| -> Result<(), DispatchError> {
-                candidates.try_push(candidate_info).map_err(|_| Error::<T>::ProposedCandidateMaxExceeded)?;
-                Ok(())
-            })?;
-			Self::deposit_event(Event::ProposedCandidateAdded { _proposed_candidate: who });
+            ProposedCandidates::<T>::insert(&who, candidate_info);
+            Self::sort_proposed_candidate(who.clone())?;
+			Self::deposit_event(Event::ProposedCandidateAdded { who });
 			
 			Ok(().into())
 		}
@@ -346,17 +879,16 @@ pub mod pallet {
 		/// Bond Proposed Candidate
 		/// Note:
 		/// 	Get the difference of the existing bond then effect the result: zero no change;
-		/// 	if greater than zero, reserve the difference; otherwise unreserve.  Once the bond of 
-		/// 	a candidate is updated, sort immediately the proposed candidates.
-		/// Todo: 
-		/// 	How do we deal with the reserve and unreserve for some reason fails?
+		/// 	if greater than zero, hold the difference; otherwise schedule the difference for
+		/// 	unbonding.  Once the bond of a candidate is updated, sort immediately the proposed
+		/// 	candidates.
 		#[pallet::call_index(1)]
 		#[pallet::weight(<weights::SubstrateWeight<T> as WeightInfo>::bond_candidate())]
 		pub fn bond_candidate(origin: OriginFor<T>, new_bond: BalanceOf<T>,) -> DispatchResultWithPostInfo {
 			let who = ensure_signed(origin)?;
 
 			// Check if the candidate is registered.
-			ensure!(ProposedCandidates::<T>::get().iter().any(|c| c.who == who), Error::<T>::ProposedCandidateNotFound); 
+			let candidate = ProposedCandidates::<T>::get(&who).ok_or(Error::<T>::ProposedCandidateNotFound)?;
 
 			// Minimum bond checked
 			ensure!(
@@ -370,55 +902,58 @@ pub mod pallet {
 				ensure!(!pallet_collator_selection::Invulnerables::<T>::get().contains(&who), Error::<T>::InvulernableMember);
 				ensure!(!Self::still_authoring(who.clone()), Error::<T>::AuraAuthorityMember);
 			} else {
-				let candidates = ProposedCandidates::<T>::get();
-				if let Some(candidate) = candidates.iter().find(|c| c.who == who) {
-					if candidate.bond == Zero::zero() {
-						ensure!(T::StakingCurrency::free_balance(&who) >= new_bond,Error::<T>::ProposedCandidateInsufficientBalance);
-					}
+				if candidate.bond == Zero::zero() {
+					ensure!(T::StakingCurrency::balance(&who) >= new_bond,Error::<T>::ProposedCandidateInsufficientBalance);
 				}
 			}
 			
-			let _ = ProposedCandidates::<T>::mutate(|candidates| {
-				if let Some(candidate) = candidates.iter_mut().find(|c| c.who == who) {
+			ProposedCandidates::<T>::try_mutate(&who, |maybe_candidate| -> DispatchResult {
+				if let Some(candidate) = maybe_candidate {
 
-					// If the current bond is zero the new bond is immediately reserved
+					// If the current bond is zero the new bond is immediately held
 					if candidate.bond == Zero::zero() {
 						candidate.bond = new_bond;
 						candidate.last_updated = frame_system::Pallet::<T>::block_number();
 
-						let _ = T::StakingCurrency::reserve(&who, new_bond);
+						T::StakingCurrency::hold(&HoldReason::CandidateBond.into(), &who, new_bond)
+							.map_err(|_| Error::<T>::ProposedCandidateInsufficientBalance)?;
 
 					} else {
-						// If the current bond exceeds the new bond - unreserve
+						// If the current bond exceeds the new bond - schedule the difference for unbonding
 						if candidate.bond > new_bond {
 							let bond_diff = candidate.bond.saturating_sub(new_bond);
 
 							candidate.bond = new_bond;
 							candidate.last_updated = frame_system::Pallet::<T>::block_number();
 
-							let _ = T::StakingCurrency::unreserve(&who, bond_diff);
+							Self::schedule_unbonding(who.clone(), who.clone(), bond_diff)?;
 						
-						// If the new bond exceeds than the current bond - add to reserve
+						// If the new bond exceeds than the current bond - add to hold
 						} else if new_bond > candidate.bond {
 							let bond_diff = new_bond.saturating_sub(candidate.bond);
 
 							candidate.bond = new_bond;
 							candidate.last_updated = frame_system::Pallet::<T>::block_number();
 
-							let _ = T::StakingCurrency::reserve(&who, bond_diff);
+							T::StakingCurrency::hold(&HoldReason::CandidateBond.into(), &who, bond_diff)
+								.map_err(|_| Error::<T>::ProposedCandidateInsufficientBalance)?;
 						}
 					}
 				}
-			});
+				Ok(())
+			})?;
 
-			Self::sort_proposed_candidates();
-			Self::deposit_event(Event::ProposedCandidateBonded { _proposed_candidate: who });
+			Self::sort_proposed_candidate(who.clone())?;
+			Self::deposit_event(Event::ProposedCandidateBonded { who, old: candidate.bond, new: new_bond });
 			Ok(().into())
 		}
 
 		/// Set commission
 		/// Note:
-		/// 	Numbers accepted are from 1 to 100 and no irrational numbers
+		/// 	1. Numbers accepted are from 1 to 100 and no irrational numbers
+		/// 	2. The commission is the share of the delegators, it takes effect immediately only if 
+		/// 	   it is raised or set for the first time.  A decrease must be scheduled 
+		/// 	   (schedule_commission_change).
 		#[pallet::call_index(2)]
 		#[pallet::weight(<weights::SubstrateWeight<T> as WeightInfo>::set_commission_of_candidate())]
 		pub fn set_commission_of_candidate(origin: OriginFor<T>, commission: u8) -> DispatchResultWithPostInfo {
@@ -426,24 +961,31 @@ pub mod pallet {
 			// Commission control (1-100 percent only)
 			ensure!(commission >= 1 && commission <= 100, Error::<T>::ProposedCandidateInvalidCommission);
 			// Set commission
-			ProposedCandidates::<T>::mutate(|candidates| {
-				if let Some(candidate) = candidates.iter_mut().find(|c| c.who == who) {
-					candidate.commission = commission;
-					candidate.last_updated = frame_system::Pallet::<T>::block_number();
-				}
-			});
-
-			Self::deposit_event(Event::ProposedCandidateCommissionSet { _proposed_candidate: who });
+			let old_commission = ProposedCandidates::<T>::try_mutate(&who, |maybe_candidate| -> Result<u8, DispatchError> {
+				let candidate = maybe_candidate.as_mut().ok_or(Error::<T>::ProposedCandidateNotFound)?;
+				ensure!(
+					candidate.commission.is_zero() || commission >= candidate.commission, 
+					Error::<T>::ProposedCandidateCommissionNotScheduled
+				);
+				let old_commission = candidate.commission;
+				candidate.commission = commission;
+				candidate.last_updated = frame_system::Pallet::<T>::block_number();
+				Ok(old_commission)
+			})?;
+
+			let _ = Self::sort_proposed_candidate(who.clone());
+			Self::deposit_event(Event::ProposedCandidateCommissionSet { who, old: old_commission, new: commission });
 			Ok(().into())
 		}
 
 		/// Stake Proposed Candidate
 		/// Note:
 		/// 	To stake a proposed candidate means to delegate a balance for the candidate.
-		/// 	The balance is reserved.
+		/// 	The balance is held.
+		/// 	The stake of the delegator must be at least the minimum delegation.
+		/// 	If the candidate is full, the lowest delegator is kicked if the new stake is higher.
 		/// 	The stake will remain in the storage even if the candidate leaves.
 		/// Todo:
-		/// 	How to handle the reservation if there is a failure in adding the delegation.
 		/// 	Clean delegations when a candidate leaves to save space.
 		#[pallet::call_index(3)]
 		#[pallet::weight(<weights::SubstrateWeight<T> as WeightInfo>::stake_candidate())]
@@ -452,32 +994,47 @@ pub mod pallet {
 			
 			// Provide some controls
 			ensure!(who != candidate, Error::<T>::DelegationToSelfNotAllowed);
-			ensure!(ProposedCandidates::<T>::get().iter().any(|c| c.who == candidate), Error::<T>::DelegationCandidateDoesNotExist); 
-			ensure!(T::StakingCurrency::free_balance(&who) >= amount, Error::<T>::DelegationInsufficientBalance);
+			ensure!(ProposedCandidates::<T>::contains_key(&candidate), Error::<T>::DelegationCandidateDoesNotExist); 
+			ensure!(T::StakingCurrency::balance(&who) >= amount, Error::<T>::DelegationInsufficientBalance);
 
-			// Reserve the balance before updating the stake amount of the delegator
-			let _ = T::StakingCurrency::reserve(&who, amount);
+			// Hold the balance before updating the stake amount of the delegator
+			T::StakingCurrency::hold(&HoldReason::Delegation.into(), &who, amount)
+				.map_err(|_| Error::<T>::DelegationInsufficientBalance)?;
 
 			// Update delegation stake amount
 			let mut delegations = Delegations::<T>::get(&candidate).unwrap_or_default();
-			if let Some(delegation) = delegations.iter_mut().find(|d| d.delegator == who) {
+			let mut old_stake = None;
+			let stake = if let Some(delegation) = delegations.iter_mut().find(|d| d.delegator == who) {
+				old_stake = Some(delegation.stake);
 				delegation.stake += amount;
+				delegation.stake
 			} else {
-				let _ = delegations.try_push(Delegation { delegator: who.clone(), stake: amount }).map_err(|_| Error::<T>::DelegationsMaxExceeded)?;
-			}
+				if delegations.is_full() {
+					Self::kick_lowest_delegator(&candidate, &mut delegations, amount)?;
+				}
+				let _ = delegations.try_push(Delegation { delegator: who.clone(), stake: amount, auto_compound: Percent::zero() }).map_err(|_| Error::<T>::DelegationsMaxExceeded)?;
+				amount
+			};
+			ensure!(stake >= T::MinDelegation::get(), Error::<T>::DelegationBelowMinimum);
 
-			// Finally, update the storage
+			// Finally, update the storage (including the delegator state)
 			Delegations::<T>::insert(&candidate, delegations);
+			Self::set_delegator_stake(who.clone(), candidate.clone(), stake)?;
 			
 			// Update the proposed candidate total stake amount
-			let _ = Self::total_stake_proposed_candidate(candidate);
-			Self::deposit_event(Event::DelegationAdded { _delegator: who });
+			let total_stake = Self::total_stake_proposed_candidate(candidate.clone()).unwrap_or_default();
+			if let Some(old) = old_stake {
+				Self::deposit_event(Event::DelegationIncreased { who, candidate, old, new: stake, total_stake });
+			} else {
+				Self::deposit_event(Event::DelegationAdded { who, candidate, amount, total_stake });
+			}
 			Ok(().into())
 		}
 
 		/// Un-stake Proposed Candidate
 		/// Note:
-		/// 	Remove first the delegation (stake amount) before un-reserving
+		/// 	Remove first the delegation (stake amount) before scheduling it for unbonding.
+		/// 	The stake remains held until it is withdrawn after the unbonding delay.
 		#[pallet::call_index(4)]
 		#[pallet::weight(<weights::SubstrateWeight<T> as WeightInfo>::unstake_candidate())]
 		pub fn unstake_candidate(origin: OriginFor<T>, candidate: T::AccountId) -> DispatchResultWithPostInfo {
@@ -502,39 +1059,48 @@ pub mod pallet {
 				Delegations::<T>::insert(&candidate, delegations);
 			}
 
-			// Finally, unreserve the balance
-			T::StakingCurrency::unreserve(&who, stake_amount);
+			// Remove the candidate from the delegator state
+			Self::set_delegator_stake(who.clone(), candidate.clone(), Zero::zero())?;
+
+			// Finally, schedule the stake for unbonding
+			Self::schedule_unbonding(who.clone(), candidate.clone(), stake_amount)?;
 
 			// Update the proposed candidate total stake amount
-			let _ = Self::total_stake_proposed_candidate(candidate);
-			Self::deposit_event(Event::DelegationRevoked { _delegator: who });
+			let total_stake = Self::total_stake_proposed_candidate(candidate.clone()).unwrap_or_default();
+			Self::deposit_event(Event::DelegationRevoked { who, candidate, amount: stake_amount, total_stake });
 			Ok(().into())
 		}
 
 		/// Offline Proposed Candidate 
 		/// Note:
 		///		Temporarily leave the candidacy without having to un-bond and un-stake.
-		/// 	The offline status will be reflected only in the next session if the 
-		/// 	candidate is already in the waiting list.
+		/// 	The candidate is removed immediately from the waiting list, the offline status 
+		/// 	will be reflected only in the next session if the candidate is already queuing
+		/// 	or authoring.
 		#[pallet::call_index(5)]
 		#[pallet::weight(<weights::SubstrateWeight<T> as WeightInfo>::offline_candidate())]
 		pub fn offline_candidate(origin: OriginFor<T>,) -> DispatchResultWithPostInfo {
 			let who = ensure_signed(origin)?;
-			let _ = Self::offline_proposed_candidate(who,true);
-			Self::sort_proposed_candidates();
+			let _ = Self::offline_proposed_candidate(who.clone(),true);
+			let _ = Self::remove_waiting_candidate(who.clone());
+			let _ = Self::sort_proposed_candidate(who);
 			Ok(().into())
 		}
 
 		/// Online Proposed Candidate 
 		/// Note:
 		///		Make the candidate online again.
+		/// 	A candidate whose bond was slashed down to the minimum bond must top it up first.
 		/// 	Todo: Check first the status if its already queuing
 		#[pallet::call_index(6)]
 		#[pallet::weight(<weights::SubstrateWeight<T> as WeightInfo>::online_candidate())]
 		pub fn online_candidate(origin: OriginFor<T>,) -> DispatchResultWithPostInfo {
 			let who = ensure_signed(origin)?;
-			let _ = Self::offline_proposed_candidate(who,false);
-			Self::sort_proposed_candidates();
+			if let Some(candidate) = ProposedCandidates::<T>::get(&who) {
+				ensure!(candidate.bond > T::MinProposedCandidateBond::get(), Error::<T>::ProposedCandidateInsufficientBond);
+			}
+			let _ = Self::offline_proposed_candidate(who.clone(),false);
+			let _ = Self::sort_proposed_candidate(who);
 			Ok(().into())
 		}
 
@@ -547,8 +1113,8 @@ pub mod pallet {
 		pub fn leave_candidate(origin: OriginFor<T>,) -> DispatchResultWithPostInfo {
 			let who = ensure_signed(origin)?;
 
-			let _ = ProposedCandidates::<T>::mutate(|candidates| {
-				if let Some(candidate) = candidates.iter_mut().find(|c| c.who == who) {
+			let _ = ProposedCandidates::<T>::mutate(&who, |maybe_candidate| {
+				if let Some(candidate) = maybe_candidate {
 					ensure!(candidate.offline, Error::<T>::ProposedCandidateStillOnline);
 					ensure!(!WaitingCandidates::<T>::get().contains(&candidate.who), Error::<T>::ProposedCandidateStillWaiting);
 					ensure!(!pallet_collator_selection::Invulnerables::<T>::get().contains(&candidate.who), Error::<T>::ProposedCandidateStillQueuing);
@@ -562,15 +1128,17 @@ pub mod pallet {
 				Ok::<(), Error<T>>(())
 			});
 
-			Self::deposit_event(Event::ProposedCandidateLeft { _proposed_candidate: who });
+			let _ = Self::sort_proposed_candidate(who.clone());
+			Self::deposit_event(Event::ProposedCandidateLeft { who });
 			Ok(().into())
 		}
 
 		/// Bond Correction
 		/// Note:
 		/// 	Bond correction can be called when the candidate is still proposing
-		/// 	and it has a pending unreserved balance due to automated removal for
-		/// 	not authoring.  
+		/// 	and it has a pending held balance due to automated removal for
+		/// 	not authoring.  The held bond that is not scheduled for unbonding
+		/// 	is released.
 		/// 
 		/// 	Make sure that the proposed candidate is offline.  If the candidate
 		/// 	has just registered call first the offline_candidate extrinsic before
@@ -582,112 +1150,526 @@ pub mod pallet {
 		/// 	Percentage for the treasury as a slashed fee because to authoring failure
 		#[pallet::call_index(8)]
 		#[pallet::weight(<weights::SubstrateWeight<T> as WeightInfo>::bond_correction())]
-		pub fn bond_correction(origin: OriginFor<T>, frozen_balance: BalanceOf<T>,) -> DispatchResultWithPostInfo {
+		pub fn bond_correction(origin: OriginFor<T>,) -> DispatchResultWithPostInfo {
 			let who = ensure_signed(origin)?;
 
-			let _ = ProposedCandidates::<T>::mutate(|candidates| {
-				if let Some(candidate) = candidates.iter_mut().find(|c| c.who == who) {
+			let mut old_bond = BalanceOf::<T>::default();
+			let mut released = BalanceOf::<T>::default();
+			let _ = ProposedCandidates::<T>::mutate(&who, |maybe_candidate| {
+				if let Some(candidate) = maybe_candidate {
 					ensure!(candidate.offline, Error::<T>::ProposedCandidateStillOnline);
 					ensure!(!WaitingCandidates::<T>::get().contains(&candidate.who), Error::<T>::ProposedCandidateStillWaiting);
 					ensure!(!pallet_collator_selection::Invulnerables::<T>::get().contains(&candidate.who), Error::<T>::ProposedCandidateStillQueuing);
 					ensure!(!Self::still_authoring(candidate.who.clone()), Error::<T>::ProposedCandidateStillAuthoring);
 
-					// Unreserved the entire frozen bond
-					let _ = T::StakingCurrency::unreserve(&who, frozen_balance);
+					// Release the entire frozen bond (excluding the pending unbonding of the bond)
+					let held = T::StakingCurrency::balance_on_hold(&HoldReason::CandidateBond.into(), &who);
+					let unbonding = UnbondingRequests::<T>::get(&who)
+						.iter()
+						.filter(|r| r.candidate == who)
+						.fold(BalanceOf::<T>::default(), |acc, r| acc.saturating_add(r.amount));
+					released = T::StakingCurrency::release(
+						&HoldReason::CandidateBond.into(), 
+						&who, 
+						held.saturating_sub(unbonding), 
+						Precision::BestEffort
+					).unwrap_or_default();
 
 					// Set the bond to zero
+					old_bond = candidate.bond;
 					candidate.bond = Zero::zero();
 					candidate.last_updated = frame_system::Pallet::<T>::block_number();
 				}
 				Ok::<(), Error<T>>(())
 			});
 
-			Self::deposit_event(Event::ProposedCandidateBondCorrected { _proposed_candidate: who });
+			let _ = Self::sort_proposed_candidate(who.clone());
+			Self::deposit_event(Event::ProposedCandidateBondCorrected { who, old: old_bond, released });
 			Ok(().into())
 		}
 
-	}
+		/// Withdraw Unbonded
+		/// Note:
+		/// 	Release all the unbonding requests of the caller whose release block has been
+		/// 	reached.  Requests that are not yet matured are retained.  Bond decreases are
+		/// 	released from the candidate bond hold, un-stakes from the delegation hold.  Matured
+		/// 	redelegations are only removed, their amount remains staked to the new candidate.
+		#[pallet::call_index(9)]
+		#[pallet::weight(<weights::SubstrateWeight<T> as WeightInfo>::withdraw_unbonded())]
+		pub fn withdraw_unbonded(origin: OriginFor<T>,) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			let current_block_number = frame_system::Pallet::<T>::block_number();
+
+			let mut requests = UnbondingRequests::<T>::get(&who);
+			let number_of_requests = requests.len();
+			let mut withdrawn_bond: BalanceOf<T> = Zero::zero();
+			let mut withdrawn_stake: BalanceOf<T> = Zero::zero();
+			let mut withdrawn_candidates: Vec<T::AccountId> = Vec::new();
+			requests.retain(|r| {
+				if r.release_block <= current_block_number {
+					if !withdrawn_candidates.contains(&r.candidate) {
+						withdrawn_candidates.push(r.candidate.clone());
+					}
+					if r.redelegated_to.is_some() {
+						// Still held as the delegation to the new candidate
+					} else if r.candidate == who {
+						withdrawn_bond = withdrawn_bond.saturating_add(r.amount);
+					} else {
+						withdrawn_stake = withdrawn_stake.saturating_add(r.amount);
+					}
+					false
+				} else {
+					true
+				}
+			});
+			ensure!(requests.len() < number_of_requests, Error::<T>::UnbondingRequestsNoneMatured);
 
-	///	 =======
-	/// Helpers
-	/// =======
-	impl<T: Config> Pallet<T> {
-		
-		/// Convert AuthorityId to AccountId
+			// Drop the index of the candidates with no more pending requests
+			for candidate in withdrawn_candidates.iter() {
+				if !requests.iter().any(|r| &r.candidate == candidate) {
+					UnbondingAccounts::<T>::remove(candidate, &who);
+				}
+			}
+
+			// Update the unbonding requests storage
+			if requests.is_empty() {
+				UnbondingRequests::<T>::remove(&who);
+			} else {
+				UnbondingRequests::<T>::insert(&who, requests);
+			}
+
+			// Finally, release the matured amount
+			let released_bond = T::StakingCurrency::release(&HoldReason::CandidateBond.into(), &who, withdrawn_bond, Precision::BestEffort)?;
+			let released_stake = T::StakingCurrency::release(&HoldReason::Delegation.into(), &who, withdrawn_stake, Precision::BestEffort)?;
+
+			Self::deposit_event(Event::UnbondedWithdrawn { who, amount: released_bond.saturating_add(released_stake) });
+			Ok(().into())
+		}
+
+		/// Add a desired candidate (Xaver node)
 		/// Note:
-		/// 	Needed since we use Hex values when we set the Xaver Nodes at runtime
-		pub fn authority_to_account(authority: T::AuthorityId) -> T::AccountId {
-			let authority_bytes = authority.encode();
-			// Panic if not properly configured at runtime
-			let account = <T as frame_system::Config>::AccountId::decode(&mut authority_bytes.as_slice()).unwrap();
-			account
+		/// 	1. Only the update origin (e.g. the technical committee) can add a desired candidate.
+		/// 	2. The desired candidate is wait listed at the next end of session (wait_list_authors)
+		/// 	   ahead of the proposed candidates.
+		#[pallet::call_index(10)]
+		#[pallet::weight(<weights::SubstrateWeight<T> as WeightInfo>::add_desired_candidate())]
+		pub fn add_desired_candidate(origin: OriginFor<T>, who: T::AccountId) -> DispatchResultWithPostInfo {
+			<T as Config>::UpdateOrigin::ensure_origin(origin)?;
+			Self::insert_desired_candidate(who)?;
+			Ok(().into())
 		}
 
-		/// Add an account to pallet_collator_selection invulnerable
-		/// Substrate Reference:
-		/// 	https://github.com/paritytech/polkadot-sdk/blob/stable2409/cumulus/pallets/collator-selection/src/lib.rs#L841
-		pub fn add_invulnerable (invulnerable: T::AccountId) -> DispatchResult {
-			pallet_collator_selection::Invulnerables::<T>::try_mutate(|invulnerables| -> DispatchResult {
-				ensure!(!invulnerables.contains(&invulnerable), Error::<T>::InvulnerableAlreadyExist);
-				invulnerables.try_push(invulnerable.clone()).map_err(|_| Error::<T>::InvulnerableMaxExceeded)?;
-				Self::deposit_event(Event::InvulernableAdded { _invulnerable: invulnerable });
+		/// Remove a desired candidate (Xaver node)
+		/// Note:
+		/// 	1. Only the update origin (e.g. the technical committee) can remove a desired candidate.
+		/// 	2. The desired candidate is no longer wait listed at the next end of session, hence
+		/// 	   it is removed from the authors on the session after.
+		#[pallet::call_index(11)]
+		#[pallet::weight(<weights::SubstrateWeight<T> as WeightInfo>::remove_desired_candidate())]
+		pub fn remove_desired_candidate(origin: OriginFor<T>, who: T::AccountId) -> DispatchResultWithPostInfo {
+			<T as Config>::UpdateOrigin::ensure_origin(origin)?;
+			DesiredCandidates::<T>::try_mutate(|desired_candidates| -> DispatchResult {
+				let pos = desired_candidates.iter().position(|c| c == &who).ok_or(Error::<T>::DesiredCandidateNotFound)?;
+				desired_candidates.remove(pos);
 				Ok(())
-			})
-		}	
+			})?;
+			Self::deposit_event(Event::DesiredCandidateRemoved { who });
+			Ok(().into())
+		}
 
-		/// Add a desired candidate
+		/// Swap a desired candidate (Xaver node) with another account
 		/// Note:
-		/// 	Xaver nodes are automatically added to desired candidates (occupies the first priority).  
-		/// 	The remaining slot will be coming from the proposed candidates.
-		pub fn add_desired_candidate(desired_candidate: T::AccountId) -> DispatchResult {
+		/// 	1. Only the update origin (e.g. the technical committee) can swap a desired candidate.
+		/// 	2. The new desired candidate takes the position (priority) of the old one.
+		/// 	3. Like adding and removing, the swap takes effect at the next end of session.
+		#[pallet::call_index(12)]
+		#[pallet::weight(<weights::SubstrateWeight<T> as WeightInfo>::swap_desired_candidate())]
+		pub fn swap_desired_candidate(origin: OriginFor<T>, old: T::AccountId, new: T::AccountId) -> DispatchResultWithPostInfo {
+			<T as Config>::UpdateOrigin::ensure_origin(origin)?;
 			DesiredCandidates::<T>::try_mutate(|desired_candidates| -> DispatchResult {
-				ensure!(!desired_candidates.contains(&desired_candidate), Error::<T>::DesiredCandidateAlreadyExist);
-				desired_candidates.try_push(desired_candidate.clone()).map_err(|_| Error::<T>::DesiredCandidateMaxExceeded)?;
-				Self::deposit_event(Event::DesiredCandidateAdded { _desired_candidate: desired_candidate });
+				ensure!(!desired_candidates.contains(&new), Error::<T>::DesiredCandidateAlreadyExist);
+				let desired_candidate = desired_candidates.iter_mut().find(|c| **c == old).ok_or(Error::<T>::DesiredCandidateNotFound)?;
+				*desired_candidate = new.clone();
 				Ok(())
-			})
+			})?;
+			Self::deposit_event(Event::DesiredCandidateSwapped { old, new });
+			Ok(().into())
 		}
 
-		/// Add Xaver Nodes to the desired candidates at genesis
+		/// Decrease Delegation
 		/// Note:
-		/// 	The Xaver nodes (desired candidates must have a session keys)
-		pub fn add_xaver_nodes() {
-			for xaver_node in T::XaverNodes::get() {
-				let xaver_node = if xaver_node.starts_with("0x") { &xaver_node[2..] } else { xaver_node };
-				// Panic if runtime not properly configured
-				let decoded_bytes = decode(xaver_node).expect("Invalid hex string");
-				// Panic if runtime not properly configured
-				let authority = T::AuthorityId::decode(&mut decoded_bytes.as_slice()).expect("Error in decoding");
-				let account = Self::authority_to_account(authority);
-				let _ = Self::add_desired_candidate(account.clone());
-				let _ = Self::add_waiting_candidate(account);
+		/// 	1. Un-stake only an amount of the delegation, the decreased amount is scheduled for
+		/// 	   unbonding like unstake_candidate.
+		/// 	2. The remaining stake must be zero or at least the minimum delegation.  A zero
+		/// 	   remaining stake removes the delegation.
+		/// 	3. The total stake of the candidate is re-computed (and sorted).
+		#[pallet::call_index(13)]
+		#[pallet::weight(<weights::SubstrateWeight<T> as WeightInfo>::decrease_delegation())]
+		pub fn decrease_delegation(origin: OriginFor<T>, candidate: T::AccountId, amount: BalanceOf<T>) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			ensure!(!amount.is_zero(), Error::<T>::DelegationInvalidAmount);
+
+			// Extract the delegation of the delegator
+			let mut delegations = Delegations::<T>::get(&candidate).ok_or(Error::<T>::DelegationsDoesNotExist)?;
+			let delegation = delegations.iter_mut().find(|d| d.delegator == who).ok_or(Error::<T>::DelegationDelegatorDoesNotExist)?;
+			ensure!(amount <= delegation.stake, Error::<T>::DelegationInsufficientStake);
+
+			let old_stake = delegation.stake;
+			let new_stake = old_stake.saturating_sub(amount);
+			ensure!(new_stake.is_zero() || new_stake >= T::MinDelegation::get(), Error::<T>::DelegationBelowMinimum);
+			delegation.stake = new_stake;
+
+			// Update the delegation storage (a zero stake removes the delegation)
+			delegations.retain(|d| !d.stake.is_zero());
+			if delegations.is_empty() {
+				Delegations::<T>::remove(&candidate);
+			} else {
+				Delegations::<T>::insert(&candidate, delegations);
 			}
+			Self::set_delegator_stake(who.clone(), candidate.clone(), new_stake)?;
+
+			// Schedule the decreased amount for unbonding
+			Self::schedule_unbonding(who.clone(), candidate.clone(), amount)?;
+
+			// Update the proposed candidate total stake amount
+			let total_stake = Self::total_stake_proposed_candidate(candidate.clone()).unwrap_or_default();
+			Self::deposit_event(Event::DelegationDecreased { who, candidate, old: old_stake, new: new_stake, total_stake });
+			Ok(().into())
 		}
 
-		/// Remove a proposed candidate
+		/// Redelegate
 		/// Note:
-		/// 	This is called upon cleaning of the proposed candidate storage for a candidate who is leaving 
-		/// 	regardless if it has still a bond.  Also called immediately after leaving.
-		pub fn remove_proposed_candidate(proposed_candidate: T::AccountId) -> DispatchResult {
-            ProposedCandidates::<T>::try_mutate(|proposed_candidates| -> DispatchResult {
-                proposed_candidates.retain(|c| c.who != proposed_candidate); 
-                Self::deposit_event(Event::ProposedCandidateRemoved { _proposed_candidate: proposed_candidate });
-                Ok(())
-            })
+		/// 	1. Move an amount of the delegation from one candidate to another in a single call.
+		/// 	2. The held amount is moved as is (never released), no additional funds are needed.  The
+		/// 	   moved amount remains slashable by the previous candidate for the unbonding delay
+		/// 	   (schedule_redelegation).
+		/// 	3. The remaining stake in the previous candidate must be zero or at least the minimum
+		/// 	   delegation, the stake in the new candidate must be at least the minimum delegation.
+		/// 	4. The total stake of both candidates are re-computed then sorted once.
+		#[pallet::call_index(14)]
+		#[pallet::weight(<weights::SubstrateWeight<T> as WeightInfo>::redelegate())]
+		pub fn redelegate(origin: OriginFor<T>, from: T::AccountId, to: T::AccountId, amount: BalanceOf<T>) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			ensure!(!amount.is_zero(), Error::<T>::DelegationInvalidAmount);
+			ensure!(from != to, Error::<T>::DelegationSameCandidate);
+			ensure!(who != to, Error::<T>::DelegationToSelfNotAllowed);
+			ensure!(ProposedCandidates::<T>::contains_key(&to), Error::<T>::DelegationCandidateDoesNotExist);
+
+			// Decrease the delegation to the previous candidate
+			let mut from_delegations = Delegations::<T>::get(&from).ok_or(Error::<T>::DelegationsDoesNotExist)?;
+			let from_delegation = from_delegations.iter_mut().find(|d| d.delegator == who).ok_or(Error::<T>::DelegationDelegatorDoesNotExist)?;
+			ensure!(amount <= from_delegation.stake, Error::<T>::DelegationInsufficientStake);
+			let from_stake = from_delegation.stake.saturating_sub(amount);
+			ensure!(from_stake.is_zero() || from_stake >= T::MinDelegation::get(), Error::<T>::DelegationBelowMinimum);
+			from_delegation.stake = from_stake;
+			let auto_compound = from_delegation.auto_compound;
+
+			from_delegations.retain(|d| !d.stake.is_zero());
+			if from_delegations.is_empty() {
+				Delegations::<T>::remove(&from);
+			} else {
+				Delegations::<T>::insert(&from, from_delegations);
+			}
+			Self::set_delegator_stake(who.clone(), from.clone(), from_stake)?;
+
+			// Keep the moved amount slashable by the previous candidate
+			Self::schedule_redelegation(who.clone(), from.clone(), to.clone(), amount)?;
+
+			// Increase the delegation to the new candidate
+			let mut to_delegations = Delegations::<T>::get(&to).unwrap_or_default();
+			let to_stake = if let Some(delegation) = to_delegations.iter_mut().find(|d| d.delegator == who) {
+				delegation.stake = delegation.stake.saturating_add(amount);
+				delegation.stake
+			} else {
+				if to_delegations.is_full() {
+					Self::kick_lowest_delegator(&to, &mut to_delegations, amount)?;
+				}
+				to_delegations.try_push(Delegation { delegator: who.clone(), stake: amount, auto_compound }).map_err(|_| Error::<T>::DelegationsMaxExceeded)?;
+				amount
+			};
+			ensure!(to_stake >= T::MinDelegation::get(), Error::<T>::DelegationBelowMinimum);
+			Delegations::<T>::insert(&to, to_delegations);
+			Self::set_delegator_stake(who.clone(), to.clone(), to_stake)?;
+
+			// Update the total stake of both candidates, then sort once
+			let from_total_stake = Self::compute_total_stake(from.clone()).unwrap_or_default();
+			let to_total_stake = Self::compute_total_stake(to.clone())?;
+			Self::sort_proposed_candidates(&[from.clone(), to.clone()])?;
+
+			Self::deposit_event(Event::Redelegated { who, from, to, amount, from_total_stake, to_total_stake });
+			Ok(().into())
 		}
 
-		/// Change status of proposed candidate
+		/// Schedule a commission change
 		/// Note:
-		/// 	1. Online - Upon registration and upon online
-		/// 	2. Waiting - Set during a wait listing the author or downgrading through author preparation
-		/// 	3. Queuing - Set during queuing and preparing the authors
-		/// 	4. Authoring - Set during new_session, if the existing status is Authoring and the level
-		///        is still zero, increment it by one.
-		/// 	5. Offline - Manually set or during slashing
+		/// 	1. The new commission is applied at the end of the session after the commission change 
+		/// 	   delay, giving the delegators time to react.
+		/// 	2. The change is limited to the maximum commission change per session multiplied by the
+		/// 	   delay (in sessions).
+		/// 	3. Scheduling again replaces the pending commission change.
+		#[pallet::call_index(15)]
+		#[pallet::weight(<weights::SubstrateWeight<T> as WeightInfo>::schedule_commission_change())]
+		pub fn schedule_commission_change(origin: OriginFor<T>, commission: u8) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			ensure!(commission >= 1 && commission <= 100, Error::<T>::ProposedCandidateInvalidCommission);
+			let candidate = ProposedCandidates::<T>::get(&who).ok_or(Error::<T>::ProposedCandidateNotFound)?;
+
+			let delay = T::CommissionChangeDelay::get();
+			let max_change = (T::MaxCommissionChangePerSession::get() as u32).saturating_mul(delay.max(1));
+			ensure!(candidate.commission.abs_diff(commission) as u32 <= max_change, Error::<T>::ProposedCandidateCommissionChangeExceeded);
+
+			let apply_session = pallet_session::CurrentIndex::<T>::get().saturating_add(delay);
+			if let Some(previous) = PendingCommissions::<T>::get(&who) {
+				CommissionChangeQueue::<T>::remove(previous.apply_session, &who);
+			}
+			PendingCommissions::<T>::insert(&who, PendingCommission { commission, apply_session });
+			CommissionChangeQueue::<T>::insert(apply_session, &who, ());
+
+			Self::deposit_event(Event::CommissionChangeScheduled { 
+				who, 
+				old: candidate.commission, 
+				new: commission, 
+				apply_session, 
+			});
+			Ok(().into())
+		}
+
+		/// Set the candidate metadata
+		/// Note:
+		/// 	1. Only proposed candidates can set their metadata (display name, url, contact and the
+		/// 	   p2p address of the node).
+		/// 	2. A deposit is held for the storage, a base deposit plus a deposit per byte.  Only the
+		/// 	   difference is held or released when the metadata is replaced.
+		/// 	3. The metadata is cleared and the deposit released once the candidate is removed.
+		#[pallet::call_index(16)]
+		#[pallet::weight(<weights::SubstrateWeight<T> as WeightInfo>::set_candidate_metadata())]
+		pub fn set_candidate_metadata(
+			origin: OriginFor<T>, 
+			display_name: BoundedVec<u8, T::MaxMetadataLength>,
+			url: BoundedVec<u8, T::MaxMetadataLength>,
+			contact: BoundedVec<u8, T::MaxMetadataLength>,
+			p2p_address: BoundedVec<u8, T::MaxMetadataLength>,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			ensure!(ProposedCandidates::<T>::contains_key(&who), Error::<T>::ProposedCandidateNotFound);
+
+			let bytes = display_name.len()
+				.saturating_add(url.len())
+				.saturating_add(contact.len())
+				.saturating_add(p2p_address.len()) as u32;
+			let deposit = T::MetadataDepositBase::get()
+				.saturating_add(T::MetadataDepositPerByte::get().saturating_mul(bytes.into()));
+
+			// Hold or release only the difference of the deposit
+			let old_deposit = CandidateMetadata::<T>::get(&who).map(|m| m.deposit).unwrap_or_else(Zero::zero);
+			if deposit > old_deposit {
+				T::StakingCurrency::hold(&HoldReason::CandidateMetadata.into(), &who, deposit.saturating_sub(old_deposit))
+					.map_err(|_| Error::<T>::ProposedCandidateInsufficientDeposit)?;
+			} else if deposit < old_deposit {
+				T::StakingCurrency::release(&HoldReason::CandidateMetadata.into(), &who, old_deposit.saturating_sub(deposit), Precision::BestEffort)?;
+			}
+
+			CandidateMetadata::<T>::insert(&who, CandidateMetadataInfo { display_name, url, contact, p2p_address, deposit });
+
+			Self::deposit_event(Event::CandidateMetadataSet { who, deposit });
+			Ok(().into())
+		}
+
+		/// Set the inflation
+		/// Note:
+		/// 	1. Only the update origin (e.g. the technical committee) can set the inflation.
+		/// 	2. The block inflation is minted to the pot starting the next block, then paid to the
+		/// 	   author and its delegators.
+		#[pallet::call_index(17)]
+		#[pallet::weight(<weights::SubstrateWeight<T> as WeightInfo>::set_inflation())]
+		pub fn set_inflation(origin: OriginFor<T>, annual_inflation: Perbill, issuance_cap: BalanceOf<T>) -> DispatchResultWithPostInfo {
+			<T as Config>::UpdateOrigin::ensure_origin(origin)?;
+			Inflation::<T>::put(InflationInfo { annual_inflation, issuance_cap });
+			Self::deposit_event(Event::InflationSet { annual_inflation, issuance_cap });
+			Ok(().into())
+		}
+
+		/// Claim rewards
+		/// Note:
+		/// 	1. The rewards of the delegators are accumulated per candidate (reward per stake) and kept
+		/// 	   in the pot, a delegator claims its rewards from a candidate whenever it wants.
+		/// 	2. Rewards are still claimable after the delegator un-staked the candidate.
+		#[pallet::call_index(18)]
+		#[pallet::weight(<weights::SubstrateWeight<T> as WeightInfo>::claim_rewards())]
+		pub fn claim_rewards(origin: OriginFor<T>, candidate: T::AccountId) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+
+			let stake = Self::delegator_stake(&who, &candidate);
+			Self::settle_rewards(&who, &candidate, stake);
+			if stake.is_zero() {
+				RewardCheckpoints::<T>::remove(&who, &candidate);
+			}
+
+			let amount = PendingRewards::<T>::take(&who, &candidate);
+			ensure!(!amount.is_zero(), Error::<T>::RewardsNothingToClaim);
+			T::StakingCurrency::transfer(&Self::account_id(), &who, amount, Preservation::Expendable)?;
+
+			Self::deposit_event(Event::RewardsClaimed { who, candidate, amount });
+			Ok(().into())
+		}
+
+		/// Set auto compound
+		/// Note:
+		/// 	1. The percentage of the delegation rewards that is re-staked to the candidate at the end
+		/// 	   of every session the candidate authored, the rest remains claimable.
+		/// 	2. Zero (default) disables auto compounding.
+		#[pallet::call_index(19)]
+		#[pallet::weight(<weights::SubstrateWeight<T> as WeightInfo>::set_auto_compound())]
+		pub fn set_auto_compound(origin: OriginFor<T>, candidate: T::AccountId, auto_compound: Percent) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+
+			Delegations::<T>::try_mutate(&candidate, |maybe_delegations| -> DispatchResult {
+				let delegations = maybe_delegations.as_mut().ok_or(Error::<T>::DelegationsDoesNotExist)?;
+				let delegation = delegations.iter_mut().find(|d| d.delegator == who).ok_or(Error::<T>::DelegationDelegatorDoesNotExist)?;
+				delegation.auto_compound = auto_compound;
+				Ok(())
+			})?;
+
+			Self::deposit_event(Event::AutoCompoundSet { who, candidate, auto_compound });
+			Ok(().into())
+		}
+
+		/// Heartbeat
+		/// Note:
+		/// 	1. Submitted once per session by the offchain worker of a collator as an unsigned
+		/// 	   transaction, signed with the Aura session key of the collator.
+		/// 	2. A collator without authored blocks in the session is not slashed if its heartbeat
+		/// 	   was received (it may simply not have been scheduled).
+		#[pallet::call_index(20)]
+		#[pallet::weight(<weights::SubstrateWeight<T> as WeightInfo>::heartbeat())]
+		pub fn heartbeat(
+			origin: OriginFor<T>, 
+			heartbeat: Heartbeat<BlockNumberFor<T>>, 
+			signature: <<T as pallet_aura::Config>::AuthorityId as RuntimeAppPublic>::Signature,
+		) -> DispatchResultWithPostInfo {
+			ensure_none(origin)?;
+			let who = Self::check_heartbeat(&heartbeat, &signature)?;
+
+			ReceivedHeartbeats::<T>::insert(heartbeat.session_index, &who, frame_system::Pallet::<T>::block_number());
+			Self::deposit_event(Event::HeartbeatReceived { who, session: heartbeat.session_index });
+			Ok(().into())
+		}
+	}
+
+	/// ==================
+	/// Unsigned Validation
+	/// ==================
+	#[pallet::validate_unsigned]
+	impl<T: Config> ValidateUnsigned for Pallet<T> {
+		type Call = Call<T>;
+
+		/// Validate the heartbeats
+		/// Note:
+		/// 	Only one heartbeat per session and authority is kept in the pool, it lives until the
+		/// 	end of the session.
+		fn validate_unsigned(_source: TransactionSource, call: &Self::Call) -> TransactionValidity {
+			if let Call::heartbeat { heartbeat, signature } = call {
+				Self::check_heartbeat(heartbeat, signature).map_err(|error| {
+					if error == DispatchError::from(Error::<T>::HeartbeatInvalidSignature) {
+						InvalidTransaction::BadProof
+					} else {
+						InvalidTransaction::Stale
+					}
+				})?;
+
+				ValidTransaction::with_tag_prefix("XodeStakingHeartbeat")
+					.priority(T::HeartbeatPriority::get())
+					.and_provides((heartbeat.session_index, heartbeat.authority_index))
+					.longevity(
+						<T as pallet_session::Config>::NextSessionRotation::average_session_length()
+							.saturated_into::<u64>()
+					)
+					.propagate(true)
+					.build()
+			} else {
+				InvalidTransaction::Call.into()
+			}
+		}
+	}
+
+	///	 =======
+	/// Helpers
+	/// =======
+	impl<T: Config> Pallet<T> {
+		
+		/// Add an account to pallet_collator_selection invulnerable
+		/// Substrate Reference:
+		/// 	https://github.com/paritytech/polkadot-sdk/blob/stable2409/cumulus/pallets/collator-selection/src/lib.rs#L841
+		pub fn add_invulnerable (invulnerable: T::AccountId) -> DispatchResult {
+			pallet_collator_selection::Invulnerables::<T>::try_mutate(|invulnerables| -> DispatchResult {
+				ensure!(!invulnerables.contains(&invulnerable), Error::<T>::InvulnerableAlreadyExist);
+				invulnerables.try_push(invulnerable.clone()).map_err(|_| Error::<T>::InvulnerableMaxExceeded)?;
+				Self::deposit_event(Event::InvulernableAdded { who: invulnerable });
+				Ok(())
+			})
+		}	
+
+		/// Insert a desired candidate
+		/// Note:
+		/// 	Xaver nodes are added to desired candidates at genesis or through the add_desired_candidate 
+		/// 	extrinsic (occupies the first priority).  The remaining slot will be coming from the proposed 
+		/// 	candidates.
+		pub fn insert_desired_candidate(desired_candidate: T::AccountId) -> DispatchResult {
+			DesiredCandidates::<T>::try_mutate(|desired_candidates| -> DispatchResult {
+				ensure!(!desired_candidates.contains(&desired_candidate), Error::<T>::DesiredCandidateAlreadyExist);
+				desired_candidates.try_push(desired_candidate.clone()).map_err(|_| Error::<T>::DesiredCandidateMaxExceeded)?;
+				Self::deposit_event(Event::DesiredCandidateAdded { who: desired_candidate });
+				Ok(())
+			})
+		}
+
+		/// Remove a proposed candidate
+		/// Note:
+		/// 	This is called upon cleaning of the proposed candidate storage for a candidate who is leaving 
+		/// 	regardless if it has still a bond.  Also called immediately after leaving.  The remaining
+		/// 	bond is scheduled for unbonding.  If the candidate still has delegations, it is marked for
+		/// 	releasing (done lazily on idle).  If the bond can not be scheduled for unbonding, the
+		/// 	candidate is not removed (it is retried on the next session).
+		pub fn remove_proposed_candidate(proposed_candidate: T::AccountId) -> DispatchResult {
+            if let Some(candidate) = ProposedCandidates::<T>::get(&proposed_candidate) {
+                if !candidate.bond.is_zero() {
+                    Self::schedule_unbonding(proposed_candidate.clone(), proposed_candidate.clone(), candidate.bond)?;
+                }
+                ProposedCandidates::<T>::remove(&proposed_candidate);
+            }
+            SortedCandidates::<T>::mutate(|sorted_candidates| {
+                if let Some(position) = sorted_candidates.iter().position(|c| c.who == proposed_candidate) {
+                    sorted_candidates.remove(position);
+                }
+            });
+            if Delegations::<T>::contains_key(&proposed_candidate) {
+                ReleasingCandidates::<T>::insert(&proposed_candidate, frame_system::Pallet::<T>::block_number());
+            } else {
+                Self::clear_rewards_of_candidate(&proposed_candidate);
+            }
+            if let Some(pending) = PendingCommissions::<T>::take(&proposed_candidate) {
+                CommissionChangeQueue::<T>::remove(pending.apply_session, &proposed_candidate);
+            }
+            if let Some(metadata) = CandidateMetadata::<T>::take(&proposed_candidate) {
+                let _ = T::StakingCurrency::release(&HoldReason::CandidateMetadata.into(), &proposed_candidate, metadata.deposit, Precision::BestEffort);
+                Self::deposit_event(Event::CandidateMetadataCleared { who: proposed_candidate.clone(), deposit: metadata.deposit });
+            }
+            Self::deposit_event(Event::ProposedCandidateRemoved { who: proposed_candidate });
+            Ok(())
+		}
+
+		/// Change status of proposed candidate
+		/// Note:
+		/// 	1. Online - Upon registration and upon online
+		/// 	2. Waiting - Set during a wait listing the author or downgrading through author preparation
+		/// 	3. Queuing - Set during queuing and preparing the authors
+		/// 	4. Authoring - Set during new_session, if the existing status is Authoring and the level
+		///        is still zero, increment it by one.
+		/// 	5. Offline - Manually set or during slashing
 		/// 	6. Leaving is not a status, it is an event triggered by an extrinsic
 		pub fn status_proposed_candidate(proposed_candidate: T::AccountId, status: Status) -> DispatchResult {
-			ProposedCandidates::<T>::mutate(|candidates| {
-				if let Some(candidate) = candidates.iter_mut().find(|c| c.who == proposed_candidate) {
+			ProposedCandidates::<T>::mutate(&proposed_candidate, |maybe_candidate| {
+				if let Some(candidate) = maybe_candidate {
 					if status == Status::Authoring {
 						if candidate.status == Status::Authoring && 
 						   candidate.status_level == 0 {
@@ -712,8 +1694,8 @@ pub mod pallet {
 		/// 	1. This is helper function is called every hook initialization. Hence, we get
 		/// 	   the current block.
 		pub fn authored_proposed_candidate(proposed_candidate: T::AccountId) -> DispatchResult {
-			ProposedCandidates::<T>::mutate(|candidates| {
-				if let Some(candidate) = candidates.iter_mut().find(|c| c.who == proposed_candidate) {
+			ProposedCandidates::<T>::mutate(&proposed_candidate, |maybe_candidate| {
+				if let Some(candidate) = maybe_candidate {
 					candidate.last_authored = frame_system::Pallet::<T>::block_number();
 				}
 			});
@@ -723,16 +1705,16 @@ pub mod pallet {
 		/// Go offline/online proposed candidates
 		/// Note:
 		pub fn offline_proposed_candidate(proposed_candidate: T::AccountId, offline: bool) -> DispatchResult {
-			ProposedCandidates::<T>::mutate(|candidates| {
-				if let Some(candidate) = candidates.iter_mut().find(|c| c.who == proposed_candidate) {
+			ProposedCandidates::<T>::mutate(&proposed_candidate, |maybe_candidate| {
+				if let Some(candidate) = maybe_candidate {
 					candidate.offline = offline;
 					candidate.last_updated = frame_system::Pallet::<T>::block_number();
 				}
 			});
 			if offline {
-				Self::deposit_event(Event::ProposedCandidateOffline { _proposed_candidate: proposed_candidate });
+				Self::deposit_event(Event::ProposedCandidateOffline { who: proposed_candidate });
 			} else {
-				Self::deposit_event(Event::ProposedCandidateOnline { _proposed_candidate: proposed_candidate });
+				Self::deposit_event(Event::ProposedCandidateOnline { who: proposed_candidate });
 			}
 			Ok(().into())
 		}
@@ -745,14 +1727,14 @@ pub mod pallet {
 			WaitingCandidates::<T>::try_mutate(|waiting_candidates| -> DispatchResult {
 				ensure!(!waiting_candidates.contains(&waiting_candidate), Error::<T>::WaitingCandidateAlreadyExist);
 				waiting_candidates.try_push(waiting_candidate.clone()).map_err(|_| Error::<T>::WaitingCandidateMaxExceeded)?;
-				Self::deposit_event(Event::WaitingCandidateAdded { _waiting_candidate: waiting_candidate });
+				Self::deposit_event(Event::WaitingCandidateAdded { who: waiting_candidate });
 				Ok(())
 			})
 		}
 
 		/// Remove a waiting candidate
 		/// Note:	
-		/// 	This is called when the candidate wants to leave.
+		/// 	This is called when the candidate wants to leave or goes offline.
 		pub fn remove_waiting_candidate(waiting_candidate: T::AccountId) -> DispatchResult {
 			WaitingCandidates::<T>::try_mutate(|waiting_candidates| -> DispatchResult {
 				ensure!(waiting_candidates.contains(&waiting_candidate), Error::<T>::WaitingCandidateNotFound);
@@ -761,54 +1743,485 @@ pub mod pallet {
 				} else {
 					return Err(Error::<T>::WaitingCandidateNotFound.into());
 				}
-				Self::deposit_event(Event::WaitingCandidateRemoved { _waiting_candidate: waiting_candidate });
+				Self::deposit_event(Event::WaitingCandidateRemoved { who: waiting_candidate });
 				Ok(())
 			})
 		}
 
-		/// Sort proposed candidates:
+		/// Proposed candidates
+		/// Note:
+		/// 	Returns the candidate information following the order of the sorted candidates index.
+		pub fn proposed_candidates() -> Vec<CandidateInfo<T::AccountId, BalanceOf<T>, BlockNumberFor<T>>> {
+			SortedCandidates::<T>::get()
+				.iter()
+				.filter_map(|c| ProposedCandidates::<T>::get(&c.who))
+				.collect()
+		}
+
+		/// Sort a proposed candidate:
 		/// Note:	
-		/// 	Prioritize total_stake first, then bond, then oldest last_updated
-		/// 	True = -1, False = 0
-		pub fn sort_proposed_candidates() {
-            let mut proposed_candidates = ProposedCandidates::<T>::get();
-            proposed_candidates.sort_by(|a, b| {
-                a.offline.cmp(&b.offline)
-					.then_with(|| {
-						let a_combined = a.bond + a.total_stake;
-						let b_combined = b.bond + b.total_stake;
-						b_combined.cmp(&a_combined)
-					})
-                    .then_with(|| a.last_updated.cmp(&b.last_updated)) 
-            });
-            ProposedCandidates::<T>::put(proposed_candidates);
-        }
+		/// 	1. Only the entry of the given candidate is re-positioned in the sorted candidates index, 
+		/// 	   the order of the other entries is retained.  The entry is removed and inserted back
+		/// 	   at the position found by a binary search, the index is not re-sorted.
+		/// 	2. Online first, then the highest score (bond + total_stake), then oldest last_updated.
+		/// 	   True = -1, False = 0
+		/// 	3. Entries with the same ordering keep their insertion order.
+		pub fn sort_proposed_candidate(proposed_candidate: T::AccountId) -> DispatchResult {
+			Self::sort_proposed_candidates(&[proposed_candidate])
+		}
+
+		/// Sort several proposed candidates:
+		/// Note:
+		/// 	Same as sort_proposed_candidate but the sorted candidates index is read and written once.
+		pub fn sort_proposed_candidates(proposed_candidates: &[T::AccountId]) -> DispatchResult {
+			SortedCandidates::<T>::try_mutate(|sorted_candidates| -> DispatchResult {
+				for proposed_candidate in proposed_candidates.iter() {
+					if let Some(position) = sorted_candidates.iter().position(|c| c.who == *proposed_candidate) {
+						sorted_candidates.remove(position);
+					}
+					if let Some(candidate) = ProposedCandidates::<T>::get(proposed_candidate) {
+						let entry = CandidateScore {
+							who: candidate.who,
+							score: candidate.bond.saturating_add(candidate.total_stake),
+							offline: candidate.offline,
+							last_updated: candidate.last_updated,
+						};
+						let position = sorted_candidates.partition_point(|c| {
+							c.offline.cmp(&entry.offline)
+								.then_with(|| entry.score.cmp(&c.score))
+								.then_with(|| c.last_updated.cmp(&entry.last_updated)) != Ordering::Greater
+						});
+						sorted_candidates.try_insert(position, entry).map_err(|_| Error::<T>::ProposedCandidateMaxExceeded)?;
+					}
+				}
+				Ok(())
+			})
+		}
 
 		/// Compute total_stake in the candidate information
 		/// Note:
 		/// 	Re-compute the total stake and called every staking extrinsic.
 		/// 	Once the total is completed immediately sort the proposed candidates.
-		pub fn total_stake_proposed_candidate(proposed_candidate: T::AccountId) -> DispatchResult {
-			let mut proposed_candidates = ProposedCandidates::<T>::get();
-			let mut found = false;
-			for i in 0..proposed_candidates.len() {
-				if proposed_candidates[i].who == proposed_candidate {
-					let total_stake = if let Some(delegations) = <Delegations<T>>::get(proposed_candidate.clone()) {
-						delegations.iter().fold(BalanceOf::<T>::default(), |acc, delegation| acc + delegation.stake)
-					} else {
-						BalanceOf::<T>::default()
-					};
-					proposed_candidates[i].total_stake = total_stake;
-					proposed_candidates[i].last_updated = frame_system::Pallet::<T>::block_number();
-					found = true;
+		/// 	Returns the new total stake.
+		pub fn total_stake_proposed_candidate(proposed_candidate: T::AccountId) -> Result<BalanceOf<T>, DispatchError> {
+			let total_stake = Self::compute_total_stake(proposed_candidate.clone())?;
+			Self::sort_proposed_candidate(proposed_candidate.clone())?;
+			Self::deposit_event(Event::ProposedCandidateTotalStake { who: proposed_candidate, total_stake });
+			Ok(total_stake)
+		}
+
+		/// Compute total_stake without sorting
+		/// Note:
+		/// 	The caller is responsible for sorting the proposed candidate afterwards.
+		/// 	Returns the new total stake.
+		pub fn compute_total_stake(proposed_candidate: T::AccountId) -> Result<BalanceOf<T>, DispatchError> {
+			ProposedCandidates::<T>::try_mutate(&proposed_candidate, |maybe_candidate| -> Result<BalanceOf<T>, DispatchError> {
+				let candidate = maybe_candidate.as_mut().ok_or(Error::<T>::ProposedCandidateNotFound)?;
+				let total_stake = if let Some(delegations) = <Delegations<T>>::get(proposed_candidate.clone()) {
+					delegations.iter().fold(BalanceOf::<T>::default(), |acc, delegation| acc + delegation.stake)
+				} else {
+					BalanceOf::<T>::default()
+				};
+				candidate.total_stake = total_stake;
+				candidate.last_updated = frame_system::Pallet::<T>::block_number();
+				Ok(total_stake)
+			})
+		}
+
+		/// Set the stake of a delegator to a candidate in the delegator state
+		/// Note:
+		/// 	1. A zero stake removes the candidate from the delegator state.
+		/// 	2. A new candidate is limited by MaxDelegationsPerDelegator.
+		/// 	3. The rewards of the current stake are settled first (every stake change goes through here).
+		pub fn set_delegator_stake(delegator: T::AccountId, candidate: T::AccountId, stake: BalanceOf<T>) -> DispatchResult {
+			DelegatorState::<T>::try_mutate_exists(&delegator, |maybe_state| -> DispatchResult {
+				let mut state = maybe_state.take().unwrap_or_default();
+
+				// Settle the rewards of the current stake before it changes
+				let current_stake = state.iter()
+					.find(|c| c.candidate == candidate)
+					.map(|c| c.stake)
+					.unwrap_or_else(Zero::zero);
+				Self::settle_rewards(&delegator, &candidate, current_stake);
+
+				if stake.is_zero() {
+					RewardCheckpoints::<T>::remove(&delegator, &candidate);
+					state.retain(|c| c.candidate != candidate);
+				} else if let Some(candidate_stake) = state.iter_mut().find(|c| c.candidate == candidate) {
+					candidate_stake.stake = stake;
+				} else {
+					state.try_push(CandidateStake { candidate, stake }).map_err(|_| Error::<T>::DelegatorStateMaxExceeded)?;
+				}
+				if !state.is_empty() {
+					*maybe_state = Some(state);
+				}
+				Ok(())
+			})
+		}
+
+		/// Schedule an unbonding request
+		/// Note:
+		/// 	The amount is not released, it stays held until the release block so that
+		/// 	slashing can still reach it.  The release block is estimated from the average 
+		/// 	session length multiplied by the unbonding delay (in sessions).  If the unbonding
+		/// 	requests are full, the amount is merged with a pending request from the same candidate.
+		pub fn schedule_unbonding(who: T::AccountId, candidate: T::AccountId, amount: BalanceOf<T>) -> DispatchResult {
+			let release_block = Self::insert_unbonding_request(&who, &candidate, amount, None)?;
+			Self::deposit_event(Event::UnbondingScheduled { who, candidate, amount, release_block });
+			Ok(())
+		}
+
+		/// Schedule a redelegation
+		/// Note:
+		/// 	The redelegated amount stays held as the delegation to the new candidate, the request
+		/// 	only keeps it slashable by the previous candidate until the release block.
+		pub fn schedule_redelegation(who: T::AccountId, from: T::AccountId, to: T::AccountId, amount: BalanceOf<T>) -> DispatchResult {
+			Self::insert_unbonding_request(&who, &from, amount, Some(to))?;
+			Ok(())
+		}
+
+		/// Insert an unbonding request
+		/// Note:
+		/// 	1. The release block is estimated from the average session length multiplied by the
+		/// 	   unbonding delay (in sessions).  Returns the release block.
+		/// 	2. If the unbonding requests are full, the amount is merged with a pending request of
+		/// 	   the same candidate (and redelegation), the merged request waits for the later release
+		/// 	   block.  Fails if there is no such request.
+		fn insert_unbonding_request(
+			who: &T::AccountId,
+			candidate: &T::AccountId,
+			amount: BalanceOf<T>,
+			redelegated_to: Option<T::AccountId>,
+		) -> Result<BlockNumberFor<T>, DispatchError> {
+			let delay = <T as pallet_session::Config>::NextSessionRotation::average_session_length()
+				.saturating_mul(BlockNumberFor::<T>::from(T::UnbondingDelay::get()));
+			let release_block = frame_system::Pallet::<T>::block_number().saturating_add(delay);
+
+			UnbondingRequests::<T>::try_mutate(who, |requests| -> DispatchResult {
+				let request = UnbondingRequest { candidate: candidate.clone(), amount, release_block, redelegated_to };
+				if let Err(request) = requests.try_push(request) {
+					let pending = requests.iter_mut()
+						.find(|r| r.candidate == request.candidate && r.redelegated_to == request.redelegated_to)
+						.ok_or(Error::<T>::UnbondingRequestsMaxExceeded)?;
+					pending.amount = pending.amount.saturating_add(request.amount);
+					pending.release_block = pending.release_block.max(request.release_block);
+				}
+				Ok(())
+			})?;
+			UnbondingAccounts::<T>::insert(candidate, who, ());
+			Ok(release_block)
+		}
+
+		/// Kick the lowest delegator of a full candidate
+		/// Note:
+		/// 	1. The new stake must be higher than the lowest delegation, otherwise the candidate
+		/// 	   remains full.
+		/// 	2. The kicked stake is scheduled for unbonding like an un-stake, the kick fails if it can
+		/// 	   not be scheduled (the stake is never released immediately).
+		/// 	3. The caller is responsible for storing the delegations and the total stake.
+		pub fn kick_lowest_delegator(
+			candidate: &T::AccountId, 
+			delegations: &mut BoundedVec<Delegation<T::AccountId, BalanceOf<T>>, T::MaxProposedCandidateDelegates>, 
+			stake: BalanceOf<T>,
+		) -> DispatchResult {
+			let position = delegations.iter()
+				.enumerate()
+				.min_by_key(|(_, d)| d.stake)
+				.map(|(position, _)| position)
+				.ok_or(Error::<T>::DelegationsMaxExceeded)?;
+			ensure!(stake > delegations[position].stake, Error::<T>::DelegationsMaxExceeded);
+			let kicked = delegations.remove(position);
+
+			Self::set_delegator_stake(kicked.delegator.clone(), candidate.clone(), Zero::zero())?;
+			Self::schedule_unbonding(kicked.delegator.clone(), candidate.clone(), kicked.stake)?;
+
+			Self::deposit_event(Event::DelegationKicked { who: kicked.delegator, candidate: candidate.clone(), amount: kicked.stake });
+			Ok(())
+		}
+
+		/// Release the delegations of removed candidates
+		/// Note:
+		/// 	1. Called on idle, the delegations are released one at a time and whatever does not fit in
+		/// 	   the remaining weight (or the maximum releases per block) is carried over to the next block.
+		/// 	2. The stake is scheduled for unbonding like an un-stake so that a pending slash can still
+		/// 	   reach it.  If it can not be scheduled, the delegation is kept (still held) and retried
+		/// 	   on a later block, once the delegator has withdrawn its matured requests.  A failed
+		/// 	   release does not count toward the maximum releases per block.
+		/// 	3. The releasing candidates are visited in turn from the releasing cursor, each at most once
+		/// 	   per block, so that a candidate with kept delegations does not hold back the others.
+		/// 	4. Once all the delegations are released, the candidate is no longer marked for releasing.
+		pub fn release_delegations(remaining_weight: Weight) -> Weight {
+			use scale_info::prelude::collections::BTreeSet;
+
+			let cursor_weight = T::DbWeight::get().reads_writes(1, 1);
+			let candidate_weight = T::DbWeight::get().reads_writes(2, 2);
+			let release_weight = <weights::SubstrateWeight<T> as WeightInfo>::release_delegation();
+			let max_releases = T::MaxDelegationReleasesPerBlock::get();
+
+			if cursor_weight.any_gt(remaining_weight) {
+				return Weight::zero();
+			}
+			let mut consumed_weight = cursor_weight;
+			let mut released: u32 = 0;
+			let mut visited: BTreeSet<T::AccountId> = BTreeSet::new();
+			let initial_cursor = ReleasingCursor::<T>::get();
+			let mut cursor = initial_cursor.clone();
+
+			while released < max_releases {
+				// The candidate and at least one of its delegations must fit in the remaining weight
+				if consumed_weight.saturating_add(candidate_weight).saturating_add(release_weight).any_gt(remaining_weight) {
+					break;
+				}
+
+				// Resume after the cursor and wrap around to the first releasing candidate
+				let next = cursor.as_ref()
+					.and_then(|c| ReleasingCandidates::<T>::iter_keys_from(ReleasingCandidates::<T>::hashed_key_for(c)).next())
+					.or_else(|| ReleasingCandidates::<T>::iter_keys().next());
+				let Some(candidate) = next else {
+					consumed_weight = consumed_weight.saturating_add(T::DbWeight::get().reads(1));
+					break;
+				};
+				if !visited.insert(candidate.clone()) {
 					break;
 				}
+				consumed_weight = consumed_weight.saturating_add(candidate_weight);
+				cursor = Some(candidate.clone());
+
+				let mut delegations = Delegations::<T>::get(&candidate).unwrap_or_default();
+				let mut retained = Vec::new();
+				while released < max_releases && 
+					  consumed_weight.saturating_add(release_weight).all_lte(remaining_weight) {
+					let Some(delegation) = delegations.pop() else { break };
+					consumed_weight = consumed_weight.saturating_add(release_weight);
+
+					if Self::schedule_unbonding(delegation.delegator.clone(), candidate.clone(), delegation.stake).is_err() {
+						retained.push(delegation);
+						continue;
+					}
+					released = released.saturating_add(1);
+
+					if let Err(error) = Self::set_delegator_stake(delegation.delegator.clone(), candidate.clone(), Zero::zero()) {
+						log::warn!(
+							target: LOG_TARGET,
+							"Failed to clear the delegator state of {:?} for the released candidate {:?}: {:?}",
+							delegation.delegator, candidate, error,
+						);
+					}
+					Self::deposit_event(Event::DelegationReleased { 
+						who: delegation.delegator, 
+						candidate: candidate.clone(), 
+						amount: delegation.stake, 
+					});
+				}
+
+				// The kept delegations are moved to the front, they are retried after the others
+				for delegation in retained {
+					let _ = delegations.try_insert(0, delegation);
+				}
+
+				if delegations.is_empty() {
+					Delegations::<T>::remove(&candidate);
+					ReleasingCandidates::<T>::remove(&candidate);
+					Self::clear_rewards_of_candidate(&candidate);
+				} else {
+					Delegations::<T>::insert(&candidate, delegations);
+				}
+			}
+
+			if cursor != initial_cursor {
+				ReleasingCursor::<T>::set(cursor);
+			}
+			consumed_weight
+		}
+
+		/// Apply the scheduled commission changes
+		/// Note:
+		/// 	1. Called at the end of every session, only the commission changes queued for the ending
+		/// 	   session are drained and applied to the proposed candidates (the apply session is never
+		/// 	   earlier than the session of the schedule, hence no session is skipped).
+		/// 	2. The change is checked again against the current commission (it may have been lowered
+		/// 	   in between), a change exceeding the maximum change is discarded.
+		/// 	3. Returns the weight consumed, the queue entry, the pending commission and the proposed
+		/// 	   candidate are read and written per change.
+		pub fn apply_commission_changes(session: SessionIndex) -> Weight {
+			let delay = T::CommissionChangeDelay::get();
+			let max_change = (T::MaxCommissionChangePerSession::get() as u32).saturating_mul(delay.max(1));
+
+			// Reading past the last queued change of the session
+			let mut weight = T::DbWeight::get().reads(1);
+			for (who, ()) in CommissionChangeQueue::<T>::drain_prefix(session) {
+				weight = weight.saturating_add(T::DbWeight::get().reads_writes(3, 3));
+				let Some(pending) = PendingCommissions::<T>::take(&who) else { continue };
+				ProposedCandidates::<T>::mutate(&who, |maybe_candidate| {
+					if let Some(candidate) = maybe_candidate {
+						let old = candidate.commission;
+						if old.abs_diff(pending.commission) as u32 > max_change {
+							Self::deposit_event(Event::CommissionChangeDiscarded { who: who.clone(), old, new: pending.commission });
+							return;
+						}
+						candidate.commission = pending.commission;
+						Self::deposit_event(Event::CommissionChangeApplied { who: who.clone(), old, new: pending.commission });
+					}
+				});
+			}
+			weight
+		}
+
+		/// Note authored block
+		/// Note:
+		/// 	Counts the blocks authored by a collator in the current session (uptime).
+		pub fn note_authored_block(author: T::AccountId) {
+			let session = pallet_session::CurrentIndex::<T>::get();
+			AuthoredBlocks::<T>::mutate(session, &author, |blocks| *blocks = blocks.saturating_add(1));
+		}
+
+		/// Prune the authored blocks history
+		/// Note:
+		/// 	1. Called at the end of every session, only the last sessions within the history depth
+		/// 	   (including the next session) are kept.
+		/// 	2. Every session below the kept ones is pruned from the earliest authored session, so that
+		/// 	   a skipped session or a lower history depth does not leave older entries behind.
+		/// 	3. At most MaxCandidates entries (or empty sessions) are pruned per call, the rest is
+		/// 	   pruned at the end of the next sessions.
+		/// 	4. The missed slots share the same history.
+		pub fn prune_authored_blocks(session: SessionIndex) -> Weight {
+			let depth = T::AuthoredBlocksHistoryDepth::get().max(1);
+			let Some(pruned) = session.saturating_add(1).checked_sub(depth) else {
+				return Weight::zero();
+			};
+
+			let mut weight = T::DbWeight::get().reads_writes(1, 1);
+			let mut earliest = EarliestAuthoredSession::<T>::get();
+			let mut limit = T::MaxCandidates::get();
+			while earliest <= pruned && limit > 0 {
+				let authored = AuthoredBlocks::<T>::clear_prefix(earliest, limit, None);
+				let missed = MissedSlots::<T>::clear_prefix(earliest, limit, None);
+				weight = weight.saturating_add(T::DbWeight::get().reads_writes(
+					authored.loops.saturating_add(missed.loops).into(),
+					authored.unique.saturating_add(missed.unique).into(),
+				));
+				limit = limit.saturating_sub(authored.unique.max(missed.unique).max(1));
+				if authored.maybe_cursor.is_some() || missed.maybe_cursor.is_some() {
+					break;
+				}
+				earliest = earliest.saturating_add(1);
+			}
+			EarliestAuthoredSession::<T>::put(earliest);
+			weight
+		}
+
+		/// Note missed slots
+		/// Note:
+		/// 	1. Aura authors the slots in round robin, the expected author of a slot is the authority
+		/// 	   at the slot modulo the number of authorities.
+		/// 	2. Every slot skipped since the last noted slot is a missed slot of its expected author.
+		/// 	   Each authority misses the skipped slots divided by the number of authorities, the
+		/// 	   remainder goes to the authorities right after the last noted slot.
+		/// 	3. Nothing is counted for the first noted slot (e.g. right after the runtime upgrade).
+		/// 	4. Returns the weight consumed, the session validators are read once and every collator 
+		/// 	   with missed slots is read and written.
+		pub fn note_missed_slots() -> Weight {
+			let current_slot = u64::from(pallet_aura::CurrentSlot::<T>::get());
+			let last_slot = LastSlot::<T>::get();
+			LastSlot::<T>::put(current_slot);
+
+			// CurrentSlot, LastSlot and Authorities
+			let mut weight = T::DbWeight::get().reads_writes(3, 1);
+			let authorities = pallet_aura::Authorities::<T>::decode_len().unwrap_or_default() as u64;
+			if last_slot.is_zero() || authorities.is_zero() || current_slot <= last_slot.saturating_add(1) {
+				return weight;
+			}
+
+			// CurrentIndex and Validators
+			weight = weight.saturating_add(T::DbWeight::get().reads(2));
+			let session_index = pallet_session::CurrentIndex::<T>::get();
+			let validators = pallet_session::Validators::<T>::get();
+			let first_missed = last_slot.saturating_add(1);
+			let missed = current_slot.saturating_sub(first_missed);
+			for offset in 0..missed.min(authorities) {
+				let authority_index = (first_missed.saturating_add(offset) % authorities) as usize;
+				let count = missed / authorities + if offset < missed % authorities { 1 } else { 0 };
+				let Some(validator) = validators.get(authority_index) else {
+					continue;
+				};
+				if let Ok(who) = <T as frame_system::Config>::AccountId::decode(&mut validator.encode().as_slice()) {
+					MissedSlots::<T>::mutate(session_index, &who, |missed_slots| {
+						*missed_slots = missed_slots.saturating_add(count.saturated_into::<u32>());
+					});
+					weight = weight.saturating_add(T::DbWeight::get().reads_writes(1, 1));
+				}
+			}
+			weight
+		}
+
+		/// Authority account
+		/// Note:
+		/// 	The Aura authorities follow the order of the session validators, hence the account of an
+		/// 	authority is the session validator at the same index.
+		pub fn authority_account(authority_index: u32) -> Option<T::AccountId> {
+			let validator = pallet_session::Validators::<T>::get().get(authority_index as usize)?.encode();
+			<T as frame_system::Config>::AccountId::decode(&mut validator.as_slice()).ok()
+		}
+
+		/// Check a heartbeat
+		/// Note:
+		/// 	1. The heartbeat must be of the current session and not yet received.
+		/// 	2. The signature must be from the Aura key at the authority index.
+		/// 	3. Returns the account of the collator.
+		pub fn check_heartbeat(
+			heartbeat: &Heartbeat<BlockNumberFor<T>>,
+			signature: &<<T as pallet_aura::Config>::AuthorityId as RuntimeAppPublic>::Signature,
+		) -> Result<T::AccountId, DispatchError> {
+			let session_index = pallet_session::CurrentIndex::<T>::get();
+			ensure!(heartbeat.session_index == session_index, Error::<T>::HeartbeatInvalidSession);
+
+			let authorities = pallet_aura::Authorities::<T>::get();
+			let authority = authorities.get(heartbeat.authority_index as usize).ok_or(Error::<T>::HeartbeatInvalidAuthority)?;
+			let who = Self::authority_account(heartbeat.authority_index).ok_or(Error::<T>::HeartbeatInvalidAuthority)?;
+			ensure!(!ReceivedHeartbeats::<T>::contains_key(session_index, &who), Error::<T>::HeartbeatAlreadyReceived);
+
+			let signature_valid = heartbeat.using_encoded(|encoded| authority.verify(&encoded, signature));
+			ensure!(signature_valid, Error::<T>::HeartbeatInvalidSignature);
+			Ok(who)
+		}
+
+		/// Send the heartbeats of the local Aura keys
+		/// Note:
+		/// 	1. Called by the offchain worker, a heartbeat is sent for every local key found in the
+		/// 	   Aura authorities until it is received in the current session.
+		/// 	2. The last sent heartbeat is kept in the offchain storage, it is resent only after the
+		/// 	   resend period.
+		pub fn send_heartbeats(current_block: BlockNumberFor<T>) {
+			let session_index = pallet_session::CurrentIndex::<T>::get();
+			let local_keys = <T as pallet_aura::Config>::AuthorityId::all();
+
+			for (authority_index, authority) in pallet_aura::Authorities::<T>::get().iter().enumerate() {
+				let authority_index = authority_index as u32;
+				if !local_keys.contains(authority) {
+					continue;
+				}
+				match Self::authority_account(authority_index) {
+					Some(who) if !ReceivedHeartbeats::<T>::contains_key(session_index, &who) => {},
+					_ => continue,
+				}
+
+				let key = (HEARTBEAT_STORAGE_PREFIX, authority_index).encode();
+				let storage = StorageValueRef::persistent(&key);
+				if let Ok(Some((sent_session, sent_block))) = storage.get::<(SessionIndex, BlockNumberFor<T>)>() {
+					if sent_session == session_index && current_block < sent_block.saturating_add(HEARTBEAT_RESEND_PERIOD.into()) {
+						continue;
+					}
+				}
+
+				let heartbeat = Heartbeat { block_number: current_block, session_index, authority_index };
+				let Some(signature) = heartbeat.using_encoded(|encoded| authority.sign(&encoded)) else {
+					continue;
+				};
+				let call = Call::heartbeat { heartbeat, signature };
+				if SubmitTransaction::<T, Call<T>>::submit_unsigned_transaction(call.into()).is_ok() {
+					storage.set(&(session_index, current_block));
+				}
 			}
-			ensure!(found, Error::<T>::ProposedCandidateNotFound);
-			ProposedCandidates::<T>::put(proposed_candidates);
-			Self::sort_proposed_candidates();
-			Self::deposit_event(Event::ProposedCandidateTotalStake { _proposed_candidate: proposed_candidate });			
-			Ok(())
 		}
 
 		/// Add author
@@ -845,46 +2258,55 @@ pub mod pallet {
 		/// Slashed misbehaving authors
 		/// Note:
 		/// 	1. Desired candidates are exempted from slashing
-		/// 	2. After slashing all the misbehaving authors, clean the actual authors for the 
+		/// 	2. Validators without authored blocks and without a heartbeat in the session are
+		/// 	   considered offline.
+		/// 	3. Validators that missed more than the maximum missed slots in the session are
+		/// 	   considered offline, regardless of their authored blocks and heartbeats.
+		/// 	4. After slashing all the misbehaving authors, clean the actual authors for the 
 		/// 	   next session.
 		pub fn slashed_authors() -> DispatchResult {
 			let validators = pallet_session::Validators::<T>::get();
 			let authors = ActualAuthors::<T>::get();
 			let desired_candidates = DesiredCandidates::<T>::get();
+			let session_index = pallet_session::CurrentIndex::<T>::get();
 
-			let mut non_authors = Vec::new();
+			let mut offline_validators = Vec::new();
 			for validator in validators {
 				let validator_bytes = validator.encode();
 				let account = <T as frame_system::Config>::AccountId::decode(&mut validator_bytes.as_slice()).unwrap();
-				if !authors.contains(&account) && !desired_candidates.contains(&account) {
-					non_authors.push(account.clone());
-				}	
+				if desired_candidates.contains(&account) {
+					continue;
+				}
+				// Offline if the validator neither authored nor sent a heartbeat in the session
+				let silent = !authors.contains(&account) &&
+					!ReceivedHeartbeats::<T>::contains_key(session_index, &account);
+				// Offline if the validator missed more than the maximum missed slots in the session (it
+				// was scheduled by Aura but did not author), even if it authored or sent a heartbeat
+				if silent || MissedSlots::<T>::get(session_index, &account) > T::MaxMissedSlots::get() {
+					offline_validators.push(account.clone());
+				}
 			}
 			
-			for non_author in non_authors.iter() {
-				// Todo: Slashed the author and make it offline, prerequisite Aura Round Robbin
-				// let _ = Self::offline_proposed_candidate(non_author.clone(),true);
-				// Self::sort_proposed_candidates();
-
-				// Todo: Slashed the delegator for that author, Prerequisite Aura Round Robbin
-
-				// Optional alternative of slashing the authors as of the moment:
-				// 1. If the candidate is staling.  Staling means that he hasn't been authoring
-				//    for the last two period.
+			for offline_validator in offline_validators.iter() {
+				// The offline candidate and its delegators are slashed and the candidate is set to
+				// offline.
 				let current_block_number = frame_system::Pallet::<T>::block_number();
-				ProposedCandidates::<T>::mutate(|candidates| {
-					if let Some(candidate) = candidates.iter_mut().find(|c| c.who == *non_author) {
-						let last_authored_block_number = candidate.last_authored;
-						let diff = current_block_number.saturating_sub(last_authored_block_number);
-						let max_stale_period = T::MaxStalingPeriod::get();
-						if diff > max_stale_period {
-							// Set the candidate to offline if staling
-							candidate.offline = true;
-							candidate.last_updated = current_block_number;
-							Self::sort_proposed_candidates();
-						}
+				let offline = ProposedCandidates::<T>::mutate(offline_validator, |maybe_candidate| {
+					if let Some(candidate) = maybe_candidate {
+						// Slash the bond and the delegations of the offline candidate
+						Self::slash_candidate(candidate);
+
+						// Set the candidate to offline
+						candidate.offline = true;
+						candidate.last_updated = current_block_number;
+						return true;
 					}
+					false
 				});
+				if offline {
+					let _ = Self::remove_waiting_candidate(offline_validator.clone());
+					let _ = Self::sort_proposed_candidate(offline_validator.clone());
+				}
 			}
 			
 			// Clear the new set of actual authors
@@ -893,6 +2315,512 @@ pub mod pallet {
 			Ok(())
 		}
 
+		/// Slash a candidate
+		/// Note:
+		/// 	1. The slash fraction is multiplied by the number of offences of the candidate 
+		/// 	   (including the current one) and capped at 100 percent.  The offences are reset if
+		/// 	   the candidate had no offence during the reset period (in sessions).
+		/// 	2. The same fraction is applied to the bond, to every delegation of the candidate and to
+		/// 	   the pending unbonding requests from the candidate (bond decreases and un-stakes).
+		/// 	3. The slashed amounts are taken from the held balances and handed over to OnSlash
+		/// 	   (treasury).
+		/// 	4. The candidate information is updated in place (bond and total_stake), the caller
+		/// 	   is responsible for storing it.
+		/// 	5. A bond slashed down to the minimum bond sets the candidate offline, it cannot be
+		/// 	   online again until the bond is topped up.
+		pub fn slash_candidate(candidate: &mut CandidateInfo<T::AccountId, BalanceOf<T>, BlockNumberFor<T>>) {
+			let session_index = pallet_session::CurrentIndex::<T>::get();
+			let reset = LastSlashSession::<T>::get(&candidate.who)
+				.is_some_and(|last| session_index.saturating_sub(last) > T::SlashOffencesResetPeriod::get());
+			LastSlashSession::<T>::insert(&candidate.who, session_index);
+			let offences = SlashOffences::<T>::mutate(&candidate.who, |offences| {
+				if reset {
+					*offences = 0;
+				}
+				*offences = offences.saturating_add(1);
+				*offences
+			});
+			let fraction = Perbill::from_parts(
+				T::SlashFraction::get().deconstruct().saturating_mul(offences).min(Perbill::one().deconstruct())
+			);
+
+			// Slash the bond
+			let slashed = Self::slash_held(HoldReason::CandidateBond, &candidate.who, fraction * candidate.bond);
+			candidate.bond = candidate.bond.saturating_sub(slashed);
+			Self::deposit_event(Event::CandidateSlashed { who: candidate.who.clone(), amount: slashed });
+			if candidate.bond <= T::MinProposedCandidateBond::get() {
+				candidate.offline = true;
+				candidate.last_updated = frame_system::Pallet::<T>::block_number();
+			}
+
+			// Slash the delegations
+			candidate.total_stake = Self::slash_delegators(candidate.who.clone(), fraction);
+
+			// Slash what is still unbonding from the candidate (Slashing happens during the session
+			// rotation which is not weighed by the session pallet, hence the weight is registered here)
+			let weight = Self::slash_unbonding(candidate.who.clone(), fraction);
+			frame_system::Pallet::<T>::register_extra_weight_unchecked(weight, DispatchClass::Mandatory);
+		}
+
+		/// Slash the delegators of a candidate
+		/// Note:
+		/// 	1. Each delegation loses the same proportion of its stake.
+		/// 	2. Delegations whose stake is slashed to zero are removed.
+		/// 	3. Returns the re-computed total stake of the candidate.
+		pub fn slash_delegators(candidate: T::AccountId, fraction: Perbill) -> BalanceOf<T> {
+			let mut delegations = match Delegations::<T>::get(&candidate) {
+				Some(delegations) => delegations,
+				None => return Zero::zero(),
+			};
+
+			for delegation in delegations.iter_mut() {
+				let slashed = Self::slash_held(HoldReason::Delegation, &delegation.delegator, fraction * delegation.stake);
+				delegation.stake = delegation.stake.saturating_sub(slashed);
+				let _ = Self::set_delegator_stake(delegation.delegator.clone(), candidate.clone(), delegation.stake);
+				Self::deposit_event(Event::DelegatorSlashed { 
+					who: delegation.delegator.clone(), 
+					candidate: candidate.clone(), 
+					amount: slashed,
+				});
+			}
+			delegations.retain(|d| !d.stake.is_zero());
+
+			let total_stake = delegations.iter().fold(BalanceOf::<T>::default(), |acc, delegation| acc + delegation.stake);
+			if delegations.is_empty() {
+				Delegations::<T>::remove(&candidate);
+			} else {
+				Delegations::<T>::insert(&candidate, delegations);
+			}
+			total_stake
+		}
+
+		/// Slash the unbonding requests from a candidate
+		/// Note:
+		/// 	1. The unbonding amounts remain held until withdrawn, hence a bond decrease or an un-stake
+		/// 	   right before the slash does not escape it.
+		/// 	2. Each request from the candidate loses the same proportion of its amount, requests 
+		/// 	   slashed to zero are removed.
+		/// 	3. A redelegated amount is slashed from the delegation to the new candidate, then from
+		/// 	   what is unbonding from the new candidate if the delegation was decreased since.
+		/// 	4. Only the accounts indexed under the candidate (UnbondingAccounts) are read, the
+		/// 	   returned weight covers these accesses.
+		pub fn slash_unbonding(candidate: T::AccountId, fraction: Perbill) -> Weight {
+			let mut reads: u64 = 0;
+			let mut writes: u64 = 0;
+			let accounts: Vec<T::AccountId> = UnbondingAccounts::<T>::iter_key_prefix(&candidate).collect();
+			reads.saturating_accrue(accounts.len() as u64);
+
+			for who in accounts {
+				let mut requests = UnbondingRequests::<T>::get(&who);
+				reads.saturating_inc();
+				let reason = if who == candidate { HoldReason::CandidateBond } else { HoldReason::Delegation };
+				let mut slashed = BalanceOf::<T>::default();
+				let mut unbonding_candidates = Vec::from([candidate.clone()]);
+				for index in 0..requests.len() {
+					if requests[index].candidate != candidate {
+						continue;
+					}
+					let amount = fraction * requests[index].amount;
+					let amount = match requests[index].redelegated_to.clone() {
+						None => Self::slash_held(reason, &who, amount),
+						Some(to) => {
+							let mut remaining = amount.saturating_sub(Self::slash_redelegated_stake(&who, &to, amount));
+							// Delegations, DelegatorState, the rewards, ProposedCandidates and SortedCandidates
+							reads.saturating_accrue(7);
+							writes.saturating_accrue(6);
+							for request in requests.iter_mut().filter(|r| r.candidate == to && r.redelegated_to.is_none()) {
+								let unbonding = Self::slash_held(HoldReason::Delegation, &who, remaining.min(request.amount));
+								request.amount = request.amount.saturating_sub(unbonding);
+								remaining = remaining.saturating_sub(unbonding);
+								reads.saturating_accrue(2);
+								writes.saturating_accrue(2);
+							}
+							unbonding_candidates.push(to);
+							amount.saturating_sub(remaining)
+						},
+					};
+					requests[index].amount = requests[index].amount.saturating_sub(amount);
+					slashed = slashed.saturating_add(amount);
+					// Balances::Holds and System::Account
+					reads.saturating_accrue(2);
+					writes.saturating_accrue(2);
+				}
+				requests.retain(|r| !r.amount.is_zero());
+				for unbonding_candidate in unbonding_candidates.iter() {
+					if !requests.iter().any(|r| r.candidate == *unbonding_candidate) {
+						UnbondingAccounts::<T>::remove(unbonding_candidate, &who);
+						writes.saturating_inc();
+					}
+				}
+
+				if requests.is_empty() {
+					UnbondingRequests::<T>::remove(&who);
+				} else {
+					UnbondingRequests::<T>::insert(&who, requests);
+				}
+				writes.saturating_inc();
+				Self::deposit_event(Event::UnbondingSlashed { who, candidate: candidate.clone(), amount: slashed });
+			}
+			T::DbWeight::get().reads_writes(reads, writes)
+		}
+
+		/// Slash the stake redelegated to a candidate
+		/// Note:
+		/// 	1. The redelegated amount backs the delegation to the new candidate, hence the delegation
+		/// 	   is decreased by the slashed amount (at most its stake) and the new candidate is sorted.
+		/// 	2. Returns the actual slashed amount.
+		pub fn slash_redelegated_stake(who: &T::AccountId, to: &T::AccountId, amount: BalanceOf<T>) -> BalanceOf<T> {
+			let mut slashed = BalanceOf::<T>::default();
+			let mut stake = BalanceOf::<T>::default();
+			Delegations::<T>::mutate_exists(to, |maybe_delegations| {
+				if let Some(delegations) = maybe_delegations {
+					if let Some(delegation) = delegations.iter_mut().find(|d| d.delegator == *who) {
+						slashed = Self::slash_held(HoldReason::Delegation, who, amount.min(delegation.stake));
+						delegation.stake = delegation.stake.saturating_sub(slashed);
+						stake = delegation.stake;
+					}
+					delegations.retain(|d| !d.stake.is_zero());
+					if delegations.is_empty() {
+						*maybe_delegations = None;
+					}
+				}
+			});
+			if !slashed.is_zero() {
+				let _ = Self::set_delegator_stake(who.clone(), to.clone(), stake);
+				let _ = Self::total_stake_proposed_candidate(to.clone());
+				Self::deposit_event(Event::DelegatorSlashed { who: who.clone(), candidate: to.clone(), amount: slashed });
+			}
+			slashed
+		}
+
+		/// Slash a held balance
+		/// Note:
+		/// 	Returns the actual slashed amount since the held balance may be insufficient.
+		pub fn slash_held(reason: HoldReason, who: &T::AccountId, amount: BalanceOf<T>) -> BalanceOf<T> {
+			if amount.is_zero() {
+				return Zero::zero();
+			}
+			let (credit, remaining) = T::StakingCurrency::slash(&reason.into(), who, amount);
+			T::OnSlash::on_unbalanced(credit);
+			amount.saturating_sub(remaining)
+		}
+
+		/// Candidate summaries
+		/// Note:
+		/// 	The rank starts at one and follows the sorting of the proposed candidates.
+		pub fn candidate_summaries() -> Vec<CandidateSummary<T::AccountId, BalanceOf<T>, BlockNumberFor<T>>> {
+			Self::proposed_candidates()
+				.into_iter()
+				.enumerate()
+				.map(|(index, info)| CandidateSummary { rank: index as u32 + 1, info })
+				.collect()
+		}
+
+		/// Delegations of a delegator across all the candidates
+		/// Note:
+		/// 	Returns the candidate and the stake of every delegation from the delegator state.
+		pub fn delegations_of(delegator: T::AccountId) -> Vec<(T::AccountId, BalanceOf<T>)> {
+			DelegatorState::<T>::get(&delegator)
+				.into_iter()
+				.map(|c| (c.candidate, c.stake))
+				.collect()
+		}
+
+		/// Authored blocks of a collator per session
+		/// Note:
+		/// 	Returns the session and the number of authored blocks for every kept session, from the
+		/// 	oldest to the current session, sessions without authored blocks are skipped.
+		pub fn authored_blocks_of(who: T::AccountId) -> Vec<(SessionIndex, u32)> {
+			let current = pallet_session::CurrentIndex::<T>::get();
+			let oldest = current.saturating_sub(T::AuthoredBlocksHistoryDepth::get().max(1) - 1);
+			(oldest..=current)
+				.map(|session| (session, AuthoredBlocks::<T>::get(session, &who)))
+				.filter(|(_, blocks)| *blocks > 0)
+				.collect()
+		}
+
+		/// Authored blocks of all the collators in a session
+		pub fn session_authored_blocks(session: SessionIndex) -> Vec<(T::AccountId, u32)> {
+			AuthoredBlocks::<T>::iter_prefix(session).collect()
+		}
+
+		/// Authors of the current session
+		pub fn session_authors() -> Vec<T::AccountId> {
+			pallet_session::Validators::<T>::get()
+				.into_iter()
+				.filter_map(|validator| {
+					let validator_bytes = validator.encode();
+					<T as frame_system::Config>::AccountId::decode(&mut validator_bytes.as_slice()).ok()
+				})
+				.collect()
+		}
+
+		/// Delegators' portion of an amount credited to a candidate
+		/// Note:
+		/// 	1. The commission of the candidate is the portion of the fees or the block inflation 
+		/// 	   shared to its delegators, the rest goes to the candidate.
+		/// 	2. Zero if the candidate has no commission or no stake.
+		pub fn delegators_portion(candidate: T::AccountId, amount: BalanceOf<T>) -> BalanceOf<T> {
+			match ProposedCandidates::<T>::get(&candidate) {
+				Some(info) if !info.total_stake.is_zero() => Percent::from_percent(info.commission).mul_floor(amount),
+				_ => Zero::zero(),
+			}
+		}
+
+		/// Accumulate the rewards of the delegators of a candidate
+		/// Note:
+		/// 	The amount must already be in the pot.  Only the reward per stake of the candidate is
+		/// 	updated (constant time), each delegator claims its share later (claim_rewards).
+		pub fn accumulate_rewards(candidate: T::AccountId, amount: BalanceOf<T>) {
+			if amount.is_zero() {
+				return;
+			}
+			if let Some(info) = ProposedCandidates::<T>::get(&candidate) {
+				if !info.total_stake.is_zero() {
+					let increment = FixedU128::saturating_from_rational(
+						amount.saturated_into::<u128>(), 
+						info.total_stake.saturated_into::<u128>(),
+					);
+					RewardPerStake::<T>::mutate(&candidate, |reward_per_stake| {
+						*reward_per_stake = reward_per_stake.saturating_add(increment);
+					});
+				}
+			}
+		}
+
+		/// Settle the rewards of a delegator
+		/// Note:
+		/// 	The rewards accrued by the stake since the last checkpoint are moved to the pending 
+		/// 	rewards, then the checkpoint is moved to the current reward per stake.
+		pub fn settle_rewards(delegator: &T::AccountId, candidate: &T::AccountId, stake: BalanceOf<T>) {
+			let reward_per_stake = RewardPerStake::<T>::get(candidate);
+			let accrued = Self::accrued_rewards(delegator, candidate, stake);
+			if !accrued.is_zero() {
+				PendingRewards::<T>::mutate(delegator, candidate, |pending| *pending = pending.saturating_add(accrued));
+			}
+			RewardCheckpoints::<T>::insert(delegator, candidate, reward_per_stake);
+		}
+
+		/// Clear the rewards of a removed candidate
+		/// Note:
+		/// 	1. Called once the candidate has no delegations left, every delegation was released
+		/// 	   through set_delegator_stake which settled its rewards (kept in the pending rewards)
+		/// 	   and removed its checkpoint.
+		/// 	2. The reward per stake is kept if the candidate registered again in the meantime.
+		pub fn clear_rewards_of_candidate(candidate: &T::AccountId) {
+			if !ProposedCandidates::<T>::contains_key(candidate) {
+				RewardPerStake::<T>::remove(candidate);
+			}
+		}
+
+		/// Rewards accrued by a stake since the last checkpoint of the delegator
+		pub fn accrued_rewards(delegator: &T::AccountId, candidate: &T::AccountId, stake: BalanceOf<T>) -> BalanceOf<T> {
+			RewardPerStake::<T>::get(candidate)
+				.saturating_sub(RewardCheckpoints::<T>::get(delegator, candidate))
+				.saturating_mul_int(stake.saturated_into::<u128>())
+				.saturated_into()
+		}
+
+		/// Claimable rewards of a delegator from a candidate (Settled and accrued)
+		pub fn pending_rewards_of(delegator: T::AccountId, candidate: T::AccountId) -> BalanceOf<T> {
+			let stake = Self::delegator_stake(&delegator, &candidate);
+			PendingRewards::<T>::get(&delegator, &candidate)
+				.saturating_add(Self::accrued_rewards(&delegator, &candidate, stake))
+		}
+
+		/// Stake of a delegator to a candidate (Zero if not delegated)
+		pub fn delegator_stake(delegator: &T::AccountId, candidate: &T::AccountId) -> BalanceOf<T> {
+			DelegatorState::<T>::get(delegator)
+				.iter()
+				.find(|c| c.candidate == *candidate)
+				.map(|c| c.stake)
+				.unwrap_or_else(Zero::zero)
+		}
+
+		/// Queue the actual authors for compounding
+		/// Note:
+		/// 	1. Called at the end of the session before the actual authors are cleared, only the
+		/// 	   candidates that authored in the session have accrued new rewards.
+		/// 	2. A candidate still compounding from a previous session keeps its position, the rewards
+		/// 	   of the current session are compounded along with the rest.
+		/// 	3. Returns the weight consumed (per actual author).
+		pub fn queue_compounding() -> Weight {
+			let mut weight = T::DbWeight::get().reads(1);
+			for candidate in ActualAuthors::<T>::get() {
+				weight = weight.saturating_add(T::DbWeight::get().reads(1));
+				if !CompoundingCandidates::<T>::contains_key(&candidate) {
+					weight = weight.saturating_add(T::DbWeight::get().writes(1));
+					CompoundingCandidates::<T>::insert(&candidate, 0);
+				}
+			}
+			weight
+		}
+
+		/// Compound the rewards of the delegators of the compounding candidates
+		/// Note:
+		/// 	1. Called on idle, the auto compounding delegations are compounded one at a time and
+		/// 	   whatever does not fit in the remaining weight is carried over to the next block from
+		/// 	   the index of the next delegation.
+		/// 	2. The auto compound share of the pending rewards is moved from the pot to the delegator
+		/// 	   and held as additional stake, the rest remains claimable.
+		/// 	3. Each delegation is compounded in its own storage layer, if any step fails the changes of
+		/// 	   that delegation are reverted and its rewards remain claimable.
+		/// 	4. A candidate that is no longer proposed is dropped, its rewards remain claimable.
+		/// 	5. The total stake of the compounded candidates is re-computed and sorted once.
+		pub fn compound_rewards(remaining_weight: Weight) -> Weight {
+			// CompoundingCandidates, Delegations and ProposedCandidates (The compounding candidate)
+			let candidate_weight = T::DbWeight::get().reads_writes(3, 1);
+			// Delegations, ProposedCandidates and SortedCandidates (The compounded candidate)
+			let compounded_weight = T::DbWeight::get().reads_writes(3, 3);
+			let delegation_weight = T::DbWeight::get().reads_writes(7, 6);
+
+			let pot = Self::account_id();
+			let mut compounded_candidates = Vec::new();
+			let mut consumed_weight = Weight::zero();
+
+			loop {
+				// The candidate and at least one of its delegations must fit in the remaining weight
+				if consumed_weight.saturating_add(candidate_weight).saturating_add(compounded_weight)
+					.saturating_add(delegation_weight).any_gt(remaining_weight) {
+					break;
+				}
+
+				let Some((candidate, start)) = CompoundingCandidates::<T>::iter().next() else {
+					consumed_weight = consumed_weight.saturating_add(T::DbWeight::get().reads(1));
+					break;
+				};
+				consumed_weight = consumed_weight.saturating_add(candidate_weight).saturating_add(compounded_weight);
+
+				let mut delegations = match Delegations::<T>::get(&candidate) {
+					Some(delegations) if ProposedCandidates::<T>::contains_key(&candidate) => delegations,
+					_ => {
+						CompoundingCandidates::<T>::remove(&candidate);
+						continue;
+					},
+				};
+
+				let mut index = start as usize;
+				let mut compounded = false;
+				while index < delegations.len() && 
+					  consumed_weight.saturating_add(delegation_weight).all_lte(remaining_weight) {
+					let delegation = &mut delegations[index];
+					index = index.saturating_add(1);
+					if delegation.auto_compound.is_zero() {
+						continue;
+					}
+
+					consumed_weight = consumed_weight.saturating_add(delegation_weight);
+					let result = with_storage_layer(|| -> Result<BalanceOf<T>, DispatchError> {
+						Self::settle_rewards(&delegation.delegator, &candidate, delegation.stake);
+						let amount = delegation.auto_compound.mul_floor(PendingRewards::<T>::get(&delegation.delegator, &candidate));
+						if amount.is_zero() {
+							return Ok(amount);
+						}
+						T::StakingCurrency::transfer(&pot, &delegation.delegator, amount, Preservation::Expendable)?;
+						PendingRewards::<T>::mutate(&delegation.delegator, &candidate, |pending| *pending = pending.saturating_sub(amount));
+						T::StakingCurrency::hold(&HoldReason::Delegation.into(), &delegation.delegator, amount)?;
+						Self::set_delegator_stake(delegation.delegator.clone(), candidate.clone(), delegation.stake.saturating_add(amount))?;
+						Ok(amount)
+					});
+
+					match result {
+						Ok(amount) if !amount.is_zero() => {
+							delegation.stake = delegation.stake.saturating_add(amount);
+							Self::deposit_event(Event::DelegationCompounded { who: delegation.delegator.clone(), candidate: candidate.clone(), amount });
+							compounded = true;
+						},
+						_ => {},
+					}
+				}
+
+				let finished = index >= delegations.len();
+				if compounded {
+					Delegations::<T>::insert(&candidate, delegations);
+					let _ = Self::compute_total_stake(candidate.clone());
+					compounded_candidates.push(candidate.clone());
+				}
+
+				if !finished {
+					// Out of weight, resume from the next delegation on a later block
+					CompoundingCandidates::<T>::insert(&candidate, index as u32);
+					break;
+				}
+				CompoundingCandidates::<T>::remove(&candidate);
+			}
+
+			if !compounded_candidates.is_empty() {
+				let _ = Self::sort_proposed_candidates(&compounded_candidates);
+			}
+			consumed_weight
+		}
+
+		/// Share of a delegator from an amount credited to a candidate
+		/// Note:
+		/// 	The delegators' portion multiplied by the stake ratio of the delegator, computed the same
+		/// 	way as the reward per stake.  Zero if the candidate has no commission or the delegator 
+		/// 	has no stake.
+		pub fn delegator_share(candidate: T::AccountId, delegator: T::AccountId, amount: BalanceOf<T>) -> BalanceOf<T> {
+			let info = match ProposedCandidates::<T>::get(&candidate) {
+				Some(info) => info,
+				None => return Zero::zero(),
+			};
+			let portion = Self::delegators_portion(candidate.clone(), amount);
+			if portion.is_zero() {
+				return Zero::zero();
+			}
+			FixedU128::saturating_from_rational(portion.saturated_into::<u128>(), info.total_stake.saturated_into::<u128>())
+				.saturating_mul_int(Self::delegator_stake(&delegator, &candidate).saturated_into::<u128>())
+				.saturated_into()
+		}
+
+		/// The pot account of the pallet (Receives the minted inflation before it is paid)
+		pub fn account_id() -> T::AccountId {
+			T::PalletId::get().into_account_truncating()
+		}
+
+		/// Block inflation
+		/// Note:
+		/// 	The annual inflation of the total issuance divided by the blocks per year, capped so that
+		/// 	the total issuance never exceeds the issuance cap.
+		pub fn block_inflation() -> BalanceOf<T> {
+			let inflation = Inflation::<T>::get();
+			if inflation.annual_inflation.is_zero() {
+				return Zero::zero();
+			}
+
+			let total_issuance = T::StakingCurrency::total_issuance();
+			if total_issuance >= inflation.issuance_cap {
+				return Zero::zero();
+			}
+
+			let annual_amount = inflation.annual_inflation.mul_floor(total_issuance);
+			let block_amount = annual_amount / BalanceOf::<T>::from(T::BlocksPerYear::get().max(1));
+			block_amount.min(inflation.issuance_cap.saturating_sub(total_issuance))
+		}
+
+		/// Reward the author with the block inflation
+		/// Note:
+		/// 	1. The reward is minted to the pot, the delegators' portion stays in the pot and is
+		/// 	   accumulated (claimed later) and the rest is paid to the author.
+		/// 	2. Authors that are not proposed candidates (e.g. desired candidates) receive the whole reward.
+		/// 	3. Nothing is minted if the author account does not exist.
+		/// 	4. The minted amount is added to the session inflation, reported at the end of the session.
+		/// 	5. Returns the weight consumed (constant).
+		pub fn reward_author(author: T::AccountId, reward: BalanceOf<T>) -> Weight {
+			let pot = Self::account_id();
+			if !frame_system::Pallet::<T>::account_exists(&author) ||
+			   T::StakingCurrency::mint_into(&pot, reward).is_err() {
+				return T::DbWeight::get().reads(2);
+			}
+
+			let portion = Self::delegators_portion(author.clone(), reward);
+			Self::accumulate_rewards(author.clone(), portion);
+			let _ = T::StakingCurrency::transfer(&pot, &author, reward.saturating_sub(portion), Preservation::Expendable);
+
+			SessionInflation::<T>::mutate(|minted| *minted = minted.saturating_add(reward));
+			T::DbWeight::get().reads_writes(6, 5)
+		}
+
 		/// Wait-list the authors
 		/// Note:
 		/// 	1. Wait-list first the desired candidates.  Parameter to be wait listed.
@@ -904,11 +2832,14 @@ pub mod pallet {
 		/// 	   retain.
 		pub fn wait_list_authors() -> DispatchResult {
 			let desired_candidates = DesiredCandidates::<T>::get();
-			let proposed_candidates = ProposedCandidates::<T>::get();
+			let proposed_candidates = Self::proposed_candidates();
 			let mut waiting_candidates: BoundedVec<T::AccountId, T::MaxCandidates> = BoundedVec::default();
 
-			// First, add all desired candidates
+			// First, add all desired candidates (unless registered as an offline proposed candidate)
 			for candidate in desired_candidates.iter() {
+				if ProposedCandidates::<T>::get(candidate).is_some_and(|c| c.offline) {
+					continue;
+				}
 				if waiting_candidates.len() < T::MaxCandidates::get() as usize {
 					waiting_candidates.try_push(candidate.clone()).map_err(|_| Error::<T>::WaitingCandidateAlreadyExist)?;
 				}
@@ -943,7 +2874,7 @@ pub mod pallet {
 		/// 	5. For Queuing candidate, just change the status to waiting.
 		/// 	6. For Authoring candidate, just change the status to queuing.
 		pub fn prepare_authors() -> DispatchResult {
-			let proposed_candidates = ProposedCandidates::<T>::get();
+			let proposed_candidates = Self::proposed_candidates();
 
 			for proposed_candidate in proposed_candidates.iter() {
 				if proposed_candidate.offline || 
@@ -951,14 +2882,11 @@ pub mod pallet {
 				   proposed_candidate.bond == Zero::zero() {
 					if proposed_candidate.status == Status::Online || 
 					   proposed_candidate.status == Status::Offline {
-						// Todo: un-stake the delegates before removing the proposed candidates
-
-						// Remove from the proposed candidates
+						// Remove from the proposed candidates (The delegations are released on idle)
 						let _ = Self::remove_proposed_candidate(proposed_candidate.who.clone());
 					} else if proposed_candidate.status == Status::Waiting {
-						// Todo: un-stake the delegates before removing the proposed candidates
-
-						// Remove immediately from the waiting and proposed candidates
+						// Remove immediately from the waiting and proposed candidates (The delegations 
+						// are released on idle)
 						let _ = Self::remove_waiting_candidate(proposed_candidate.who.clone());
 						let _ = Self::remove_proposed_candidate(proposed_candidate.who.clone());
 					} else if proposed_candidate.status == Status::Queuing {
@@ -996,8 +2924,8 @@ pub mod pallet {
 
 				// Change status to Queuing if the status is waiting, otherwise
 				// retain current status.
-				ProposedCandidates::<T>::mutate(|candidates| {
-					if let Some(candidate) = candidates.iter_mut().find(|c| c.who == waiting_candidate) {
+				ProposedCandidates::<T>::mutate(&waiting_candidate, |maybe_candidate| {
+					if let Some(candidate) = maybe_candidate {
 						if candidate.status == Status::Waiting {
 							candidate.status = Status::Queuing;
 						}
@@ -1009,6 +2937,153 @@ pub mod pallet {
 		}
 	}
 
+	/// =========
+	/// Try State
+	/// =========
+	#[cfg(any(feature = "try-runtime", test))]
+	impl<T: Config> Pallet<T> {
+
+		/// Staking invariants
+		/// Note:
+		/// 	Checked on every block with try-runtime and after every mock test.
+		pub fn do_try_state() -> Result<(), sp_runtime::TryRuntimeError> {
+			Self::try_state_total_stake()?;
+			Self::try_state_held_balances()?;
+			Self::try_state_waiting_candidates()?;
+			Self::try_state_invulnerables()?;
+			Self::try_state_unbonding_accounts()?;
+			Self::try_state_commission_changes()?;
+			Ok(())
+		}
+
+		/// The total stake of every proposed candidate is the sum of its delegations
+		fn try_state_total_stake() -> Result<(), sp_runtime::TryRuntimeError> {
+			for (who, candidate) in ProposedCandidates::<T>::iter() {
+				let total_stake = Delegations::<T>::get(&who)
+					.unwrap_or_default()
+					.iter()
+					.fold(BalanceOf::<T>::default(), |acc, delegation| acc.saturating_add(delegation.stake));
+				ensure!(candidate.total_stake == total_stake, "The total stake of a candidate differs from its delegations");
+			}
+			Ok(())
+		}
+
+		/// The held balances cover the bookkeeping
+		/// Note:
+		/// 	1. The candidate bond hold covers the bond and the pending unbonding of the bond.
+		/// 	2. The delegation hold covers the delegations (including the ones of the releasing
+		/// 	   candidates) and the pending unbonding of the delegations.  The redelegations are
+		/// 	   already covered by the delegation to the new candidate.
+		/// 	3. The holds may exceed the bookkeeping, only the lower bound is checked.
+		fn try_state_held_balances() -> Result<(), sp_runtime::TryRuntimeError> {
+			use scale_info::prelude::collections::BTreeMap;
+
+			let mut bonds: BTreeMap<T::AccountId, BalanceOf<T>> = BTreeMap::new();
+			let mut stakes: BTreeMap<T::AccountId, BalanceOf<T>> = BTreeMap::new();
+
+			for (who, candidate) in ProposedCandidates::<T>::iter() {
+				let bond = bonds.entry(who).or_default();
+				*bond = bond.saturating_add(candidate.bond);
+			}
+			for (_candidate, delegations) in Delegations::<T>::iter() {
+				for delegation in delegations.iter() {
+					let stake = stakes.entry(delegation.delegator.clone()).or_default();
+					*stake = stake.saturating_add(delegation.stake);
+				}
+			}
+			for (who, requests) in UnbondingRequests::<T>::iter() {
+				for request in requests.iter().filter(|r| r.redelegated_to.is_none()) {
+					let amount = if request.candidate == who { 
+						bonds.entry(who.clone()).or_default() 
+					} else { 
+						stakes.entry(who.clone()).or_default() 
+					};
+					*amount = amount.saturating_add(request.amount);
+				}
+			}
+
+			for (who, bond) in bonds.iter() {
+				ensure!(
+					T::StakingCurrency::balance_on_hold(&HoldReason::CandidateBond.into(), who) >= *bond,
+					"The held bond does not cover the bond of a candidate"
+				);
+			}
+			for (who, stake) in stakes.iter() {
+				ensure!(
+					T::StakingCurrency::balance_on_hold(&HoldReason::Delegation.into(), who) >= *stake,
+					"The held stake does not cover the delegations of a delegator"
+				);
+			}
+			Ok(())
+		}
+
+		/// The waiting candidates are unique, not offline and not leaving
+		fn try_state_waiting_candidates() -> Result<(), sp_runtime::TryRuntimeError> {
+			let waiting_candidates = WaitingCandidates::<T>::get();
+			for (index, waiting_candidate) in waiting_candidates.iter().enumerate() {
+				ensure!(
+					!waiting_candidates.iter().skip(index + 1).any(|c| c == waiting_candidate),
+					"A waiting candidate is duplicated"
+				);
+				if let Some(candidate) = ProposedCandidates::<T>::get(waiting_candidate) {
+					ensure!(!candidate.leaving, "A leaving candidate is still waiting");
+					ensure!(!candidate.offline, "An offline candidate is still waiting");
+				}
+			}
+			Ok(())
+		}
+
+		/// The commission change queue indexes exactly the pending commissions at their apply session
+		fn try_state_commission_changes() -> Result<(), sp_runtime::TryRuntimeError> {
+			for (who, pending) in PendingCommissions::<T>::iter() {
+				ensure!(
+					CommissionChangeQueue::<T>::contains_key(pending.apply_session, &who),
+					"A pending commission is not queued"
+				);
+			}
+			for (apply_session, who, ()) in CommissionChangeQueue::<T>::iter() {
+				ensure!(
+					PendingCommissions::<T>::get(&who).is_some_and(|p| p.apply_session == apply_session),
+					"A queued commission change is not pending"
+				);
+			}
+			Ok(())
+		}
+
+		/// The unbonding accounts index exactly the candidates of the unbonding requests
+		fn try_state_unbonding_accounts() -> Result<(), sp_runtime::TryRuntimeError> {
+			for (who, requests) in UnbondingRequests::<T>::iter() {
+				for request in requests.iter() {
+					ensure!(
+						UnbondingAccounts::<T>::contains_key(&request.candidate, &who),
+						"An unbonding request is not indexed"
+					);
+				}
+			}
+			for (candidate, who, ()) in UnbondingAccounts::<T>::iter() {
+				ensure!(
+					UnbondingRequests::<T>::get(&who).iter().any(|r| r.candidate == candidate),
+					"An unbonding account has no request from the candidate"
+				);
+			}
+			Ok(())
+		}
+
+		/// The statuses of the proposed candidates are consistent with the invulnerables
+		/// Note:
+		/// 	A proposed candidate is wait listed (Waiting) before it is queued (Queuing) to the
+		/// 	invulnerables, hence a queued candidate is never Online nor leaving.
+		fn try_state_invulnerables() -> Result<(), sp_runtime::TryRuntimeError> {
+			for invulnerable in pallet_collator_selection::Invulnerables::<T>::get().iter() {
+				if let Some(candidate) = ProposedCandidates::<T>::get(invulnerable) {
+					ensure!(candidate.status > Status::Online, "A queued candidate has not been wait listed");
+					ensure!(!candidate.leaving, "A leaving candidate is still queued");
+				}
+			}
+			Ok(())
+		}
+	}
+
 	/// ===============
 	/// Session Manager
 	/// ===============
@@ -1027,18 +3102,50 @@ pub mod pallet {
 			// Todo or may not do anything
 		}
 
-		fn end_session(_: SessionIndex) {
+		fn end_session(index: SessionIndex) {
+			let previous_authors = pallet_collator_selection::Invulnerables::<T>::get();
+
+			// Apply the scheduled commission changes of the current session (The session rotation
+			// is not weighed by the session pallet, hence the weight is registered here)
+			let weight = Self::apply_commission_changes(index);
+			frame_system::Pallet::<T>::register_extra_weight_unchecked(weight, DispatchClass::Mandatory);
+
+			// Queue the actual authors of the current session for compounding, the delegation rewards
+			// are compounded on idle (The session rotation is not weighed by the session pallet, hence
+			// the weight is registered here)
+			let weight = Self::queue_compounding();
+			frame_system::Pallet::<T>::register_extra_weight_unchecked(weight, DispatchClass::Mandatory);
+
+			// Report the inflation minted in the current session
+			let minted = SessionInflation::<T>::take();
+			if !minted.is_zero() {
+				Self::deposit_event(Event::InflationMinted { session: index, amount: minted });
+			}
+
 			// Queue the authors from the waiting list
 			let _ = Self::queue_authors();
 
 			// Slashed the authors of the current session
 			let _ = Self::slashed_authors();
 
+			// The heartbeats of the current session are no longer needed
+			let _ = ReceivedHeartbeats::<T>::clear_prefix(index, u32::MAX, None);
+
+			// Keep the authored blocks within the history depth (after slashing, which reads the
+			// missed slots of the current session)
+			let weight = Self::prune_authored_blocks(index);
+			frame_system::Pallet::<T>::register_extra_weight_unchecked(weight, DispatchClass::Mandatory);
+
 			// Prepare the authors for the next waiting list
 			let _ = Self::prepare_authors();
 
 			// Wait list the authors
 			let _ = Self::wait_list_authors();
+
+			// Summarize the authors selected for the next session and the ones removed
+			let selected = pallet_collator_selection::Invulnerables::<T>::get().to_vec();
+			let removed = previous_authors.into_iter().filter(|author| !selected.contains(author)).collect();
+			Self::deposit_event(Event::SessionRotated { session: index, selected, removed });
 		}
 	}
 
@@ -0,0 +1,502 @@
+//! # Xode Staking Pallet Migrations
+//!
+//! Storage migrations of the xode staking pallet, one module per storage version.
+//! Every step is an unchecked migration wrapped in a VersionedMigration, hence it only runs
+//! when the on-chain storage version matches and the new version is set afterwards.
+
+/// Version 1
+/// Note:
+/// 	Bonds and delegations were reserved through ReservableCurrency, they are now held
+/// 	through fungible::MutateHold with a dedicated HoldReason.
+pub mod v1 {
+	use crate::pallet::*;
+	use core::marker::PhantomData;
+	use frame_support::{
+		migrations::VersionedMigration,
+		pallet_prelude::*,
+		traits::{fungible::MutateHold, ReservableCurrency, UncheckedOnRuntimeUpgrade},
+	};
+	use frame_system::pallet_prelude::BlockNumberFor;
+	use sp_runtime::Saturating;
+	#[cfg(feature = "try-runtime")]
+	use scale_info::prelude::{collections::btree_map::BTreeMap, vec::Vec};
+
+	/// Proposed candidates (The layout up to version 1)
+	#[frame_support::storage_alias]
+	pub type ProposedCandidates<T: Config> = StorageValue<
+		Pallet<T>,
+		BoundedVec<
+			CandidateInfo<<T as frame_system::Config>::AccountId, BalanceOf<T>, BlockNumberFor<T>>,
+			<T as pallet_collator_selection::Config>::MaxCandidates
+		>,
+		ValueQuery,
+	>;
+
+	/// Convert the staking reserves into holds (Version 0 to 1)
+	pub type MigrateToV1<T, OldCurrency> = VersionedMigration<
+		0,
+		1,
+		UncheckedMigrateToV1<T, OldCurrency>,
+		Pallet<T>,
+		<T as frame_system::Config>::DbWeight,
+	>;
+
+	/// Convert the staking reserves into holds
+	/// Note:
+	/// 	1. The bond of every proposed candidate is moved to HoldReason::CandidateBond.
+	/// 	2. The stake of every delegation is moved to HoldReason::Delegation.
+	/// 	3. Every pending unbonding request is moved to the hold reason it came from.
+	/// 	4. OldCurrency is the currency previously used as the staking currency (Balances).
+	/// 	   If a hold fails the amount is reserved back so that no funds are freed.
+	pub struct UncheckedMigrateToV1<T, OldCurrency>(PhantomData<(T, OldCurrency)>);
+
+	impl<T, OldCurrency> UncheckedOnRuntimeUpgrade for UncheckedMigrateToV1<T, OldCurrency>
+	where
+		T: Config,
+		OldCurrency: ReservableCurrency<T::AccountId, Balance = BalanceOf<T>>,
+	{
+		fn on_runtime_upgrade() -> Weight {
+			let mut reads: u64 = 0;
+			let mut writes: u64 = 0;
+
+			// Candidate bonds
+			reads.saturating_inc();
+			for candidate in ProposedCandidates::<T>::get().iter() {
+				Self::reserve_to_hold(&candidate.who, candidate.bond, HoldReason::CandidateBond);
+				reads.saturating_accrue(2);
+				writes.saturating_accrue(2);
+			}
+
+			// Delegations
+			for (_candidate, delegations) in super::v3::Delegations::<T>::iter() {
+				reads.saturating_inc();
+				for delegation in delegations.iter() {
+					Self::reserve_to_hold(&delegation.delegator, delegation.stake, HoldReason::Delegation);
+					reads.saturating_accrue(2);
+					writes.saturating_accrue(2);
+				}
+			}
+
+			// Pending unbonding requests
+			for (who, requests) in UnbondingRequests::<T>::iter() {
+				reads.saturating_inc();
+				for request in requests.iter().filter(|r| r.redelegated_to.is_none()) {
+					let reason = if request.candidate == who { HoldReason::CandidateBond } else { HoldReason::Delegation };
+					Self::reserve_to_hold(&who, request.amount, reason);
+					reads.saturating_accrue(2);
+					writes.saturating_accrue(2);
+				}
+			}
+
+			T::DbWeight::get().reads_writes(reads, writes)
+		}
+
+		/// The staked amount of every account (bonds, delegations and unbonding requests)
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, sp_runtime::TryRuntimeError> {
+			let mut staked: BTreeMap<T::AccountId, BalanceOf<T>> = BTreeMap::new();
+			for candidate in ProposedCandidates::<T>::get().iter() {
+				let amount = staked.entry(candidate.who.clone()).or_default();
+				*amount = amount.saturating_add(candidate.bond);
+			}
+			for (_candidate, delegations) in super::v3::Delegations::<T>::iter() {
+				for delegation in delegations.iter() {
+					let amount = staked.entry(delegation.delegator.clone()).or_default();
+					*amount = amount.saturating_add(delegation.stake);
+				}
+			}
+			for (who, requests) in UnbondingRequests::<T>::iter() {
+				for request in requests.iter().filter(|r| r.redelegated_to.is_none()) {
+					let amount = staked.entry(who.clone()).or_default();
+					*amount = amount.saturating_add(request.amount);
+				}
+			}
+			Ok(staked.into_iter().collect::<Vec<_>>().encode())
+		}
+
+		/// No staked funds are freed, holds are part of the reserved balance
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(state: Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+			let staked = Vec::<(T::AccountId, BalanceOf<T>)>::decode(&mut &state[..])
+				.map_err(|_| "Failed to decode the staked amounts")?;
+			for (who, amount) in staked {
+				ensure!(OldCurrency::reserved_balance(&who) >= amount, "Staked funds were freed by the migration");
+			}
+			Ok(())
+		}
+	}
+
+	impl<T, OldCurrency> UncheckedMigrateToV1<T, OldCurrency>
+	where
+		T: Config,
+		OldCurrency: ReservableCurrency<T::AccountId, Balance = BalanceOf<T>>,
+	{
+		/// Move a reserved amount to a hold
+		fn reserve_to_hold(who: &T::AccountId, amount: BalanceOf<T>, reason: HoldReason) {
+			let remaining = OldCurrency::unreserve(who, amount);
+			let unreserved = amount.saturating_sub(remaining);
+			if T::StakingCurrency::hold(&reason.into(), who, unreserved).is_err() {
+				let _ = OldCurrency::reserve(who, unreserved);
+			}
+		}
+	}
+}
+
+/// Version 2
+/// Note:
+/// 	The proposed candidates were a single bounded vector, they are now stored per account
+/// 	with a separate sorted candidates index for the selection.
+pub mod v2 {
+	use crate::pallet::*;
+	use core::marker::PhantomData;
+	use frame_support::{
+		migrations::VersionedMigration,
+		pallet_prelude::*,
+		traits::UncheckedOnRuntimeUpgrade,
+	};
+	use scale_info::prelude::vec::Vec;
+	use sp_runtime::Saturating;
+
+	/// Split the proposed candidates into a map and a sorted index (Version 1 to 2)
+	pub type MigrateToV2<T> = VersionedMigration<
+		1,
+		2,
+		UncheckedMigrateToV2<T>,
+		Pallet<T>,
+		<T as frame_system::Config>::DbWeight,
+	>;
+
+	/// Split the proposed candidates into a map and a sorted index
+	/// Note:
+	/// 	1. Every candidate information is moved to the ProposedCandidates map.
+	/// 	2. The sorted candidates index is rebuilt with the current ordering.
+	/// 	3. Must run after v1::MigrateToV1, the old vector is read through v1::ProposedCandidates.
+	pub struct UncheckedMigrateToV2<T>(PhantomData<T>);
+
+	impl<T: Config> UncheckedOnRuntimeUpgrade for UncheckedMigrateToV2<T> {
+		fn on_runtime_upgrade() -> Weight {
+			let mut reads: u64 = 1;
+			let mut writes: u64 = 1;
+
+			let candidates: Vec<_> = super::v1::ProposedCandidates::<T>::take().into_iter().collect();
+			for candidate in candidates.iter() {
+				ProposedCandidates::<T>::insert(&candidate.who, candidate);
+				writes.saturating_inc();
+			}
+			for candidate in candidates.iter() {
+				let _ = Pallet::<T>::sort_proposed_candidate(candidate.who.clone());
+				reads.saturating_accrue(2);
+				writes.saturating_inc();
+			}
+
+			T::DbWeight::get().reads_writes(reads, writes)
+		}
+
+		/// The number of proposed candidates
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, sp_runtime::TryRuntimeError> {
+			Ok((super::v1::ProposedCandidates::<T>::get().len() as u32).encode())
+		}
+
+		/// Every proposed candidate is moved to the map and sorted
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(state: Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+			let count = u32::decode(&mut &state[..]).map_err(|_| "Failed to decode the candidates count")?;
+			ensure!(!super::v1::ProposedCandidates::<T>::exists(), "The old proposed candidates still exist");
+			ensure!(ProposedCandidates::<T>::iter_keys().count() as u32 == count, "Proposed candidates were lost");
+			ensure!(SortedCandidates::<T>::get().len() as u32 == count, "Proposed candidates were not sorted");
+			Ok(())
+		}
+	}
+}
+
+/// Version 3
+/// Note:
+/// 	The delegations were only keyed by candidate, the delegator state is the reverse index
+/// 	(delegator to candidates) built from the existing delegations.
+pub mod v3 {
+	use crate::{pallet::*, LOG_TARGET};
+	use core::marker::PhantomData;
+	use frame_support::{
+		migrations::VersionedMigration,
+		pallet_prelude::*,
+		traits::{fungible::MutateHold, tokens::Precision, UncheckedOnRuntimeUpgrade},
+	};
+	use scale_info::prelude::vec::Vec;
+	use sp_runtime::{traits::Zero, Saturating};
+
+	/// Delegation (The layout up to version 3)
+	#[derive(PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug, scale_info::TypeInfo, MaxEncodedLen,)]
+	pub struct Delegation<AccountId, Balance> {
+		pub delegator: AccountId,
+		pub stake: Balance,
+	}
+
+	/// Delegations (The layout up to version 3)
+	#[frame_support::storage_alias]
+	pub type Delegations<T: Config> = StorageMap<
+		Pallet<T>,
+		Twox64Concat,
+		<T as frame_system::Config>::AccountId,
+		BoundedVec<Delegation<<T as frame_system::Config>::AccountId, BalanceOf<T>>, <T as Config>::MaxProposedCandidateDelegates>,
+		OptionQuery,
+	>;
+
+	/// Build the delegator state from the delegations (Version 2 to 3)
+	pub type MigrateToV3<T> = VersionedMigration<
+		2,
+		3,
+		UncheckedMigrateToV3<T>,
+		Pallet<T>,
+		<T as frame_system::Config>::DbWeight,
+	>;
+
+	/// Build the delegator state from the delegations
+	/// Note:
+	/// 	1. Every delegation is added to the delegator state of its delegator.
+	/// 	2. Delegations beyond MaxDelegationsPerDelegator are removed from the candidate and 
+	/// 	   scheduled for unbonding (the stake stays held until the release block).
+	/// 	3. If the unbonding can not be scheduled either, the delegation is removed and its hold is
+	/// 	   released immediately, no delegation is kept without being indexed.  Both counts are
+	/// 	   logged.
+	pub struct UncheckedMigrateToV3<T>(PhantomData<T>);
+
+	impl<T: Config> UncheckedOnRuntimeUpgrade for UncheckedMigrateToV3<T> {
+		fn on_runtime_upgrade() -> Weight {
+			let mut reads: u64 = 0;
+			let mut writes: u64 = 0;
+			let mut unbonded: u32 = 0;
+			let mut released: u32 = 0;
+
+			let candidates: Vec<_> = Delegations::<T>::iter_keys().collect();
+			for candidate in candidates {
+				let mut delegations = Delegations::<T>::get(&candidate).unwrap_or_default();
+				let mut removed_stake = BalanceOf::<T>::zero();
+				reads.saturating_inc();
+
+				delegations.retain(|delegation| {
+					// DelegatorState, RewardPerStake and RewardCheckpoints are read, the delegator
+					// state and the reward checkpoint are written
+					reads.saturating_accrue(3);
+					writes.saturating_accrue(2);
+					if Pallet::<T>::set_delegator_stake(delegation.delegator.clone(), candidate.clone(), delegation.stake).is_ok() {
+						return true;
+					}
+
+					// The delegator state is full, UnbondingRequests and UnbondingAccounts are written
+					reads.saturating_inc();
+					writes.saturating_accrue(2);
+					if Pallet::<T>::schedule_unbonding(delegation.delegator.clone(), candidate.clone(), delegation.stake).is_ok() {
+						unbonded.saturating_inc();
+					} else {
+						// The unbonding requests are full as well, Holds is written
+						writes.saturating_inc();
+						let _ = T::StakingCurrency::release(
+							&HoldReason::Delegation.into(),
+							&delegation.delegator,
+							delegation.stake,
+							Precision::BestEffort,
+						);
+						released.saturating_inc();
+					}
+					removed_stake = removed_stake.saturating_add(delegation.stake);
+					false
+				});
+
+				if !removed_stake.is_zero() {
+					if delegations.is_empty() {
+						Delegations::<T>::remove(&candidate);
+					} else {
+						Delegations::<T>::insert(&candidate, delegations);
+					}
+					ProposedCandidates::<T>::mutate(&candidate, |maybe_candidate| {
+						if let Some(proposed_candidate) = maybe_candidate {
+							proposed_candidate.total_stake = proposed_candidate.total_stake.saturating_sub(removed_stake);
+						}
+					});
+					let _ = Pallet::<T>::sort_proposed_candidate(candidate.clone());
+					reads.saturating_accrue(2);
+					writes.saturating_accrue(3);
+				}
+			}
+
+			if unbonded > 0 || released > 0 {
+				log::warn!(
+					target: LOG_TARGET,
+					"Delegations beyond MaxDelegationsPerDelegator: {} unbonded, {} released",
+					unbonded,
+					released,
+				);
+			}
+
+			T::DbWeight::get().reads_writes(reads, writes)
+		}
+
+		/// Every remaining delegation is indexed in the delegator state
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(_state: Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+			for (candidate, delegations) in Delegations::<T>::iter() {
+				for delegation in delegations.iter() {
+					let state = DelegatorState::<T>::get(&delegation.delegator);
+					ensure!(
+						state.iter().any(|c| c.candidate == candidate && c.stake == delegation.stake),
+						"A delegation is missing from the delegator state"
+					);
+				}
+			}
+			Ok(())
+		}
+	}
+}
+
+/// Version 4
+/// Note:
+/// 	The delegations have an auto compound percentage, existing delegations are not
+/// 	compounded.
+pub mod v4 {
+	use crate::pallet::*;
+	use core::marker::PhantomData;
+	use frame_support::{
+		migrations::VersionedMigration,
+		pallet_prelude::*,
+		traits::UncheckedOnRuntimeUpgrade,
+	};
+	use scale_info::prelude::vec::Vec;
+	use sp_runtime::{Percent, Saturating};
+
+	/// Add the auto compound percentage to the delegations (Version 3 to 4)
+	pub type MigrateToV4<T> = VersionedMigration<
+		3,
+		4,
+		UncheckedMigrateToV4<T>,
+		Pallet<T>,
+		<T as frame_system::Config>::DbWeight,
+	>;
+
+	/// Add the auto compound percentage to the delegations
+	/// Note:
+	/// 	1. Every delegation is translated with a zero auto compound.
+	/// 	2. Must run after v3::MigrateToV3, the old layout is defined in v3::Delegations.
+	pub struct UncheckedMigrateToV4<T>(PhantomData<T>);
+
+	impl<T: Config> UncheckedOnRuntimeUpgrade for UncheckedMigrateToV4<T> {
+		fn on_runtime_upgrade() -> Weight {
+			let mut reads: u64 = 0;
+			let mut writes: u64 = 0;
+
+			Delegations::<T>::translate::<
+				BoundedVec<
+					super::v3::Delegation<<T as frame_system::Config>::AccountId, BalanceOf<T>>,
+					<T as Config>::MaxProposedCandidateDelegates
+				>,
+				_
+			>(
+				|_candidate, old_delegations| {
+					reads.saturating_inc();
+					writes.saturating_inc();
+					let delegations: Vec<_> = old_delegations
+						.into_iter()
+						.map(|d| Delegation { delegator: d.delegator, stake: d.stake, auto_compound: Percent::zero() })
+						.collect();
+					Some(BoundedVec::truncate_from(delegations))
+				},
+			);
+
+			T::DbWeight::get().reads_writes(reads, writes)
+		}
+
+		/// The number of delegations
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, sp_runtime::TryRuntimeError> {
+			let count: u32 = super::v3::Delegations::<T>::iter_values().map(|d| d.len() as u32).sum();
+			Ok(count.encode())
+		}
+
+		/// Every delegation is decodable with the new layout and is not compounded
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(state: Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+			let count = u32::decode(&mut &state[..]).map_err(|_| "Failed to decode the delegations count")?;
+			let mut migrated: u32 = 0;
+			for delegations in Delegations::<T>::iter_values() {
+				ensure!(delegations.iter().all(|d| d.auto_compound.is_zero()), "A migrated delegation is compounded");
+				migrated.saturating_accrue(delegations.len() as u32);
+			}
+			ensure!(migrated == count, "Delegations were lost");
+			Ok(())
+		}
+	}
+}
+
+/// Version 5
+/// Note:
+/// 	The Xaver nodes were hard-coded in the runtime and added on initialize while the next block
+/// 	number was not set, they are now set at genesis.  The existing chain is seeded with the
+/// 	Xaver nodes and the unused next block number is removed.
+pub mod v5 {
+	use crate::{pallet::*, LOG_TARGET};
+	use core::marker::PhantomData;
+	use frame_support::{
+		migrations::VersionedMigration,
+		pallet_prelude::*,
+		traits::UncheckedOnRuntimeUpgrade,
+	};
+	use frame_system::pallet_prelude::BlockNumberFor;
+	use scale_info::prelude::vec::Vec;
+	use sp_runtime::Saturating;
+
+	/// Next block number (Removed in version 5)
+	#[frame_support::storage_alias]
+	pub type NextBlockNumber<T: Config> = StorageValue<Pallet<T>, BlockNumberFor<T>, OptionQuery>;
+
+	/// Seed the Xaver nodes and remove the next block number (Version 4 to 5)
+	pub type MigrateToV5<T, XaverNodes> = VersionedMigration<
+		4,
+		5,
+		UncheckedMigrateToV5<T, XaverNodes>,
+		Pallet<T>,
+		<T as frame_system::Config>::DbWeight,
+	>;
+
+	/// Seed the Xaver nodes and remove the next block number
+	/// Note:
+	/// 	1. Every Xaver node that is not yet a desired candidate is added to the desired and the
+	/// 	   waiting candidates, like the genesis config.  Nodes that can not be added are logged.
+	/// 	2. XaverNodes are the accounts of the Xaver nodes previously hard-coded in the runtime.
+	pub struct UncheckedMigrateToV5<T, XaverNodes>(PhantomData<(T, XaverNodes)>);
+
+	impl<T: Config, XaverNodes: Get<Vec<T::AccountId>>> UncheckedOnRuntimeUpgrade for UncheckedMigrateToV5<T, XaverNodes> {
+		fn on_runtime_upgrade() -> Weight {
+			let mut reads: u64 = 0;
+			let mut writes: u64 = 1;
+
+			NextBlockNumber::<T>::kill();
+
+			for node in XaverNodes::get() {
+				reads.saturating_inc();
+				if DesiredCandidates::<T>::get().contains(&node) {
+					continue;
+				}
+				reads.saturating_accrue(2);
+				writes.saturating_accrue(2);
+				if let Err(error) = Pallet::<T>::insert_desired_candidate(node.clone()) {
+					log::warn!(target: LOG_TARGET, "Failed to add the Xaver node {:?}: {:?}", node, error);
+					continue;
+				}
+				let _ = Pallet::<T>::add_waiting_candidate(node);
+			}
+
+			T::DbWeight::get().reads_writes(reads, writes)
+		}
+
+		/// The next block number is removed and every Xaver node is a desired candidate
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(_state: Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+			ensure!(!NextBlockNumber::<T>::exists(), "The next block number still exists");
+			let desired_candidates = DesiredCandidates::<T>::get();
+			for node in XaverNodes::get() {
+				ensure!(desired_candidates.contains(&node), "A Xaver node is not a desired candidate");
+			}
+			Ok(())
+		}
+	}
+}
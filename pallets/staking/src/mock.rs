@@ -40,8 +40,6 @@ use polkadot_runtime_common::{
 };
 use frame_support::traits::VariantCountOf;
 use frame_support::pallet_prelude::DispatchClass;
-use sp_runtime::Percent;
-use sp_runtime::traits::Zero;
 
 pub const SLOT_DURATION: u64 = 6000;
 pub type Balance = u128;
@@ -285,7 +283,7 @@ impl pallet_authorship::Config for Test {
 }
 
 parameter_types! {
-	pub const ExistentialDeposit: u128 = 1;
+	pub static ExistentialDeposit: u128 = 1;
 }
 impl pallet_balances::Config for Test {
 	type MaxLocks = ConstU32<50>;
@@ -352,6 +350,8 @@ impl pallet_asset_rate::Config for Test {
 	type AssetKind = u32;
 	type RuntimeEvent = RuntimeEvent;
 	type WeightInfo = pallet_asset_rate::weights::SubstrateWeight<Test>;
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = ();
 }
 
 pub const MILLICENTS: Balance = 1_000_000_000;
@@ -397,6 +397,8 @@ impl pallet_treasury::Config for Test {
 	type Paymaster = frame_support::traits::tokens::pay::PayAssetFromAccount<pallet_assets::Pallet<Test>, XodeTreasuryAccount>;
 	type BalanceConverter = pallet_asset_rate::Pallet<Test>;
 	type PayoutPeriod = SpendPayoutPeriod;
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = ();
 }
 
 pub struct WeightToFee;
@@ -421,7 +423,7 @@ pub const AUTHOR_SHARE: u32 = 80;
 pub struct DealWithFees<R>(core::marker::PhantomData<R>);
 impl<R> OnUnbalanced<Credit<R::AccountId, pallet_balances::Pallet<R>>> for DealWithFees<R>
 where
-	R: pallet_balances::Config + pallet_authorship::Config + pallet_treasury::Config + crate::Config,
+	R: pallet_balances::Config + pallet_authorship::Config + pallet_treasury::Config + crate::Config<StakingCurrency = pallet_balances::Pallet<R>>,
     <R as frame_system::Config>::AccountId: From<AccountId>,
     <R as frame_system::Config>::AccountId: Into<AccountId>,
 {
@@ -443,72 +445,33 @@ where
 pub struct ToAuthor<R>(core::marker::PhantomData<R>);
 impl<R> OnUnbalanced<Credit<R::AccountId, pallet_balances::Pallet<R>>> for ToAuthor<R>
 where
-    R: pallet_balances::Config + pallet_authorship::Config + crate::Config,
+    R: pallet_balances::Config + pallet_authorship::Config + pallet_treasury::Config + crate::Config<StakingCurrency = pallet_balances::Pallet<R>>,
     <R as frame_system::Config>::AccountId: From<AccountId>,
     <R as frame_system::Config>::AccountId: Into<AccountId>,
 {
     fn on_nonzero_unbalanced(
         amount: Credit<<R as frame_system::Config>::AccountId, pallet_balances::Pallet<R>>,
     ) {
-		println!("Author fee - 1");
         if let Some(author) = <pallet_authorship::Pallet<R>>::author() {
 
-			println!("Author fee - 2");
-			if let Some(candidate) = crate::ProposedCandidates::<R>::get().iter().find(|c| c.who == author) {
-
-				// commission of the delegator
-				println!("Author fee - 3");
-				let commission = Percent::from_percent(candidate.commission.into());
-				if commission.deconstruct() > 0 {
-
-					println!("Author fee - 4");
-					if let Some(delegations) = crate::Delegations::<R>::get(&author) {
-						
-						// remaining amount to be shared
-						let mut remaining_amount = amount;
-
-						// distribute only to delegator with stake > 0
-						println!("Remaining Amount: {:?}", remaining_amount);
-						for (_index, delegation) in delegations.iter().enumerate() {
-							if delegation.stake > Zero::zero() {
-								
-								// stake / total_stake
-								print!("Delegator: {:?}, ", delegation.delegator);
-								let delegator_share_ratio = Percent::from_rational(delegation.stake, candidate.total_stake);
-								print!("Ratio: {:?}, ", delegator_share_ratio);
-								print!("Commission: {:?}, ", commission);
-
-								// delegator_share_amount
-								//let delegator_share_amount = shared_amount.saturating_mul(
-								//	(delegator_share_ratio.deconstruct() / 100).into()
-								//).saturating_mul(
-								//	(commission.deconstruct() / 100).into()
-								//);
-								let ds1 = Perbill::from_percent(delegator_share_ratio.deconstruct() as u32).mul_ceil(remaining_amount.peek());
-								let ds2 = Perbill::from_percent(commission.deconstruct() as u32).mul_ceil(ds1);
-								print!("Delegator share: {:?}, ", ds2);
-
-
-								// extract the delegator_share_amount (share) from the remaining amount, then resolve it.
-								let (share, leftover) = remaining_amount.split(ds2.into());
-								let _ = <pallet_balances::Pallet<R>>::resolve(&delegation.delegator,share,);
-
-								// the remaining amount (left-over) will be given to the next delegator.
-								remaining_amount = leftover; 
-								println!("Remaining amount: {:?}, ", remaining_amount);
-							}
-						}
-
-						// after all its delegator has been paid, the remaining amount will given to the author
-						let _ = <pallet_balances::Pallet<R>>::resolve(&author, remaining_amount);
-					}
-				} else {
-					// if the commission is zero, the author will not share
-					let _ = <pallet_balances::Pallet<R>>::resolve(&author, amount);
-				}
-			} else {
-				// if there is no delegator the author will get everything
-				let _ = <pallet_balances::Pallet<R>>::resolve(&author, amount);
+			// the delegators' portion (commission) is kept in the staking pot and claimed later
+			let portion = crate::Pallet::<R>::delegators_portion(author.clone(), amount.peek());
+			let (shared, remaining_amount) = amount.split(portion);
+
+			let remaining_amount = match <pallet_balances::Pallet<R>>::resolve(&crate::Pallet::<R>::account_id(), shared) {
+				Ok(()) => {
+					// accumulate the reward per stake of the author, constant regardless of the delegators
+					crate::Pallet::<R>::accumulate_rewards(author.clone(), portion);
+					remaining_amount
+				},
+				// if the pot can not receive the shared amount the author will get everything
+				Err(shared) => remaining_amount.merge(shared),
+			};
+
+			// the remaining amount will be given to the author, if the author can not receive it (e.g.
+			// below the existential deposit) it goes to the treasury instead of being dropped
+			if let Err(remaining_amount) = <pallet_balances::Pallet<R>>::resolve(&author, remaining_amount) {
+				ResolveTo::<pallet_treasury::TreasuryAccountId<R>, pallet_balances::Pallet<R>>::on_unbalanced(remaining_amount);
 			}
         }
     }
@@ -531,27 +494,103 @@ parameter_types! {
 	pub const XodeStakingPalletId: PalletId = PalletId(*b"xd/stkng");
 	pub const MaxProposedCandidates: u32 = 100;
 	pub const MaxProposedCandidateDelegates: u32 = 100;
-	pub const Nodes: &'static [&'static str] = &[
-		"0x306721211d5404bd9da88e0204360a1a9ab8b87c66c1bc2fcdd37f3c2222cc20",  	// Charlie
-		"0x90b5ab205c6974c9ea841be688864633dc9ca8a357843eeacf2314649965fe22", 	// Dave 
-		"0xe659a7a1628cdd93febc04a4e0646ea20e9f5f0ce097d9a05290d4a9e054df4e",   // Eve 
-	];
-	pub const MaxStalingPeriod: BlockNumber = MINUTES * 2; 
+	pub const MaxDelegationsPerDelegator: u32 = 10;
+	pub const MinDelegation: Balance = 10;
+	pub const MaxDelegationReleasesPerBlock: u32 = 4;
 	pub const MinProposedCandidateBond: Balance = PICO_UNIT;
+	pub const UnbondingDelay: u32 = 2;
+	pub const CommissionChangeDelay: u32 = 2;
+	pub const MaxCommissionChangePerSession: u8 = 5;
+	pub const MaxUnbondingRequests: u32 = 32;
+	pub const SlashFraction: Perbill = Perbill::from_percent(10);
+	pub const SlashOffencesResetPeriod: u32 = 4;
+	pub const CandidateMetadataDepositBase: Balance = 10;
+	pub const CandidateMetadataDepositPerByte: Balance = 1;
+	pub const MaxCandidateMetadataLength: u32 = 64;
+	pub const BlocksPerYear: u32 = MINUTES * 60 * 24 * 365;
+	pub static AuthoredBlocksHistoryDepth: u32 = 4;
+	pub const MaxMissedSlots: u32 = 2;
+	pub const HeartbeatPriority: u64 = u64::MAX;
+}
+
+impl<C> frame_system::offchain::SendTransactionTypes<C> for Test
+where
+	RuntimeCall: From<C>,
+{
+	type OverarchingCall = RuntimeCall;
+	type Extrinsic = UncheckedExtrinsic;
 }
 
 impl crate::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type MaxProposedCandidates = MaxProposedCandidates;
 	type MaxProposedCandidateDelegates = MaxProposedCandidateDelegates;
-	type XaverNodes = Nodes;
+	type MaxDelegationsPerDelegator = MaxDelegationsPerDelegator;
+	type MinDelegation = MinDelegation;
+	type MaxDelegationReleasesPerBlock = MaxDelegationReleasesPerBlock;
+	type RuntimeHoldReason = RuntimeHoldReason;
 	type StakingCurrency = Balances;
 	type WeightInfo = crate::weights::SubstrateWeight<Test>;
 	type PalletId = XodeStakingPalletId;
-	type MaxStalingPeriod = MaxStalingPeriod;
+	type MaxMissedSlots = MaxMissedSlots;
 	type MinProposedCandidateBond = MinProposedCandidateBond;
+	type UnbondingDelay = UnbondingDelay;
+	type CommissionChangeDelay = CommissionChangeDelay;
+	type MaxCommissionChangePerSession = MaxCommissionChangePerSession;
+	type MaxUnbondingRequests = MaxUnbondingRequests;
+	type SlashFraction = SlashFraction;
+	type SlashOffencesResetPeriod = SlashOffencesResetPeriod;
+	type MetadataDepositBase = CandidateMetadataDepositBase;
+	type MetadataDepositPerByte = CandidateMetadataDepositPerByte;
+	type MaxMetadataLength = MaxCandidateMetadataLength;
+	type BlocksPerYear = BlocksPerYear;
+	type AuthoredBlocksHistoryDepth = AuthoredBlocksHistoryDepth;
+	type OnSlash = ResolveTo<pallet_treasury::TreasuryAccountId<Test>, Balances>;
+	type UpdateOrigin = EnsureRoot<AccountId>;
+	type HeartbeatPriority = HeartbeatPriority;
 }
 
-pub fn test1_ext() -> sp_io::TestExternalities {
-	GenesisConfig::<Test>::default().build_storage().unwrap().into()
+/// Xaver nodes (desired candidates), the first eight bytes of the Aura keys of Charlie, Dave and Eve
+pub const XAVER_NODES: [AccountId; 3] = [
+	0xbd04541d21216730,		// Charlie: 0x306721211d5404bd9da88e0204360a1a9ab8b87c66c1bc2fcdd37f3c2222cc20
+	0xc974695c20abb590,		// Dave: 0x90b5ab205c6974c9ea841be688864633dc9ca8a357843eeacf2314649965fe22
+	0x93dd8c62a1a759e6,		// Eve: 0xe659a7a1628cdd93febc04a4e0646ea20e9f5f0ce097d9a05290d4a9e054df4e
+];
+
+pub fn test1_ext() -> TestExt {
+	let mut storage = GenesisConfig::<Test>::default().build_storage().unwrap();
+	crate::GenesisConfig::<Test> {
+		desired_candidates: XAVER_NODES.to_vec(),
+		..Default::default()
+	}
+	.assimilate_storage(&mut storage)
+	.unwrap();
+	TestExt(storage.into())
+}
+
+/// For the benchmark tests, the heartbeat benchmark signs with a key from the keystore
+#[cfg(feature = "runtime-benchmarks")]
+pub fn benchmark_ext() -> TestExt {
+	use sp_keystore::{testing::MemoryKeystore, KeystoreExt};
+	let mut ext = test1_ext();
+	ext.0.register_extension(KeystoreExt::new(MemoryKeystore::new()));
+	ext
+}
+
+/// Test externalities checking the staking invariants (try_state) after every test
+pub struct TestExt(pub sp_io::TestExternalities);
+
+impl TestExt {
+	pub fn execute_with<R>(&mut self, execute: impl FnOnce() -> R) -> R {
+		self.0.execute_with(|| {
+			let result = execute();
+			XodeStaking::do_try_state().expect("The staking invariants must hold after every test");
+			result
+		})
+	}
+
+	/// For the tests writing the storage directly (e.g. the layout before a migration)
+	pub fn without_try_state(self) -> sp_io::TestExternalities {
+		self.0
+	}
 }
\ No newline at end of file
@@ -0,0 +1,42 @@
+//! # Xode Staking Runtime API
+//!
+//! Read-only access to the staking information for wallets, dashboards and the node RPC.
+
+use codec::Codec;
+use scale_info::prelude::vec::Vec;
+use crate::CandidateSummary;
+use sp_staking::SessionIndex;
+
+sp_api::decl_runtime_apis! {
+	/// The API to query the xode staking pallet
+	pub trait XodeStakingApi<AccountId, Balance, BlockNumber> where
+		AccountId: Codec,
+		Balance: Codec,
+		BlockNumber: Codec,
+	{
+		/// Proposed candidates with their status and rank
+		fn candidates() -> Vec<CandidateSummary<AccountId, Balance, BlockNumber>>;
+
+		/// Delegations (candidate, stake) of a delegator across all candidates
+		fn delegations_of(delegator: AccountId) -> Vec<(AccountId, Balance)>;
+
+		/// Authors of the current session
+		fn session_authors() -> Vec<AccountId>;
+
+		/// Waiting candidates (Desired and proposed candidates queued for the next session)
+		fn waiting_candidates() -> Vec<AccountId>;
+
+		/// Candidates that have authored at least a block in the current session
+		fn actual_authors() -> Vec<AccountId>;
+
+		/// Estimated share of a delegator from the author's portion of the fees (amount),
+		/// given the commission of the candidate
+		fn estimate_delegator_share(candidate: AccountId, delegator: AccountId, amount: Balance) -> Balance;
+
+		/// Authored blocks (session, blocks) of a collator for the sessions kept in the history
+		fn authored_blocks_of(who: AccountId) -> Vec<(SessionIndex, u32)>;
+
+		/// Authored blocks (collator, blocks) of all the collators in a session
+		fn session_authored_blocks(session: SessionIndex) -> Vec<(AccountId, u32)>;
+	}
+}
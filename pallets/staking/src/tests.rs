@@ -1,17 +1,24 @@
-use crate::{mock::*, CandidateInfo, Status,
-	DesiredCandidates, ProposedCandidates, WaitingCandidates,
+use crate::{mock::*, CandidateInfo, Status, Event, HoldReason, Error, Heartbeat,
+	DesiredCandidates, WaitingCandidates, SlashOffences, Delegations, ProposedCandidates, ReceivedHeartbeats,
+	MissedSlots, LastSlot, UnbondingRequests, UnbondingAccounts, RewardPerStake,
 };
 use codec::Encode;
 use frame_support::{
-	dispatch::GetDispatchInfo, traits::{Hooks, OnUnbalanced,}
+	assert_noop, assert_ok, dispatch::GetDispatchInfo, traits::{Hooks, OnUnbalanced, fungible::InspectHold,}
 };
 
 use pallet_session::SessionManager;
 use frame_support::traits::Currency;
 use pallet_transaction_payment::{ChargeTransactionPayment, OnChargeTransaction};
-use sp_core::sr25519;
+use sp_core::{sr25519, Pair};
+use sp_consensus_aura::{sr25519::AuthoritySignature, Slot};
+use sp_runtime::traits::ValidateUnsigned;
+use sp_runtime::transaction_validity::{InvalidTransaction, TransactionSource};
 use sp_runtime::traits::SignedExtension;
 use sp_runtime::traits::Dispatchable;
+use sp_runtime::BuildStorage;
+use sp_runtime::{FixedPointNumber, FixedU128, Perbill, Percent, traits::Zero};
+use frame_support::BoundedVec;
 
 use pallet_transaction_payment::FungibleAdapter;
 
@@ -38,7 +45,7 @@ fn check_staking_storages(
 	println!("Keys {:?}",queued_keys);
 	assert_eq!(queued_keys.len(), number_of_queued_keys);
 
-	let proposed_candidates = ProposedCandidates::<Test>::get();
+	let proposed_candidates = XodeStaking::proposed_candidates();
 	println!("Proposed Candidates {:?}",proposed_candidates);
 	assert_eq!(proposed_candidates.len(), number_of_proposed_candidates);
 
@@ -55,11 +62,47 @@ fn set_new_block_with_author(
 	Authorship::on_initialize(System::block_number());
 	Authorship::on_finalize(System::block_number());
 
+	// The nodes of the session validators are alive (heartbeats)
+	let session_index = pallet_session::CurrentIndex::<Test>::get();
+	for validator in pallet_session::Validators::<Test>::get() {
+		ReceivedHeartbeats::<Test>::insert(session_index, validator, System::block_number());
+	}
+
 	System::set_block_number((block_number).into());
 	XodeStaking::on_initialize(System::block_number());
 	candidate.last_authored = System::block_number();
 }
 
+/// Advance the Aura slots of the session validators, skipping the slot of the validator on every
+/// round so that only the validator misses its slots.
+fn skip_slots_of(validator: AccountId, rounds: u32) {
+	// The noted slots do not credit any author
+	AuthorGiven::clear_author();
+	Authorship::on_finalize(System::block_number());
+
+	let authorities = pallet_aura::Authorities::<Test>::decode_len().unwrap() as u64;
+	let index = pallet_session::Validators::<Test>::get().iter().position(|v| *v == validator).unwrap() as u64;
+	let mut slot = LastSlot::<Test>::get();
+	if slot == 0 {
+		// Nothing is counted for the first noted slot
+		slot = if 1 % authorities == index { 2 } else { 1 };
+		pallet_aura::CurrentSlot::<Test>::put(Slot::from(slot));
+		XodeStaking::on_initialize(System::block_number());
+	}
+	for _ in 0..rounds {
+		// Every slot up to the slot of the validator is noted, then the slot is skipped
+		slot += 1;
+		while slot % authorities != index {
+			pallet_aura::CurrentSlot::<Test>::put(Slot::from(slot));
+			XodeStaking::on_initialize(System::block_number());
+			slot += 1;
+		}
+	}
+	slot += 1;
+	pallet_aura::CurrentSlot::<Test>::put(Slot::from(slot));
+	XodeStaking::on_initialize(System::block_number());
+}
+
 #[test]
 fn test_pallet_xode_staking_process() {
 	test1_ext().execute_with(|| {
@@ -146,7 +189,7 @@ fn test_pallet_xode_staking_process() {
 			let _ = XodeStaking::register_candidate(RuntimeOrigin::signed(account_id));
 		}
 
-		let proposed_candidates = ProposedCandidates::<Test>::get();
+		let proposed_candidates = XodeStaking::proposed_candidates();
 		assert_eq!(proposed_candidates.len(), 2, "The number of proposed candidates should be 2");
 
 		for i in 0..2 {
@@ -172,7 +215,7 @@ fn test_pallet_xode_staking_process() {
 		}
 
 		// Index reverse because of sorting based on bond
-		let proposed_candidates = ProposedCandidates::<Test>::get();
+		let proposed_candidates = XodeStaking::proposed_candidates();
 		assert_eq!(proposed_candidates[0], candidates[1], "Must match");
 		assert_eq!(proposed_candidates[1], candidates[0], "Must match");
 
@@ -204,7 +247,7 @@ fn test_pallet_xode_staking_process() {
 		//    candidates must author a block within Session 2 or it will be set to
 		//    offline.  Todo: Slashed if not authoring.
 		// =======================================================================
-		let proposed_candidates = ProposedCandidates::<Test>::get();
+		let proposed_candidates = XodeStaking::proposed_candidates();
 		let _ = XodeStaking::bond_candidate(RuntimeOrigin::signed(proposed_candidates[1].who), 400);
 
 		// 1_000_000 - 400 = 999_600
@@ -214,22 +257,23 @@ fn test_pallet_xode_staking_process() {
 		candidates[0].last_updated = System::block_number();
 		
 		// Revert to original sort due to an increase of bond
-		let proposed_candidates = ProposedCandidates::<Test>::get();
+		let proposed_candidates = XodeStaking::proposed_candidates();
 		assert_eq!(proposed_candidates[0], candidates[0], "Must match");
 		assert_eq!(proposed_candidates[1], candidates[1], "Must match");
 
 		set_new_block_with_author(&mut candidates[1], System::block_number() + 1);
 		
-		let proposed_candidates = ProposedCandidates::<Test>::get();
+		let proposed_candidates = XodeStaking::proposed_candidates();
 		let _ = XodeStaking::bond_candidate(RuntimeOrigin::signed(proposed_candidates[0].who), 200);
 
-		assert_eq!(Balances::free_balance(&proposed_candidates[0].who), 999_800);
+		// The decreased bond (200) remains reserved until withdrawn after the unbonding delay
+		assert_eq!(Balances::free_balance(&proposed_candidates[0].who), 999_600);
 
 		candidates[0].bond = 200;
 		candidates[0].last_updated = System::block_number();
 
 		// Reversal again due to the decrease of bond
-		let proposed_candidates = ProposedCandidates::<Test>::get();
+		let proposed_candidates = XodeStaking::proposed_candidates();
 		assert_eq!(proposed_candidates[0], candidates[1], "Must match");
 		assert_eq!(proposed_candidates[1], candidates[0], "Must match");
 
@@ -252,7 +296,7 @@ fn test_pallet_xode_staking_process() {
 		candidates[0].status = Status::Authoring;
 		candidates[1].status = Status::Authoring;
 
-		let proposed_candidates = ProposedCandidates::<Test>::get();
+		let proposed_candidates = XodeStaking::proposed_candidates();
 		assert_eq!(proposed_candidates[0], candidates[1], "Must match");
 		assert_eq!(proposed_candidates[1], candidates[0], "Must match");
 		
@@ -317,7 +361,7 @@ fn test_pallet_xode_staking_process() {
 		candidates[1].total_stake = 6_000;
 		candidates[1].last_updated = System::block_number();
 
-		let proposed_candidates = ProposedCandidates::<Test>::get();
+		let proposed_candidates = XodeStaking::proposed_candidates();
 		assert_eq!(proposed_candidates[0], candidates[0]);
 		assert_eq!(proposed_candidates[1], candidates[1]);	
 
@@ -330,7 +374,7 @@ fn test_pallet_xode_staking_process() {
 		candidates[0].total_stake = 3_000;
 		candidates[0].last_updated = System::block_number();
 
-		let proposed_candidates = ProposedCandidates::<Test>::get();
+		let proposed_candidates = XodeStaking::proposed_candidates();
 		assert_eq!(proposed_candidates[0], candidates[1]);
 		assert_eq!(proposed_candidates[1], candidates[0]);
 
@@ -353,15 +397,15 @@ fn test_pallet_xode_staking_process() {
 		candidates[0].status_level = 1;
 		candidates[1].status = Status::Authoring;
 		candidates[1].status_level = 1;
-		let proposed_candidates = ProposedCandidates::<Test>::get();
+		let proposed_candidates = XodeStaking::proposed_candidates();
 		assert_eq!(proposed_candidates[0], candidates[1], "Must match");
 		assert_eq!(proposed_candidates[1], candidates[0], "Must match");
 		
 		// =======================================================================
-		// SCENE 5 (Offline): Within Session 4, 5 and Session 6 initialization
+		// SCENE 5 (Offline): Within Session 4 and Session 5 initialization
 		// ----------------------------------------------------------------------- 
 		// 1. Make one proposed candidate go offline
-		// 2. Downgraded after set to offline after two sessions.
+		// 2. Downgraded after set to offline after one session (it left the waiting list).
 		// 3. Todo: Test to online if the status is not yet downgraded
 		// 4. Todo: If the candidate is offline or in the process of offline. (Separate
 		//          Test).
@@ -378,7 +422,7 @@ fn test_pallet_xode_staking_process() {
 		candidates[1].last_updated = System::block_number();
 
 		// Since we offline, the sort is now reverted back
-		let proposed_candidates = ProposedCandidates::<Test>::get();
+		let proposed_candidates = XodeStaking::proposed_candidates();
 		assert_eq!(proposed_candidates[0], candidates[0]);
 		assert_eq!(proposed_candidates[1], candidates[1]);		
 
@@ -387,21 +431,6 @@ fn test_pallet_xode_staking_process() {
 		XodeStaking::new_session(5);
 		Session::on_initialize(System::block_number()); 
 
-		check_staking_storages(
-			5, 
-			5, 
-			5, 
-			2, 
-			4,
-			5,
-			1,
-		);
-
-		set_new_block_with_author(&mut candidates[0], 6 * MINUTES);
-
-		XodeStaking::new_session(6);
-		Session::on_initialize(System::block_number()); 
-
 		check_staking_storages(
 			5, 
 			4, 
@@ -409,13 +438,13 @@ fn test_pallet_xode_staking_process() {
 			2, 
 			4,
 			5,
-			2,
+			1,
 		);
 
 		candidates[1].status = Status::Queuing;
 		candidates[1].status_level = 0;
 
-		let proposed_candidates = ProposedCandidates::<Test>::get();
+		let proposed_candidates = XodeStaking::proposed_candidates();
 		assert_eq!(proposed_candidates[0], candidates[0]);
 		assert_eq!(proposed_candidates[1], candidates[1]);
 		
@@ -432,7 +461,7 @@ fn test_pallet_xode_staking_process() {
 		candidates[1].offline = false;
 		candidates[1].last_updated = System::block_number();
 
-		let proposed_candidates = ProposedCandidates::<Test>::get();
+		let proposed_candidates = XodeStaking::proposed_candidates();
 		assert_eq!(proposed_candidates[0], candidates[1]);
 		assert_eq!(proposed_candidates[1], candidates[0]);	
 
@@ -453,7 +482,7 @@ fn test_pallet_xode_staking_process() {
 
 		candidates[1].status = Status::Queuing;
 
-		let proposed_candidates = ProposedCandidates::<Test>::get();
+		let proposed_candidates = XodeStaking::proposed_candidates();
 		assert_eq!(proposed_candidates[0], candidates[1]);
 		assert_eq!(proposed_candidates[1], candidates[0]);
 
@@ -474,7 +503,7 @@ fn test_pallet_xode_staking_process() {
 
 		candidates[1].status = Status::Authoring;
 
-		let proposed_candidates = ProposedCandidates::<Test>::get();
+		let proposed_candidates = XodeStaking::proposed_candidates();
 		assert_eq!(proposed_candidates[0], candidates[1]);
 		assert_eq!(proposed_candidates[1], candidates[0]);
 
@@ -495,12 +524,12 @@ fn test_pallet_xode_staking_process() {
 
 		candidates[1].status_level = 1;
 
-		let proposed_candidates = ProposedCandidates::<Test>::get();
+		let proposed_candidates = XodeStaking::proposed_candidates();
 		assert_eq!(proposed_candidates[0], candidates[1]);
 		assert_eq!(proposed_candidates[1], candidates[0]);
 
 		// =======================================================================
-		// SCENE 7 (Leaving): Within Session 9, 10, 11, 12, 13 and Session 14 initialization
+		// SCENE 7 (Leaving): Within Session 9, 10, 11, 12 and Session 14 initialization
 		// ----------------------------------------------------------------------- 
 		// 1. Add a new candidate (Candidate-3), bond then leave
 		// 2. Make sure to first offline the proposed candidate before leaving
@@ -537,7 +566,7 @@ fn test_pallet_xode_staking_process() {
 
 		let _ = XodeStaking::register_candidate(RuntimeOrigin::signed(candidates[2].who));
 
-		let proposed_candidates = ProposedCandidates::<Test>::get();
+		let proposed_candidates = XodeStaking::proposed_candidates();
 		assert_eq!(proposed_candidates.len(), 3, "Increase by 1");
 		assert_eq!(proposed_candidates[2], candidates[2], "Must match");		
 
@@ -553,7 +582,7 @@ fn test_pallet_xode_staking_process() {
 		candidates[2].bond = 200;
 		candidates[2].last_updated = System::block_number();
 
-		let proposed_candidates = ProposedCandidates::<Test>::get();
+		let proposed_candidates = XodeStaking::proposed_candidates();
 		assert_eq!(proposed_candidates[2], candidates[2], "Must match");
 
 		set_new_block_with_author(&mut candidates[0], 10 * MINUTES);
@@ -573,7 +602,7 @@ fn test_pallet_xode_staking_process() {
 
 		candidates[2].status = Status::Waiting;
 
-		let proposed_candidates = ProposedCandidates::<Test>::get();
+		let proposed_candidates = XodeStaking::proposed_candidates();
 		assert_eq!(proposed_candidates[2], candidates[2], "Still waiting, do not leave");
 
 		set_new_block_with_author(&mut candidates[0], 11 * MINUTES);
@@ -595,7 +624,7 @@ fn test_pallet_xode_staking_process() {
 		// level 0.
 		candidates[2].status = Status::Authoring;
 
-		let proposed_candidates = ProposedCandidates::<Test>::get();
+		let proposed_candidates = XodeStaking::proposed_candidates();
 		assert_eq!(proposed_candidates[2], candidates[2], "Status is now authoring level 0 (no queuing).");
 
 		set_new_block_with_author(&mut candidates[1], System::block_number() + 1);
@@ -610,21 +639,6 @@ fn test_pallet_xode_staking_process() {
 		XodeStaking::new_session(12);
 		Session::on_initialize(System::block_number()); 
 
-		check_staking_storages(
-			6, 
-			6, 
-			6, 
-			3, 
-			5,
-			7,
-			3,
-		);	
-
-		set_new_block_with_author(&mut candidates[1], 13 * MINUTES);
-
-		XodeStaking::new_session(12);
-		Session::on_initialize(System::block_number()); 
-
 		check_staking_storages(
 			6, 
 			5, 
@@ -632,13 +646,12 @@ fn test_pallet_xode_staking_process() {
 			3, 
 			5,
 			7,
-			4,
-		);
+			3,
+		);	
 
 		candidates[2].status = Status::Queuing;
-		candidates[2].last_updated = System::block_number(); // Todo: Why?
 
-		let proposed_candidates = ProposedCandidates::<Test>::get();
+		let proposed_candidates = XodeStaking::proposed_candidates();
 		assert_eq!(proposed_candidates[2], candidates[2], "Status is now queuing after being offline.");
 
 		set_new_block_with_author(&mut candidates[1], 14 * MINUTES);
@@ -653,13 +666,12 @@ fn test_pallet_xode_staking_process() {
 			3, 
 			5,
 			7,
-			5,
+			4,
 		);
 
 		candidates[2].status = Status::Waiting;
-		candidates[2].last_updated = System::block_number(); // Todo: Why?
 
-		let proposed_candidates = ProposedCandidates::<Test>::get();
+		let proposed_candidates = XodeStaking::proposed_candidates();
 		assert_eq!(proposed_candidates[2], candidates[2], "Status is now waiting (Ready to leave)");
 
 		set_new_block_with_author(&mut candidates[0], System::block_number() + 1);
@@ -671,7 +683,7 @@ fn test_pallet_xode_staking_process() {
 		candidates[2].leaving = true;
 		candidates[2].last_updated = System::block_number();
 
-		let proposed_candidates = ProposedCandidates::<Test>::get();
+		let proposed_candidates = XodeStaking::proposed_candidates();
 		assert_eq!(proposed_candidates.len(), 3, "The number of proposed candidates is still 3");
 		assert_eq!(proposed_candidates[2], candidates[2], "Must match with the last proposed candidate, because it is leaving.");	
 
@@ -687,7 +699,7 @@ fn test_pallet_xode_staking_process() {
 			2, // Candidate[2] has left the building
 			5,
 			7,
-			6,
+			5,
 		);
 	});
 }
@@ -756,11 +768,15 @@ fn test_pallet_xode_staking_fees_treasury_author_share() {
 		// Deal with fees
 		DealWithFees::<Test>::on_unbalanceds(vec![imbalance.unwrap()].into_iter());
 
-		// 22_114_400 * 20% = 4_422_880
-		assert_eq!(Balances::free_balance(XodeTreasuryAccount::get()), 4_422_880);
-		// 22_114_400 * 80% = 17_691_520
-		// 10_000_000_000 + 17_691_520 = 10_017_691_520
-		assert_eq!(Balances::free_balance(1), 10_017_691_520);
+		// Fee * 20% goes to the treasury
+		let treasury_share = actual_fee * TREASURY_SHARE as u128 / (TREASURY_SHARE + AUTHOR_SHARE) as u128;
+		assert_eq!(Balances::free_balance(XodeTreasuryAccount::get()), treasury_share);
+
+		// The author is not a proposed candidate, it receives the whole remaining fee (80%) and
+		// nothing is kept in the pot nor accumulated
+		assert_eq!(Balances::free_balance(1), 10_000_000_000 + actual_fee - treasury_share);
+		assert_eq!(Balances::free_balance(XodeStaking::account_id()), 0);
+		assert_eq!(RewardPerStake::<Test>::get(1), FixedU128::zero());
 
 	});
 }
@@ -840,12 +856,10 @@ fn test_pallet_xode_staking_fees_author_delegator_share()  {
 		).expect("pre_dispatch error");
 		let post_result = call.clone().dispatch(RuntimeOrigin::signed(2)).expect("dispatch failure");
 		let actual_fee = TransactionPayment::compute_actual_fee(len.try_into().unwrap(), &info, &post_result, 0);
-		// actual fee: 22_114_400
 		println!("Fee: {:?}",actual_fee);
 		
-		// 100_000_000_000_000 - 22_114_400 = 99_999_977_885_600
 		println!("After dispatch: {:?}",Balances::free_balance(2));
-		assert_eq!(Balances::free_balance(&2), 99_999_977_885_600);
+		assert_eq!(Balances::free_balance(&2), 100_000_000_000_000 - actual_fee);
 
 		// Withdraw with DealWithFees implementation on charge transaction
 		type FungibleAdapterT = FungibleAdapter<Balances, DealWithFees<Test>>;
@@ -861,39 +875,62 @@ fn test_pallet_xode_staking_fees_author_delegator_share()  {
 		// Deal with fees
 		DealWithFees::<Test>::on_unbalanceds(vec![imbalance.unwrap()].into_iter());
 
-		// 22_114_400 * 20% = 4_422_880
-		assert_eq!(Balances::free_balance(XodeTreasuryAccount::get()), 4_422_880);
+		// Fee * 20% goes to the treasury
+		let treasury_share = actual_fee * TREASURY_SHARE as u128 / (TREASURY_SHARE + AUTHOR_SHARE) as u128;
+		assert_eq!(Balances::free_balance(XodeTreasuryAccount::get()), treasury_share);
+
+		// Commission = 50%, the delegators' portion of the author share (80%) is kept in the pot
+		let author_share = actual_fee - treasury_share;
+		let portion = Percent::from_percent(50).mul_floor(author_share);
+		assert_eq!(Balances::free_balance(XodeStaking::account_id()), portion);
+
+		// The remaining amount is given to the author
+		assert_eq!(Balances::free_balance(1), 90_000_000_000_000 + author_share - portion);
+
+		// The portion is accumulated per stake of the candidate (Total stake: 150_000_000_000_000)
+		let total_stake: u128 = 150_000_000_000_000;
+		let reward_per_stake = FixedU128::saturating_from_rational(portion, total_stake);
+		assert_eq!(RewardPerStake::<Test>::get(1), reward_per_stake);
+
+		// The delegators claim their share (stake ratio) of the delegators' portion, the reward per
+		// stake is rounded down
+		let mut claimed = 0;
+		for i in 0..5 {
+			let delegator = 11 + i as u64;
+			let share = reward_per_stake.saturating_mul_int(10_000_000_000_000u128 * (i + 1) as u128);
+			let free_balance = Balances::free_balance(delegator);
+			assert_eq!(XodeStaking::pending_rewards_of(delegator, 1), share);
+			assert_ok!(XodeStaking::claim_rewards(RuntimeOrigin::signed(delegator), 1));
+			assert_eq!(Balances::free_balance(delegator), free_balance + share);
+			claimed += share;
+		}
+		assert!(portion - claimed < 5);
 
-		// Starting: 22_114_400 * 80% = 17_691_520
-		// Commission = 50%
-		
-		// Staker 1 Ratio (1/15=6%) = 17_691_520 * 0.5 * 0.06 = 530_746 
-		// 90_000_000_000_000 + 530_746 = 90_000_000_530_746 
-		assert_eq!(Balances::free_balance(11), 90_000_000_530_746);
-
-		// Remaining = 17_691_520 - 530_746 = 17_160_774
-		// Staker 2 Ratio (2/15=13%) = 17_160_774 * 0.5 * 0.13 = 1_115_451 
-		// 80_000_000_000_000 + 1_115_451 = 80_000_001_115_451 
-		assert_eq!(Balances::free_balance(12), 80_000_001_115_451);
-
-		// Remaining = 17_160_774 - 1_115_451 = 16_045_323
-		// Staker 3 Ratio (3/15=20%) = 16_045_323 * 0.5 * 0.20 = 1_604_533 
-		// 70_000_000_000_000 + 1_604_533 = 70_000_001_604_533
-		assert_eq!(Balances::free_balance(13), 70_000_001_604_533);		
-
-		// Remaining = 16_045_323 - 1_604_533 = 14_440_790
-		// Staker 4 Ratio (4/15=26%) = 14_440_790 * 0.5 * 0.26 = 1_877_303 
-		// 60_000_000_000_000 + 1_877_303 = 60_000_001_877_303
-		assert_eq!(Balances::free_balance(14), 60_000_001_877_303);			
-
-		// Remaining = 14_440_790 - 1_877_303 = 12_563_487
-		// Staker 5 Ratio (5/15=33%) = 12_563_487 * 0.5 * 0.33 = 2_072_976 
-		// 50_000_000_000_000 + 2_072_976 = 50_000_002_072_976
-		assert_eq!(Balances::free_balance(15), 50_000_002_072_976);		
-
-		// Remaining = 12_563_487 - 2_072_976 = 10_490_511
-		// 90_000_000_000_000 + 10_490_511 = 90_000_010_490_511
-		assert_eq!(Balances::free_balance(1), 90_000_010_490_511);
+		// The rounding leftover stays in the pot
+		assert_eq!(Balances::free_balance(XodeStaking::account_id()), portion - claimed);
+	});
+}
+
+#[test]
+fn test_pallet_xode_staking_fees_author_below_existential_deposit() {
+	use frame_support::traits::fungible::Balanced;
+
+	test1_ext().execute_with(|| {
+		System::set_block_number(1);
+		ExistentialDeposit::set(1_000);
+		let _ = Balances::deposit_creating(&XodeTreasuryAccount::get(), 1_000);
+
+		AuthorGiven::set_author(1);
+		Authorship::on_initialize(1);
+		assert_eq!(Authorship::author(), Some(1));
+
+		// The author has no balance and its share is below the existential deposit, the share goes
+		// to the treasury instead of being dropped
+		let total_issuance = Balances::total_issuance();
+		ToAuthor::<Test>::on_unbalanced(<Balances as Balanced<AccountId>>::issue(500));
+		assert_eq!(Balances::free_balance(1), 0);
+		assert_eq!(Balances::free_balance(XodeTreasuryAccount::get()), 1_500);
+		assert_eq!(Balances::total_issuance(), total_issuance + 500);
 	});
 }
 
@@ -960,7 +997,7 @@ fn test_pallet_xode_staking_author_staling() {
 			let _ = XodeStaking::register_candidate(RuntimeOrigin::signed(account_id));
 		}
 	
-		let proposed_candidates = ProposedCandidates::<Test>::get();
+		let proposed_candidates = XodeStaking::proposed_candidates();
 		assert_eq!(proposed_candidates.len(), 3, "The number of proposed candidates should be 3");
 	
 		for i in 0..3 {
@@ -986,7 +1023,7 @@ fn test_pallet_xode_staking_author_staling() {
 		}
 	
 		// Index reverse because of sorting based on bond
-		let proposed_candidates = ProposedCandidates::<Test>::get();
+		let proposed_candidates = XodeStaking::proposed_candidates();
 		assert_eq!(proposed_candidates[0], candidates[2], "Must match");
 		assert_eq!(proposed_candidates[1], candidates[1], "Must match");
 		assert_eq!(proposed_candidates[2], candidates[0], "Must match");
@@ -1038,6 +1075,13 @@ fn test_pallet_xode_staking_author_staling() {
 
 		set_new_block_with_author(&mut candidates[0], 5 * MINUTES);
 
+		// candidates[2] missed its slots in the session
+		skip_slots_of(candidates[2].who, MaxMissedSlots::get() + 1);
+		assert_eq!(
+			MissedSlots::<Test>::get(pallet_session::CurrentIndex::<Test>::get(), candidates[2].who),
+			MaxMissedSlots::get() + 1
+		);
+
 		XodeStaking::new_session(5);
 		Session::on_initialize(System::block_number()); 
 
@@ -1046,17 +1090,414 @@ fn test_pallet_xode_staking_author_staling() {
 			6, 
 			6, 
 			3, 
-			5,	// candidates[2] is staling
+			5,	// candidates[2] is offline
 			8,
 			5,
 		);
 
+		// The offline candidate is slashed by 10% of the bond (300) and the proceeds go to the treasury
+		candidates[2].bond = 270;
 		candidates[2].offline = true;
 		candidates[2].last_updated = System::block_number();
 		candidates[2].status = Status::Authoring;
 
-		let proposed_candidates = ProposedCandidates::<Test>::get();
-		assert_eq!(proposed_candidates[0], candidates[2], "Must match");
+		// The offline candidate is sorted last
+		let proposed_candidates = XodeStaking::proposed_candidates();
+		assert_eq!(proposed_candidates[2], candidates[2], "Must match");
+		assert_eq!(Balances::reserved_balance(&candidates[2].who), 270);
+		assert_eq!(Balances::free_balance(&XodeTreasuryAccount::get()), 30);
+		assert_eq!(SlashOffences::<Test>::get(&candidates[2].who), 1);
+		System::assert_has_event(RuntimeEvent::XodeStaking(Event::CandidateSlashed { who: candidates[2].who, amount: 30 }));
+	});
+}
+
+#[test]
+fn test_pallet_xode_staking_slash_candidate_escalates() {
+	test1_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		let candidate = 1;
+		let _ = Balances::deposit_creating(&candidate, 1_000_000);
+		assert_ok!(XodeStaking::register_candidate(RuntimeOrigin::signed(candidate)));
+		assert_ok!(XodeStaking::bond_candidate(RuntimeOrigin::signed(candidate), 1_000));
+
+		// First offence: 10% of 1_000
+		let mut candidate_info = XodeStaking::proposed_candidates()[0].clone();
+		XodeStaking::slash_candidate(&mut candidate_info);
+		ProposedCandidates::<Test>::insert(&candidate, candidate_info.clone());
+		assert_eq!(candidate_info.bond, 900);
+		assert_eq!(Balances::reserved_balance(&candidate), 900);
+
+		// Second offence: 20% of 900
+		XodeStaking::slash_candidate(&mut candidate_info);
+		ProposedCandidates::<Test>::insert(&candidate, candidate_info.clone());
+		assert_eq!(candidate_info.bond, 720);
+		assert_eq!(Balances::reserved_balance(&candidate), 720);
+
+		assert_eq!(SlashOffences::<Test>::get(&candidate), 2);
+		assert_eq!(Balances::free_balance(&XodeTreasuryAccount::get()), 280);
+	});
+}
+
+#[test]
+fn test_pallet_xode_staking_slash_candidate_below_minimum_bond() {
+	test1_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		let candidate = 1;
+		let _ = Balances::deposit_creating(&candidate, 1_000_000);
+		assert_ok!(XodeStaking::register_candidate(RuntimeOrigin::signed(candidate)));
+		assert_ok!(XodeStaking::bond_candidate(RuntimeOrigin::signed(candidate), 1_000));
+
+		// A slash that keeps the bond above the minimum does not change the status
+		let mut candidate_info = XodeStaking::proposed_candidates()[0].clone();
+		XodeStaking::slash_candidate(&mut candidate_info);
+		ProposedCandidates::<Test>::insert(&candidate, candidate_info.clone());
+		assert_eq!(candidate_info.bond, 900);
+		assert!(!candidate_info.offline);
+
+		// Tenth offence: 100% of 900, the bond falls below the minimum bond
+		SlashOffences::<Test>::insert(&candidate, 9);
+		XodeStaking::slash_candidate(&mut candidate_info);
+		ProposedCandidates::<Test>::insert(&candidate, candidate_info.clone());
+		assert_eq!(candidate_info.bond, 0);
+		assert!(candidate_info.bond <= MinProposedCandidateBond::get());
+		assert!(candidate_info.offline);
+
+		// The candidate cannot be online again until the bond is topped up
+		assert_noop!(
+			XodeStaking::online_candidate(RuntimeOrigin::signed(candidate)),
+			Error::<Test>::ProposedCandidateInsufficientBond
+		);
+		assert_ok!(XodeStaking::bond_candidate(RuntimeOrigin::signed(candidate), 1_000));
+		assert_ok!(XodeStaking::online_candidate(RuntimeOrigin::signed(candidate)));
+		assert!(!ProposedCandidates::<Test>::get(&candidate).unwrap().offline);
+	});
+}
+
+#[test]
+fn test_pallet_xode_staking_slash_candidate_offences_reset() {
+	test1_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		let candidate = 1;
+		let _ = Balances::deposit_creating(&candidate, 1_000_000);
+		assert_ok!(XodeStaking::register_candidate(RuntimeOrigin::signed(candidate)));
+		assert_ok!(XodeStaking::bond_candidate(RuntimeOrigin::signed(candidate), 1_000));
+
+		// First offence at session 1: 10% of 1_000
+		pallet_session::CurrentIndex::<Test>::put(1);
+		let mut candidate_info = XodeStaking::proposed_candidates()[0].clone();
+		XodeStaking::slash_candidate(&mut candidate_info);
+		ProposedCandidates::<Test>::insert(&candidate, candidate_info.clone());
+		assert_eq!(candidate_info.bond, 900);
+
+		// Second offence within the reset period (4 sessions): 20% of 900
+		pallet_session::CurrentIndex::<Test>::put(5);
+		XodeStaking::slash_candidate(&mut candidate_info);
+		ProposedCandidates::<Test>::insert(&candidate, candidate_info.clone());
+		assert_eq!(candidate_info.bond, 720);
+		assert_eq!(SlashOffences::<Test>::get(&candidate), 2);
+
+		// No offence for more than the reset period, the offences start over: 10% of 720
+		pallet_session::CurrentIndex::<Test>::put(10);
+		XodeStaking::slash_candidate(&mut candidate_info);
+		ProposedCandidates::<Test>::insert(&candidate, candidate_info.clone());
+		assert_eq!(candidate_info.bond, 648);
+		assert_eq!(SlashOffences::<Test>::get(&candidate), 1);
+	});
+}
+
+#[test]
+fn test_pallet_xode_staking_slash_candidate_delegators() {
+	test1_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		let candidate = 1;
+		let delegators = [11, 12];
+		let _ = Balances::deposit_creating(&candidate, 1_000_000);
+		assert_ok!(XodeStaking::register_candidate(RuntimeOrigin::signed(candidate)));
+		assert_ok!(XodeStaking::bond_candidate(RuntimeOrigin::signed(candidate), 1_000));
+		for (i, delegator) in delegators.iter().enumerate() {
+			let _ = Balances::deposit_creating(delegator, 1_000_000);
+			assert_ok!(XodeStaking::stake_candidate(RuntimeOrigin::signed(*delegator), candidate, (i as u128 + 1) * 500));
+		}
+
+		let mut candidate_info = XodeStaking::proposed_candidates()[0].clone();
+		assert_eq!(candidate_info.total_stake, 1_500);
+
+		// The delegations lose the same 10% as the bond
+		XodeStaking::slash_candidate(&mut candidate_info);
+		ProposedCandidates::<Test>::insert(&candidate, candidate_info.clone());
+		assert_eq!(candidate_info.bond, 900);
+		assert_eq!(candidate_info.total_stake, 1_350);
+
+		let delegations = Delegations::<Test>::get(&candidate).unwrap();
+		assert_eq!(delegations[0].stake, 450);
+		assert_eq!(delegations[1].stake, 900);
+		assert_eq!(Balances::reserved_balance(&delegators[0]), 450);
+		assert_eq!(Balances::reserved_balance(&delegators[1]), 900);
+
+		System::assert_has_event(RuntimeEvent::XodeStaking(Event::DelegatorSlashed { who: delegators[0], candidate, amount: 50 }));
+		System::assert_has_event(RuntimeEvent::XodeStaking(Event::DelegatorSlashed { who: delegators[1], candidate, amount: 100 }));
+		assert_eq!(Balances::free_balance(&XodeTreasuryAccount::get()), 250);
+	});
+}
+
+#[test]
+fn test_pallet_xode_staking_slash_candidate_unbonding() {
+	test1_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		let candidate = 1;
+		let delegator = 11;
+		let _ = Balances::deposit_creating(&candidate, 1_000_000);
+		let _ = Balances::deposit_creating(&delegator, 1_000_000);
+		assert_ok!(XodeStaking::register_candidate(RuntimeOrigin::signed(candidate)));
+		assert_ok!(XodeStaking::bond_candidate(RuntimeOrigin::signed(candidate), 1_000));
+		assert_ok!(XodeStaking::stake_candidate(RuntimeOrigin::signed(delegator), candidate, 500));
+
+		// Decrease the bond and un-stake right before the slash
+		assert_ok!(XodeStaking::bond_candidate(RuntimeOrigin::signed(candidate), 400));
+		assert_ok!(XodeStaking::unstake_candidate(RuntimeOrigin::signed(delegator), candidate));
+
+		// The unbonding amounts lose the same 10% as the bond
+		let mut candidate_info = XodeStaking::proposed_candidates()[0].clone();
+		XodeStaking::slash_candidate(&mut candidate_info);
+		ProposedCandidates::<Test>::insert(&candidate, candidate_info.clone());
+		assert_eq!(candidate_info.bond, 360);
+
+		assert_eq!(UnbondingRequests::<Test>::get(&candidate)[0].amount, 540);
+		assert_eq!(UnbondingRequests::<Test>::get(&delegator)[0].amount, 450);
+		assert_eq!(Balances::balance_on_hold(&HoldReason::CandidateBond.into(), &candidate), 900);
+		assert_eq!(Balances::balance_on_hold(&HoldReason::Delegation.into(), &delegator), 450);
+
+		System::assert_has_event(RuntimeEvent::XodeStaking(Event::UnbondingSlashed { who: candidate, candidate, amount: 60 }));
+		System::assert_has_event(RuntimeEvent::XodeStaking(Event::UnbondingSlashed { who: delegator, candidate, amount: 50 }));
+		assert_eq!(Balances::free_balance(&XodeTreasuryAccount::get()), 150);
+	});
+}
+
+#[test]
+fn test_pallet_xode_staking_slash_unbonding_reads_the_candidate_only() {
+	test1_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		let candidates = [1, 2];
+		let delegator = 11;
+		let _ = Balances::deposit_creating(&delegator, 1_000_000);
+		for candidate in candidates {
+			let _ = Balances::deposit_creating(&candidate, 1_000_000);
+			assert_ok!(XodeStaking::register_candidate(RuntimeOrigin::signed(candidate)));
+			assert_ok!(XodeStaking::bond_candidate(RuntimeOrigin::signed(candidate), 1_000));
+			assert_ok!(XodeStaking::stake_candidate(RuntimeOrigin::signed(delegator), candidate, 500));
+			assert_ok!(XodeStaking::unstake_candidate(RuntimeOrigin::signed(delegator), candidate));
+		}
+		assert!(UnbondingAccounts::<Test>::contains_key(&candidates[0], &delegator));
+		assert!(UnbondingAccounts::<Test>::contains_key(&candidates[1], &delegator));
+
+		// Only the request from the first candidate is slashed, one account is read
+		let weight = XodeStaking::slash_unbonding(candidates[0], Perbill::from_percent(100));
+		let requests = UnbondingRequests::<Test>::get(&delegator);
+		assert_eq!(requests.len(), 1);
+		assert_eq!(requests[0].candidate, candidates[1]);
+		assert_eq!(requests[0].amount, 500);
+		assert!(!UnbondingAccounts::<Test>::contains_key(&candidates[0], &delegator));
+		assert!(UnbondingAccounts::<Test>::contains_key(&candidates[1], &delegator));
+		assert_eq!(weight, RocksDbWeight::get().reads_writes(4, 4));
+
+		// Nothing is indexed under a candidate without unbonding requests
+		assert!(XodeStaking::slash_unbonding(3, Perbill::from_percent(100)).is_zero());
+	});
+}
+
+/// Set the collators as the session validators and Aura authorities, returns their Aura keys
+fn set_aura_authorities(collators: &[AccountId]) -> Vec<sr25519::Pair> {
+	let pairs: Vec<sr25519::Pair> = collators
+		.iter()
+		.map(|collator| sr25519::Pair::from_seed(&[*collator as u8; 32]))
+		.collect();
+	pallet_aura::Authorities::<Test>::put(BoundedVec::truncate_from(
+		pairs.iter().map(|pair| pair.public().into()).collect::<Vec<_>>()
+	));
+	pallet_session::Validators::<Test>::put(collators.to_vec());
+	pairs
+}
+
+fn sign_heartbeat(pair: &sr25519::Pair, heartbeat: &Heartbeat<BlockNumber>) -> AuthoritySignature {
+	pair.sign(&heartbeat.encode()).into()
+}
+
+#[test]
+fn test_pallet_xode_staking_heartbeat_works() {
+	test1_ext().execute_with(|| {
+		System::set_block_number(1);
+		let collators = [1, 2];
+		let pairs = set_aura_authorities(&collators);
+
+		let heartbeat = Heartbeat { block_number: 1, session_index: 0, authority_index: 0 };
+		let signature = sign_heartbeat(&pairs[0], &heartbeat);
+
+		// Only the Aura key at the authority index can sign the heartbeat
+		let call = crate::Call::<Test>::heartbeat { heartbeat: heartbeat.clone(), signature: signature.clone() };
+		assert!(XodeStaking::validate_unsigned(TransactionSource::External, &call).is_ok());
+		let forged = crate::Call::<Test>::heartbeat { 
+			heartbeat: heartbeat.clone(), 
+			signature: sign_heartbeat(&pairs[1], &heartbeat), 
+		};
+		assert_eq!(
+			XodeStaking::validate_unsigned(TransactionSource::External, &forged),
+			Err(InvalidTransaction::BadProof.into())
+		);
+
+		assert_ok!(XodeStaking::heartbeat(RuntimeOrigin::none(), heartbeat.clone(), signature.clone()));
+		assert_eq!(ReceivedHeartbeats::<Test>::get(0, collators[0]), Some(1));
+		System::assert_last_event(RuntimeEvent::XodeStaking(Event::HeartbeatReceived { who: collators[0], session: 0 }));
+
+		// Once per session
+		assert_noop!(
+			XodeStaking::heartbeat(RuntimeOrigin::none(), heartbeat.clone(), signature),
+			Error::<Test>::HeartbeatAlreadyReceived
+		);
+		assert_eq!(
+			XodeStaking::validate_unsigned(TransactionSource::External, &call),
+			Err(InvalidTransaction::Stale.into())
+		);
+
+		// Only for the current session and the current authorities
+		let heartbeat = Heartbeat { block_number: 1, session_index: 1, authority_index: 1 };
+		assert_noop!(
+			XodeStaking::heartbeat(RuntimeOrigin::none(), heartbeat.clone(), sign_heartbeat(&pairs[1], &heartbeat)),
+			Error::<Test>::HeartbeatInvalidSession
+		);
+		let heartbeat = Heartbeat { block_number: 1, session_index: 0, authority_index: 2 };
+		assert_noop!(
+			XodeStaking::heartbeat(RuntimeOrigin::none(), heartbeat.clone(), sign_heartbeat(&pairs[1], &heartbeat)),
+			Error::<Test>::HeartbeatInvalidAuthority
+		);
+	});
+}
+
+#[test]
+fn test_pallet_xode_staking_heartbeat_prevents_slashing() {
+	test1_ext().execute_with(|| {
+		System::set_block_number(1);
+		let collators = [1, 2, 3];
+		for collator in collators.iter() {
+			let _ = Balances::deposit_creating(collator, 1_000_000);
+			assert_ok!(XodeStaking::register_candidate(RuntimeOrigin::signed(*collator)));
+			assert_ok!(XodeStaking::bond_candidate(RuntimeOrigin::signed(*collator), 1_000));
+		}
+		let pairs = set_aura_authorities(&collators);
+
+		// The first collator sends a heartbeat, the second one authored a block and the last one
+		// stayed silent without missing any slot
+		let heartbeat = Heartbeat { block_number: 1, session_index: 0, authority_index: 0 };
+		assert_ok!(XodeStaking::heartbeat(RuntimeOrigin::none(), heartbeat.clone(), sign_heartbeat(&pairs[0], &heartbeat)));
+		assert_ok!(XodeStaking::add_author(collators[1]));
+
+		assert_ok!(XodeStaking::slashed_authors());
+
+		for collator in collators.iter().take(2) {
+			let online = ProposedCandidates::<Test>::get(collator).unwrap();
+			assert_eq!(online.bond, 1_000);
+			assert!(!online.offline);
+		}
+
+		let offline = ProposedCandidates::<Test>::get(collators[2]).unwrap();
+		assert_eq!(offline.bond, 900);
+		assert!(offline.offline);
+		assert_eq!(SlashOffences::<Test>::get(collators[2]), 1);
+	});
+}
+
+#[test]
+fn test_pallet_xode_staking_missed_slots_slash_authoring_collator() {
+	test1_ext().execute_with(|| {
+		System::set_block_number(1);
+		let collators = [1, 2, 3];
+		for collator in collators.iter() {
+			let _ = Balances::deposit_creating(collator, 1_000_000);
+			assert_ok!(XodeStaking::register_candidate(RuntimeOrigin::signed(*collator)));
+			assert_ok!(XodeStaking::bond_candidate(RuntimeOrigin::signed(*collator), 1_000));
+		}
+		let _ = set_aura_authorities(&collators);
+
+		// The first collator authored once but missed more than the maximum missed slots
+		assert_ok!(XodeStaking::add_author(collators[0]));
+		skip_slots_of(collators[0], MaxMissedSlots::get() + 1);
+
+		assert_ok!(XodeStaking::slashed_authors());
+
+		let offline = ProposedCandidates::<Test>::get(collators[0]).unwrap();
+		assert_eq!(offline.bond, 900);
+		assert!(offline.offline);
+		assert_eq!(SlashOffences::<Test>::get(collators[0]), 1);
+	});
+}
+
+#[test]
+fn test_pallet_xode_staking_missed_slots_round_robin() {
+	test1_ext().execute_with(|| {
+		System::set_block_number(1);
+		let collators = [1, 2, 3];
+		let _ = set_aura_authorities(&collators);
+
+		// The missed slots are noted in on_initialize, without any author
+		AuthorGiven::clear_author();
+
+		// Nothing is counted for the first noted slot
+		pallet_aura::CurrentSlot::<Test>::put(Slot::from(100));
+		XodeStaking::on_initialize(System::block_number());
+		assert_eq!(LastSlot::<Test>::get(), 100);
+
+		pallet_aura::CurrentSlot::<Test>::put(Slot::from(101));
+		XodeStaking::on_initialize(System::block_number());
+		assert_eq!(MissedSlots::<Test>::iter_prefix(0).count(), 0);
+
+		// Slots 102 to 105 are skipped: 102 and 105 belong to the first authority, 103 to the 
+		// second and 104 to the third
+		pallet_aura::CurrentSlot::<Test>::put(Slot::from(106));
+		XodeStaking::on_initialize(System::block_number());
+		assert_eq!(MissedSlots::<Test>::get(0, collators[0]), 2);
+		assert_eq!(MissedSlots::<Test>::get(0, collators[1]), 1);
+		assert_eq!(MissedSlots::<Test>::get(0, collators[2]), 1);
+		assert_eq!(LastSlot::<Test>::get(), 106);
+
+		// A long gap is shared without iterating every slot (slots 107 to 1_106)
+		pallet_aura::CurrentSlot::<Test>::put(Slot::from(1_107));
+		XodeStaking::on_initialize(System::block_number());
+		assert_eq!(MissedSlots::<Test>::get(0, collators[0]), 2 + 333);
+		assert_eq!(MissedSlots::<Test>::get(0, collators[1]), 1 + 333);
+		assert_eq!(MissedSlots::<Test>::get(0, collators[2]), 1 + 334);
+
+		// The missed slots are pruned with the authored blocks history
+		XodeStaking::prune_authored_blocks(AuthoredBlocksHistoryDepth::get() - 1);
+		assert_eq!(MissedSlots::<Test>::iter_prefix(0).count(), 0);
+	});
+}
+
+#[test]
+fn test_pallet_xode_staking_missed_slots_weight() {
+	test1_ext().execute_with(|| {
+		System::set_block_number(1);
+		let collators = [1, 2, 3];
+		let _ = set_aura_authorities(&collators);
+		let db_weight = RocksDbWeight::get();
+
+		pallet_aura::CurrentSlot::<Test>::put(Slot::from(100));
+		let _ = XodeStaking::note_missed_slots();
+
+		// No missed slot: CurrentSlot, LastSlot and Authorities only
+		pallet_aura::CurrentSlot::<Test>::put(Slot::from(101));
+		assert_eq!(XodeStaking::note_missed_slots(), db_weight.reads_writes(3, 1));
+
+		// Two collators missed a slot: CurrentIndex, Validators and their missed slots
+		pallet_aura::CurrentSlot::<Test>::put(Slot::from(104));
+		assert_eq!(
+			XodeStaking::note_missed_slots(), 
+			db_weight.reads_writes(3, 1) + db_weight.reads(2) + db_weight.reads_writes(2, 2)
+		);
 	});
 }
 
@@ -1106,4 +1547,422 @@ fn test_pallet_xode_staking_left() {
 		let desired_candidates = DesiredCandidates::<Test>::get();
 		assert_eq!(desired_candidates.len(), 3, "There should be exactly three desired candidates");
 	});
-}
\ No newline at end of file
+}
+
+#[test]
+fn test_pallet_xode_staking_migrate_reserves_to_holds() {
+	use frame_support::traits::{
+		fungible::InspectHold, GetStorageVersion, OnRuntimeUpgrade, ReservableCurrency, StorageVersion,
+	};
+	use crate::{migrations::{v1::{self, MigrateToV1}, v3::{self, Delegation}}, HoldReason};
+
+	test1_ext().without_try_state().execute_with(|| {
+		let candidate = 1;
+		let delegator = 11;
+		let _ = Balances::deposit_creating(&candidate, 1_000_000);
+		let _ = Balances::deposit_creating(&delegator, 1_000_000);
+
+		// Storage layout before the migration (reserves)
+		StorageVersion::new(0).put::<XodeStaking>();
+		let candidates = vec![CandidateInfo { who: candidate, bond: 200, total_stake: 300, status: Status::Online, ..Default::default() }];
+		v1::ProposedCandidates::<Test>::put(BoundedVec::try_from(candidates).unwrap());
+		assert_ok!(Balances::reserve(&candidate, 200));
+		let delegations: BoundedVec<_, MaxProposedCandidateDelegates> = vec![Delegation { delegator, stake: 300 }].try_into().unwrap();
+		v3::Delegations::<Test>::insert(&candidate, delegations);
+		assert_ok!(Balances::reserve(&delegator, 300));
+
+		MigrateToV1::<Test, Balances>::on_runtime_upgrade();
+
+		assert_eq!(XodeStaking::on_chain_storage_version(), 1);
+		assert_eq!(Balances::reserved_balance(&candidate), 200);
+		assert_eq!(Balances::balance_on_hold(&HoldReason::CandidateBond.into(), &candidate), 200);
+		assert_eq!(Balances::reserved_balance(&delegator), 300);
+		assert_eq!(Balances::balance_on_hold(&HoldReason::Delegation.into(), &delegator), 300);
+
+		// Running it again has no effect
+		MigrateToV1::<Test, Balances>::on_runtime_upgrade();
+		assert_eq!(Balances::balance_on_hold(&HoldReason::CandidateBond.into(), &candidate), 200);
+	});
+}
+
+#[test]
+fn test_pallet_xode_staking_migrate_proposed_candidates_to_map() {
+	use frame_support::traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion};
+	use crate::{migrations::{v1, v2::MigrateToV2}, ProposedCandidates, SortedCandidates};
+
+	test1_ext().without_try_state().execute_with(|| {
+		// Storage layout before the migration (a single vector of candidates)
+		StorageVersion::new(1).put::<XodeStaking>();
+		let candidates = vec![
+			CandidateInfo { who: 1, bond: 100, status: Status::Online, ..Default::default() },
+			CandidateInfo { who: 2, bond: 300, offline: true, ..Default::default() },
+			CandidateInfo { who: 3, bond: 150, total_stake: 50, status: Status::Online, ..Default::default() },
+		];
+		v1::ProposedCandidates::<Test>::put(BoundedVec::try_from(candidates).unwrap());
+
+		MigrateToV2::<Test>::on_runtime_upgrade();
+
+		assert_eq!(XodeStaking::on_chain_storage_version(), 2);
+		assert!(!v1::ProposedCandidates::<Test>::exists());
+		assert_eq!(ProposedCandidates::<Test>::get(&2).unwrap().bond, 300);
+		assert_eq!(ProposedCandidates::<Test>::get(&3).unwrap().total_stake, 50);
+
+		// Online first, then the highest bond + total stake
+		let sorted: Vec<_> = SortedCandidates::<Test>::get().iter().map(|c| c.who).collect();
+		assert_eq!(sorted, vec![3, 1, 2]);
+
+		// Running it again has no effect
+		MigrateToV2::<Test>::on_runtime_upgrade();
+		assert_eq!(SortedCandidates::<Test>::get().len(), 3);
+	});
+}
+
+#[test]
+fn test_pallet_xode_staking_migrate_delegator_state() {
+	use frame_support::traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion};
+	use crate::{migrations::v3::{self, MigrateToV3, Delegation}, DelegatorState};
+
+	test1_ext().without_try_state().execute_with(|| {
+		// Storage layout before the migration (delegations only)
+		StorageVersion::new(2).put::<XodeStaking>();
+		v3::Delegations::<Test>::insert(&1, BoundedVec::try_from(vec![
+			Delegation { delegator: 11, stake: 100 },
+			Delegation { delegator: 12, stake: 200 },
+		]).unwrap());
+		v3::Delegations::<Test>::insert(&2, BoundedVec::try_from(vec![
+			Delegation { delegator: 11, stake: 300 },
+		]).unwrap());
+
+		MigrateToV3::<Test>::on_runtime_upgrade();
+
+		assert_eq!(XodeStaking::on_chain_storage_version(), 3);
+		let mut delegations = XodeStaking::delegations_of(11);
+		delegations.sort();
+		assert_eq!(delegations, vec![(1, 100), (2, 300)]);
+		assert_eq!(DelegatorState::<Test>::get(12).len(), 1);
+	});
+}
+
+#[test]
+fn test_pallet_xode_staking_migrate_delegator_state_over_limit() {
+	use frame_support::traits::{OnRuntimeUpgrade, StorageVersion};
+	use crate::{migrations::v3::{self, MigrateToV3, Delegation}, DelegatorState};
+
+	test1_ext().without_try_state().execute_with(|| {
+		// Storage layout before the migration, the delegator staked on one candidate more than
+		// the maximum delegations per delegator
+		StorageVersion::new(2).put::<XodeStaking>();
+		let max = MaxDelegationsPerDelegator::get() as u64;
+		for candidate in 1..=max + 1 {
+			v3::Delegations::<Test>::insert(&candidate, BoundedVec::try_from(vec![
+				Delegation { delegator: 11, stake: 100 },
+			]).unwrap());
+		}
+
+		MigrateToV3::<Test>::on_runtime_upgrade();
+
+		// The delegation beyond the limit is removed and scheduled for unbonding
+		assert_eq!(DelegatorState::<Test>::get(11).len() as u64, max);
+		assert_eq!(v3::Delegations::<Test>::iter_keys().count() as u64, max);
+		let requests = UnbondingRequests::<Test>::get(11);
+		assert_eq!(requests.len(), 1);
+		assert_eq!(requests[0].amount, 100);
+		assert!(!DelegatorState::<Test>::get(11).iter().any(|c| c.candidate == requests[0].candidate));
+		assert!(!v3::Delegations::<Test>::contains_key(requests[0].candidate));
+	});
+}
+
+#[test]
+fn test_pallet_xode_staking_migrate_delegator_state_over_limit_unbonding_full() {
+	use frame_support::traits::{OnRuntimeUpgrade, StorageVersion, fungible::{InspectHold, MutateHold}};
+	use crate::{migrations::v3::{self, MigrateToV3, Delegation}, DelegatorState, UnbondingRequest};
+
+	test1_ext().without_try_state().execute_with(|| {
+		// Storage layout before the migration, the delegator staked on one candidate more than
+		// the maximum delegations per delegator and its unbonding requests are full
+		StorageVersion::new(2).put::<XodeStaking>();
+		let max = MaxDelegationsPerDelegator::get() as u64;
+		let _ = Balances::deposit_creating(&11, 100 * (max as u128 + 2));
+		for candidate in 1..=max + 1 {
+			v3::Delegations::<Test>::insert(&candidate, BoundedVec::try_from(vec![
+				Delegation { delegator: 11, stake: 100 },
+			]).unwrap());
+			assert_ok!(Balances::hold(&HoldReason::Delegation.into(), &11, 100));
+		}
+		let request = UnbondingRequest { candidate: 99, amount: 1, release_block: 100, redelegated_to: Some(98) };
+		UnbondingRequests::<Test>::insert(11, BoundedVec::truncate_from(vec![request; MaxUnbondingRequests::get() as usize]));
+
+		MigrateToV3::<Test>::on_runtime_upgrade();
+
+		// The delegation beyond the limit is removed and its hold is released
+		assert_eq!(DelegatorState::<Test>::get(11).len() as u64, max);
+		assert_eq!(v3::Delegations::<Test>::iter_keys().count() as u64, max);
+		assert!(UnbondingRequests::<Test>::get(11).iter().all(|r| r.candidate == 99));
+		assert_eq!(Balances::balance_on_hold(&HoldReason::Delegation.into(), &11), 100 * max as u128);
+		for (candidate, delegations) in v3::Delegations::<Test>::iter() {
+			assert!(DelegatorState::<Test>::get(delegations[0].delegator).iter().any(|c| c.candidate == candidate));
+		}
+	});
+}
+
+#[test]
+fn test_pallet_xode_staking_migrate_delegations_auto_compound() {
+	use frame_support::traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion};
+	use crate::migrations::{v3, v4::MigrateToV4};
+	use sp_runtime::Percent;
+
+	test1_ext().without_try_state().execute_with(|| {
+		// Storage layout before the migration (delegations without auto compound)
+		v3::Delegations::<Test>::insert(&1, BoundedVec::try_from(vec![
+			v3::Delegation { delegator: 11, stake: 100 },
+			v3::Delegation { delegator: 12, stake: 200 },
+		]).unwrap());
+
+		// Skipped if the on-chain storage version does not match
+		StorageVersion::new(2).put::<XodeStaking>();
+		MigrateToV4::<Test>::on_runtime_upgrade();
+		assert_eq!(XodeStaking::on_chain_storage_version(), 2);
+		assert_eq!(v3::Delegations::<Test>::get(&1).unwrap().len(), 2);
+
+		StorageVersion::new(3).put::<XodeStaking>();
+
+		MigrateToV4::<Test>::on_runtime_upgrade();
+
+		assert_eq!(XodeStaking::on_chain_storage_version(), 4);
+		let delegations = Delegations::<Test>::get(&1).unwrap();
+		assert_eq!(delegations.len(), 2);
+		assert_eq!(delegations[1].delegator, 12);
+		assert_eq!(delegations[1].stake, 200);
+		assert!(delegations.iter().all(|d| d.auto_compound == Percent::zero()));
+
+		// Running it again has no effect
+		MigrateToV4::<Test>::on_runtime_upgrade();
+		assert_eq!(Delegations::<Test>::get(&1).unwrap().len(), 2);
+	});
+}
+
+#[test]
+fn test_pallet_xode_staking_migrate_xaver_nodes() {
+	use frame_support::traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion};
+	use crate::migrations::v5::{self, MigrateToV5};
+
+	frame_support::parameter_types! {
+		pub Nodes: Vec<AccountId> = vec![XAVER_NODES[0], 41, 42];
+	}
+
+	test1_ext().without_try_state().execute_with(|| {
+		// Storage layout before the migration (the next block number was never set on chain)
+		StorageVersion::new(4).put::<XodeStaking>();
+		v5::NextBlockNumber::<Test>::put(10);
+
+		MigrateToV5::<Test, Nodes>::on_runtime_upgrade();
+
+		// The missing Xaver nodes are desired and wait listed, the existing one is not duplicated
+		assert_eq!(XodeStaking::on_chain_storage_version(), 5);
+		assert!(!v5::NextBlockNumber::<Test>::exists());
+		let desired_candidates = DesiredCandidates::<Test>::get();
+		assert_eq!(desired_candidates.len(), XAVER_NODES.len() + 2);
+		assert_eq!(desired_candidates.iter().filter(|c| **c == XAVER_NODES[0]).count(), 1);
+		for node in [41, 42] {
+			assert!(desired_candidates.contains(&node));
+			assert!(WaitingCandidates::<Test>::get().contains(&node));
+		}
+
+		// Running it again has no effect
+		MigrateToV5::<Test, Nodes>::on_runtime_upgrade();
+		assert_eq!(DesiredCandidates::<Test>::get().len(), XAVER_NODES.len() + 2);
+	});
+}
+
+#[test]
+fn test_pallet_xode_staking_runtime_api_helpers() {
+	test1_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		// Candidate (Author)
+		let _ = Balances::deposit_creating(&1, 100_000_000_000_000);
+		let _ = Balances::deposit_creating(&2, 100_000_000_000_000);
+		// Delegator (5 accounts)
+		for i in 11..16 {
+			let _ = Balances::deposit_creating(&i, 100_000_000_000_000);
+		}
+
+		assert_ok!(XodeStaking::register_candidate(RuntimeOrigin::signed(1)));
+		assert_ok!(XodeStaking::register_candidate(RuntimeOrigin::signed(2)));
+		assert_ok!(XodeStaking::bond_candidate(RuntimeOrigin::signed(1), 10_000_000_000_000));
+		assert_ok!(XodeStaking::bond_candidate(RuntimeOrigin::signed(2), 20_000_000_000_000));
+		assert_ok!(XodeStaking::set_commission_of_candidate(RuntimeOrigin::signed(1), 50));
+		for i in 11..16 {
+			assert_ok!(XodeStaking::stake_candidate(RuntimeOrigin::signed(i), 1, (i as u128 - 10) * 10_000_000_000_000));
+		}
+		assert_ok!(XodeStaking::stake_candidate(RuntimeOrigin::signed(11), 2, 5_000_000_000_000));
+
+		// Ranks follow the sorting (bond + total_stake)
+		let summaries = XodeStaking::candidate_summaries();
+		assert_eq!(summaries.len(), 2);
+		assert_eq!((summaries[0].rank, summaries[0].info.who), (1, 1));
+		assert_eq!((summaries[1].rank, summaries[1].info.who), (2, 2));
+
+		// Delegations across all candidates
+		let mut delegations = XodeStaking::delegations_of(11);
+		delegations.sort();
+		assert_eq!(delegations, vec![(1, 10_000_000_000_000), (2, 5_000_000_000_000)]);
+
+		// Same figures as test_pallet_xode_staking_fees_author_delegator_share
+		assert_eq!(XodeStaking::delegator_share(1, 11, 17_691_520), 589_717);
+		assert_eq!(XodeStaking::delegator_share(1, 12, 17_691_520), 1_179_434);
+		assert_eq!(XodeStaking::delegator_share(1, 15, 17_691_520), 2_948_586);
+
+		// No commission, no share
+		assert_eq!(XodeStaking::delegator_share(2, 11, 17_691_520), 0);
+	});
+}
+
+#[test]
+fn test_pallet_xode_staking_authored_blocks_history() {
+	use crate::AuthoredBlocks;
+
+	test1_ext().execute_with(|| {
+		// Session 0: two blocks from collator 1 and one block from collator 2
+		pallet_session::CurrentIndex::<Test>::put(0);
+		XodeStaking::note_authored_block(1);
+		XodeStaking::note_authored_block(1);
+		XodeStaking::note_authored_block(2);
+
+		assert_eq!(XodeStaking::authored_blocks_of(1), vec![(0, 2)]);
+		let mut session_blocks = XodeStaking::session_authored_blocks(0);
+		session_blocks.sort();
+		assert_eq!(session_blocks, vec![(1, 2), (2, 1)]);
+
+		// Session 1: one block from collator 1
+		pallet_session::CurrentIndex::<Test>::put(1);
+		XodeStaking::note_authored_block(1);
+		assert_eq!(XodeStaking::authored_blocks_of(1), vec![(0, 2), (1, 1)]);
+		assert_eq!(XodeStaking::authored_blocks_of(3), vec![]);
+
+		// The history depth is four sessions, session 0 is pruned at the end of session 3
+		XodeStaking::prune_authored_blocks(2);
+		assert_eq!(AuthoredBlocks::<Test>::get(0, 1), 2);
+		XodeStaking::prune_authored_blocks(3);
+		assert_eq!(AuthoredBlocks::<Test>::iter_prefix(0).count(), 0);
+
+		pallet_session::CurrentIndex::<Test>::put(4);
+		assert_eq!(XodeStaking::authored_blocks_of(1), vec![(1, 1)]);
+	});
+}
+
+#[test]
+fn test_pallet_xode_staking_authored_blocks_pruned_below_depth() {
+	use crate::{AuthoredBlocks, EarliestAuthoredSession};
+
+	test1_ext().execute_with(|| {
+		// One block per session from collator 1, sessions 0 to 9
+		for session in 0..10 {
+			AuthoredBlocks::<Test>::insert(session, 1, 1);
+			MissedSlots::<Test>::insert(session, 2, 1);
+		}
+
+		// The earlier sessions were never pruned, every session below the depth is pruned at once
+		XodeStaking::prune_authored_blocks(9);
+		assert_eq!(AuthoredBlocks::<Test>::iter().count(), 3);
+		assert_eq!(MissedSlots::<Test>::iter().count(), 3);
+		assert!(!AuthoredBlocks::<Test>::contains_key(6, 1));
+		assert!(AuthoredBlocks::<Test>::contains_key(7, 1));
+
+		// A reduced history depth prunes the sessions now beyond it
+		AuthoredBlocksHistoryDepth::set(2);
+		XodeStaking::prune_authored_blocks(9);
+		assert_eq!(AuthoredBlocks::<Test>::iter_keys().map(|(session, _)| session).collect::<Vec<_>>(), vec![9]);
+		assert_eq!(MissedSlots::<Test>::iter_keys().map(|(session, _)| session).collect::<Vec<_>>(), vec![9]);
+		assert_eq!(EarliestAuthoredSession::<Test>::get(), 9);
+	});
+}
+
+fn genesis_ext(
+	proposed_candidates: Vec<(u64, u128, u8)>,
+	delegations: Vec<(u64, u64, u128)>,
+) -> TestExt {
+	let mut storage = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
+	pallet_balances::GenesisConfig::<Test> {
+		balances: (1..20).map(|i| (i, 100_000_000_000_000)).collect(),
+	}
+	.assimilate_storage(&mut storage)
+	.unwrap();
+	crate::GenesisConfig::<Test> {
+		desired_candidates: XAVER_NODES.to_vec(),
+		proposed_candidates,
+		delegations,
+	}
+	.assimilate_storage(&mut storage)
+	.unwrap();
+	TestExt(storage.into())
+}
+
+#[test]
+fn test_pallet_xode_staking_genesis_works() {
+	genesis_ext(
+		vec![(1, 10_000_000_000_000, 10), (2, 20_000_000_000_000, 0)],
+		vec![(11, 1, 15_000_000_000_000), (12, 1, 5_000_000_000_000), (12, 2, 1_000_000_000_000)],
+	).execute_with(|| {
+		// Xaver nodes are desired and wait listed
+		assert_eq!(DesiredCandidates::<Test>::get().to_vec(), XAVER_NODES.to_vec());
+		assert_eq!(WaitingCandidates::<Test>::get().to_vec(), XAVER_NODES.to_vec());
+
+		// Sorted by bond + total stake
+		let proposed_candidates = XodeStaking::proposed_candidates();
+		assert_eq!(proposed_candidates.len(), 2);
+		assert_eq!(proposed_candidates[0].who, 1);
+		assert_eq!(proposed_candidates[0].total_stake, 20_000_000_000_000);
+		assert_eq!(proposed_candidates[0].commission, 10);
+		assert_eq!(proposed_candidates[1].who, 2);
+		assert_eq!(proposed_candidates[1].total_stake, 1_000_000_000_000);
+
+		// Bonds and delegations are held
+		assert_eq!(Balances::balance_on_hold(&HoldReason::CandidateBond.into(), &1), 10_000_000_000_000);
+		assert_eq!(Balances::balance_on_hold(&HoldReason::Delegation.into(), &12), 6_000_000_000_000);
+		assert_eq!(Delegations::<Test>::get(1).unwrap().len(), 2);
+	});
+}
+
+#[test]
+#[should_panic(expected = "Genesis delegation candidate is not a proposed candidate")]
+fn test_pallet_xode_staking_genesis_delegation_to_unknown_candidate() {
+	genesis_ext(
+		vec![(1, 10_000_000_000_000, 10)],
+		vec![(11, 3, 15_000_000_000_000)],
+	);
+}
+
+#[test]
+#[should_panic(expected = "Genesis delegation stake must not be zero")]
+fn test_pallet_xode_staking_genesis_zero_delegation() {
+	genesis_ext(
+		vec![(1, 10_000_000_000_000, 10)],
+		vec![(11, 1, 0)],
+	);
+}
+
+#[test]
+#[should_panic(expected = "Genesis delegation stake must be at least the minimum delegation")]
+fn test_pallet_xode_staking_genesis_delegation_below_minimum() {
+	genesis_ext(
+		vec![(1, 10_000_000_000_000, 10)],
+		vec![(11, 1, 9)],
+	);
+}
+
+#[test]
+#[should_panic(expected = "Genesis delegations exceed the maximum delegations of a delegator")]
+fn test_pallet_xode_staking_genesis_delegations_per_delegator_exceeded() {
+	// Eleven candidates, the maximum delegations of a delegator is ten
+	genesis_ext(
+		(1..12).map(|candidate| (candidate, 10_000_000_000_000, 10)).collect(),
+		(1..12).map(|candidate| (19, candidate, 1_000_000_000_000)).collect(),
+	);
+}
+
+#[test]
+#[should_panic(expected = "Genesis proposed candidate commission must be from 0 to 100")]
+fn test_pallet_xode_staking_genesis_invalid_commission() {
+	genesis_ext(vec![(1, 10_000_000_000_000, 101)], vec![]);
+}
@@ -1,8 +1,6 @@
 use crate::{
 	mock::*, 
-	ProposedCandidates,
 	Status,
-	NextBlockNumber,
 };
 use frame_support::{
 	assert_ok,
@@ -33,7 +31,7 @@ fn test_pallet_xode_staking_last_updated_and_incorrect_balance_works() {
 		assert_ok!(XodeStaking::bond_candidate(RuntimeOrigin::signed(candidate), 11_000_000_000_000_000));	
 		assert_eq!(89_000_000_000_000_000, Balances::free_balance(&candidate), "Must match");
 
-		let proposed_candidates = ProposedCandidates::<Test>::get();
+		let proposed_candidates = XodeStaking::proposed_candidates();
 		assert_eq!(proposed_candidates.len(), 1, "The number of proposed candidates should be 1");	
 
 		assert_eq!(proposed_candidates[0].last_updated, 0, "Must match");
@@ -44,9 +42,10 @@ fn test_pallet_xode_staking_last_updated_and_incorrect_balance_works() {
 		XodeStaking::on_initialize(System::block_number());
 
 		assert_ok!(XodeStaking::bond_candidate(RuntimeOrigin::signed(candidate), 5_000_000_000_000_000));
-		assert_eq!(95_000_000_000_000_000, Balances::free_balance(&candidate), "Must match");
+		// The bond difference (6_000_000_000_000_000) is still reserved while unbonding
+		assert_eq!(89_000_000_000_000_000, Balances::free_balance(&candidate), "Must match");
 
-		let proposed_candidates = ProposedCandidates::<Test>::get();
+		let proposed_candidates = XodeStaking::proposed_candidates();
 		assert_eq!(proposed_candidates.len(), 1, "The number of proposed candidates should be 1");	
 
 		assert_eq!(proposed_candidates[0].last_updated, 1, "Must match");
@@ -57,9 +56,9 @@ fn test_pallet_xode_staking_last_updated_and_incorrect_balance_works() {
 		XodeStaking::on_initialize(System::block_number());
 
 		assert_ok!(XodeStaking::bond_candidate(RuntimeOrigin::signed(candidate), 15_000_000_000_000_000));
-		assert_eq!(85_000_000_000_000_000, Balances::free_balance(&candidate), "Must match");
+		assert_eq!(79_000_000_000_000_000, Balances::free_balance(&candidate), "Must match");
 
-		let proposed_candidates = ProposedCandidates::<Test>::get();
+		let proposed_candidates = XodeStaking::proposed_candidates();
 		assert_eq!(proposed_candidates.len(), 1, "The number of proposed candidates should be 1");	
 
 		assert_eq!(proposed_candidates[0].last_updated, 2, "Must match");
@@ -70,9 +69,9 @@ fn test_pallet_xode_staking_last_updated_and_incorrect_balance_works() {
 		XodeStaking::on_initialize(System::block_number());
 
 		assert_ok!(XodeStaking::bond_candidate(RuntimeOrigin::signed(candidate), 15_000_000_000_000_000));
-		assert_eq!(85_000_000_000_000_000, Balances::free_balance(&candidate), "Must match");
+		assert_eq!(79_000_000_000_000_000, Balances::free_balance(&candidate), "Must match");
 
-		let proposed_candidates = ProposedCandidates::<Test>::get();
+		let proposed_candidates = XodeStaking::proposed_candidates();
 		assert_eq!(proposed_candidates.len(), 1, "The number of proposed candidates should be 1");	
 
 		assert_eq!(proposed_candidates[0].last_updated, 2, "Must match");
@@ -97,7 +96,7 @@ fn test_pallet_xode_staking_assignment_operator_works() {
 		assert_ok!(XodeStaking::bond_candidate(RuntimeOrigin::signed(candidate), 11_000_000_000_000_000));	
 		assert_eq!(89_000_000_000_000_000, Balances::free_balance(&candidate), "Must match");
 
-		let proposed_candidates = ProposedCandidates::<Test>::get();
+		let proposed_candidates = XodeStaking::proposed_candidates();
 		assert_eq!(proposed_candidates.len(), 1, "The number of proposed candidates should be 1");		
 
 		// The initial status must be online.  This status is set when you register a candidate
@@ -109,7 +108,7 @@ fn test_pallet_xode_staking_assignment_operator_works() {
 		XodeStaking::new_session(1);
 		Session::on_initialize(System::block_number()); 
 
-		let proposed_candidates = ProposedCandidates::<Test>::get();
+		let proposed_candidates = XodeStaking::proposed_candidates();
 		assert_eq!(proposed_candidates.len(), 1, "The number of proposed candidates should be 1");		
 
 		// Bonded online candidate will be automatically wait listed
@@ -121,7 +120,7 @@ fn test_pallet_xode_staking_assignment_operator_works() {
 		XodeStaking::new_session(2);
 		Session::on_initialize(System::block_number()); 
 
-		let proposed_candidates = ProposedCandidates::<Test>::get();
+		let proposed_candidates = XodeStaking::proposed_candidates();
 		assert_eq!(proposed_candidates.len(), 1, "The number of proposed candidates should be 1");		
 
 		// Wait listed candidate at the beginning of the session will be automatically listed as authoring
@@ -130,7 +129,7 @@ fn test_pallet_xode_staking_assignment_operator_works() {
 		// Offline the candidate
 		let _ = XodeStaking::offline_candidate(RuntimeOrigin::signed(candidate));
 
-		let proposed_candidates = ProposedCandidates::<Test>::get();
+		let proposed_candidates = XodeStaking::proposed_candidates();
 		assert_eq!(proposed_candidates.len(), 1, "The number of proposed candidates should be 1");		
 		assert_eq!(proposed_candidates[0].offline, true, "Must match");			
 
@@ -140,28 +139,16 @@ fn test_pallet_xode_staking_assignment_operator_works() {
 		XodeStaking::new_session(3);
 		Session::on_initialize(System::block_number()); 
 
-		let proposed_candidates = ProposedCandidates::<Test>::get();
+		let proposed_candidates = XodeStaking::proposed_candidates();
 		assert_eq!(proposed_candidates.len(), 1, "The number of proposed candidates should be 1");		
 
-		// It takes two session before the author is transferred to queuing
-		assert_eq!(proposed_candidates[0].status, Status::Authoring, "Must match");			
-
-		System::set_block_number((4 * MINUTES).into());
-		XodeStaking::on_initialize(System::block_number());
-
-		XodeStaking::new_session(4);
-		Session::on_initialize(System::block_number()); 
-
-		let proposed_candidates = ProposedCandidates::<Test>::get();
-		assert_eq!(proposed_candidates.len(), 1, "The number of proposed candidates should be 1");		
-
-		// After two sessions the author is now transferred to queuing
-		assert_eq!(proposed_candidates[0].status, Status::Queuing, "Must match");				
+		// The offline author left the waiting list, it is transferred to queuing after one session
+		assert_eq!(proposed_candidates[0].status, Status::Queuing, "Must match");			
 
 		// Online the candidate
 		let _ = XodeStaking::online_candidate(RuntimeOrigin::signed(candidate));
 
-		let proposed_candidates = ProposedCandidates::<Test>::get();
+		let proposed_candidates = XodeStaking::proposed_candidates();
 		assert_eq!(proposed_candidates.len(), 1, "The number of proposed candidates should be 1");		
 		assert_eq!(proposed_candidates[0].offline, false, "Must match");		
 
@@ -174,7 +161,7 @@ fn test_pallet_xode_staking_assignment_operator_works() {
 			assert_ok!(XodeStaking::bond_candidate(RuntimeOrigin::signed(i), 11_000_000_000_000_000));	
 		}		
 
-		let proposed_candidates = ProposedCandidates::<Test>::get();
+		let proposed_candidates = XodeStaking::proposed_candidates();
 		assert_eq!(proposed_candidates.len(), 100, "The number of proposed candidates should be 100");	
 
 		for i in 1..100 {
@@ -191,7 +178,7 @@ fn test_pallet_xode_staking_assignment_operator_works() {
 		XodeStaking::new_session(5);
 		Session::on_initialize(System::block_number()); 		
 
-		let proposed_candidates = ProposedCandidates::<Test>::get();
+		let proposed_candidates = XodeStaking::proposed_candidates();
 		assert_eq!(proposed_candidates.len(), 100, "The number of proposed candidates should be 100");	
 
 		// Three candidate remains online because of the three Xaver nodes inserted.
@@ -215,7 +202,7 @@ fn test_pallet_xode_staking_assignment_operator_works() {
 		XodeStaking::new_session(6);
 		Session::on_initialize(System::block_number()); 		
 
-		let proposed_candidates = ProposedCandidates::<Test>::get();
+		let proposed_candidates = XodeStaking::proposed_candidates();
 		assert_eq!(proposed_candidates.len(), 100, "The number of proposed candidates should be 100");	
 
 		// The queued author will immediately author because it is still listed in the waiting list.
@@ -241,23 +228,19 @@ fn test_pallet_xode_staking_assignment_operator_works() {
 #[test]
 fn test_pallet_xode_staking_on_initialize_weight_works() {
 	test1_ext().execute_with(|| {
-		System::set_block_number(0);
-		let weight = XodeStaking::on_initialize(System::block_number());
-
-		// Heavy weight is called only when the next block is explicitly cleared which only
-		// happens at block 0.
-
-		let expected = <Test as frame_system::Config>::DbWeight::get().reads_writes(14, 28);
-        assert_eq!(weight, expected);		
-
-		// To test if there is a next block which usually the setup on every Substrate chain.
-
-		NextBlockNumber::<Test>::put(2);
-
-		System::set_block_number(1);
-		let weight = XodeStaking::on_initialize(System::block_number());
-
-		let expected = <Test as frame_system::Config>::DbWeight::get().reads_writes(2, 2);
-        assert_eq!(weight, expected);		
+		// The Xaver nodes are set at genesis, hence block zero no longer carries a heavy weight.
+		// The inflation is disabled and there are no Aura authorities (no missed slots), hence the 
+		// weight only depends on the author (cached for the block).
+		let db_weight = <Test as frame_system::Config>::DbWeight::get();
+		for block_number in 0..2 {
+			System::set_block_number(block_number);
+			let weight = XodeStaking::on_initialize(System::block_number());
+
+			let mut expected = db_weight.reads_writes(7, 2);
+			if Authorship::author().is_some() {
+				expected += db_weight.reads_writes(4, 3);
+			}
+			assert_eq!(weight, expected);
+		}
 	});
 }
@@ -1,9 +1,14 @@
-use crate::{mock::*, Error, Status, ProposedCandidates,
+use crate::{mock::*, Error, Status, UnbondingRequest, UnbondingRequests, UnbondingAccounts, HoldReason,
+	DesiredCandidates, WaitingCandidates, DelegatorState, Delegations, ProposedCandidates, ReleasingCandidates, CompoundingCandidates, PendingCommissions, CommissionChangeQueue, CandidateMetadata, Event,
+	SessionInflation, RewardPerStake, RewardCheckpoints,
 };
 use frame_support::{
-	assert_noop, assert_ok,
+	assert_noop, assert_ok, BoundedVec,
 };
-use frame_support::traits::{Currency, Imbalance};
+use frame_support::traits::{Currency, Imbalance, Hooks, fungible::InspectHold};
+use frame_support::weights::Weight;
+use pallet_session::SessionManager;
+use sp_runtime::{traits::Zero, DispatchError, Perbill, Percent};
 
 // Register Candidate Function - Unit Tests
 // Run this command: cargo test test_pallet_xode_staking_register_candidate -- --nocapture
@@ -19,7 +24,7 @@ fn test_pallet_xode_staking_register_candidate_works() {
 		// However, we really need to get the last dispatched events to ensure the test works.
 		
 		// System::assert_last_event(Event::ProposedCandidateAdded { 
-        //     _proposed_candidate: 1, 
+        //     who: 1, 
         // }.into());
 	});
 }
@@ -30,7 +35,7 @@ fn test_pallet_xode_staking_register_candidate_no_existing_candidates() {
         let candidate = 1;
         assert_ok!(XodeStaking::register_candidate(RuntimeOrigin::signed(candidate)));
 
-		let candidate_count = ProposedCandidates::<Test>::get().len();
+		let candidate_count = XodeStaking::proposed_candidates().len();
         assert_eq!(candidate_count, 1);
     });
 }
@@ -81,7 +86,7 @@ fn test_pallet_xode_staking_register_candidate_default_values() {
         assert_ok!(XodeStaking::register_candidate(RuntimeOrigin::signed(candidate)));
 
         // Verify the default values in the storage
-		let candidates = ProposedCandidates::<Test>::get();
+		let candidates = XodeStaking::proposed_candidates();
         let _candidate_info = candidates
             .iter()
             .find(|c| c.who == candidate);
@@ -119,7 +124,7 @@ fn test_pallet_xode_staking_bond_candidate_works() {
         // Bond the candidate
 		assert_ok!(XodeStaking::bond_candidate(RuntimeOrigin::signed(candidate), 11_000_000_000_000_000));
 
-        let candidates = ProposedCandidates::<Test>::get();
+        let candidates = XodeStaking::proposed_candidates();
         if let Some(candidate_info) = candidates.iter().find(|c| c.who == candidate) {    
             assert_eq!(candidate_info.bond, 11_000_000_000_000_000);  
         }  
@@ -127,6 +132,27 @@ fn test_pallet_xode_staking_bond_candidate_works() {
 	});
 }
 
+#[test]
+fn test_pallet_xode_staking_bond_candidate_holds_with_reason() {
+	test1_ext().execute_with(|| {
+        let candidate = 1;
+		let delegator = 11;
+
+		let _ = Balances::deposit_creating(&candidate, 1000);
+		let _ = Balances::deposit_creating(&delegator, 1000);
+
+        assert_ok!(XodeStaking::register_candidate(RuntimeOrigin::signed(candidate)));
+		assert_ok!(XodeStaking::bond_candidate(RuntimeOrigin::signed(candidate), 200));
+		assert_ok!(XodeStaking::stake_candidate(RuntimeOrigin::signed(delegator), candidate, 300));
+
+		// Bonds and delegations are held under their own reasons
+		assert_eq!(200, Balances::balance_on_hold(&HoldReason::CandidateBond.into(), &candidate));
+		assert_eq!(0, Balances::balance_on_hold(&HoldReason::Delegation.into(), &candidate));
+		assert_eq!(300, Balances::balance_on_hold(&HoldReason::Delegation.into(), &delegator));
+		assert_eq!(0, Balances::balance_on_hold(&HoldReason::CandidateBond.into(), &delegator));
+	});
+}
+
 #[test]
 fn test_pallet_xode_staking_bond_candidate_not_registered_should_error() {
 	test1_ext().execute_with(|| {
@@ -159,7 +185,7 @@ fn test_pallet_xode_staking_bond_candidate_increases_bond_and_reserves() {
         // Bond the candidate
 		assert_ok!(XodeStaking::bond_candidate(RuntimeOrigin::signed(candidate), 11_000_000_000_000_000));
 
-        let candidates = ProposedCandidates::<Test>::get();
+        let candidates = XodeStaking::proposed_candidates();
         if let Some(candidate_info) = candidates.iter().find(|c| c.who == candidate) {    
             assert_eq!(candidate_info.bond, 11_000_000_000_000_000);  
         }  
@@ -168,7 +194,7 @@ fn test_pallet_xode_staking_bond_candidate_increases_bond_and_reserves() {
         // Increase the bond and reserves
 		assert_ok!(XodeStaking::bond_candidate(RuntimeOrigin::signed(candidate), 21_000_000_000_000_000));
 
-        let updated_candidates = ProposedCandidates::<Test>::get();
+        let updated_candidates = XodeStaking::proposed_candidates();
         if let Some(candidate_info) = updated_candidates.iter().find(|c| c.who == candidate) {    
             assert_eq!(candidate_info.bond, 21_000_000_000_000_000);  
         }  
@@ -192,19 +218,24 @@ fn test_pallet_xode_staking_bond_candidate_decreases_bond_and_unreserves() {
 		assert_ok!(XodeStaking::bond_candidate(RuntimeOrigin::signed(candidate), 200));
         assert_eq!(800, Balances::free_balance(&candidate), "Should have 800 free balance.");
 
-        let candidates = ProposedCandidates::<Test>::get();
+        let candidates = XodeStaking::proposed_candidates();
         if let Some(candidate_info) = candidates.iter().find(|c| c.who == candidate) {    
             assert_eq!(candidate_info.bond, 200);  
         }  
         assert_eq!(200, Balances::reserved_balance(candidate), "Should have 100 reserved balance.");
         
-        // Decrease the bond and unreserves
+        // Decrease the bond, the difference is scheduled for unbonding
 		assert_ok!(XodeStaking::bond_candidate(RuntimeOrigin::signed(candidate), 100));
 
-        let updated_candidates = ProposedCandidates::<Test>::get();
+        let updated_candidates = XodeStaking::proposed_candidates();
         if let Some(candidate_info) = updated_candidates.iter().find(|c| c.who == candidate) {    
             assert_eq!(candidate_info.bond, 100);  
         }  
+        assert_eq!(200, Balances::reserved_balance(candidate), "Should still have 200 reserved balance.");
+
+        // Unbonding delay (2 sessions) elapsed, unreserves
+        System::set_block_number(2 * MINUTES);
+        assert_ok!(XodeStaking::withdraw_unbonded(RuntimeOrigin::signed(candidate)));
         assert_eq!(100, Balances::reserved_balance(candidate), "Should have 100 reserved balance.")
     });
 }
@@ -254,8 +285,8 @@ fn test_pallet_xode_staking_bond_candidate_sorting_after_update() {
             }
         }
 
-        // Verify the order of candidates in the ProposedCandidates storage
-        let candidates = ProposedCandidates::<Test>::get();
+        // Verify the order of candidates in the SortedCandidates index
+        let candidates = XodeStaking::proposed_candidates();
         
         assert_eq!(candidates.len(), 3, "Expected 3 candidates in storage");
 
@@ -268,4 +299,1437 @@ fn test_pallet_xode_staking_bond_candidate_sorting_after_update() {
         assert_eq!(candidates[2].who, 1, "Candidate 2 should be last with the smallest bond");
         assert_eq!(candidates[2].bond, 100, "Candidate 2 bond should be 100");
     });
-}
\ No newline at end of file
+}
+
+// Withdraw Unbonded Function - Unit Tests
+// Run this command: cargo test test_pallet_xode_staking_withdraw_unbonded -- --nocapture
+// =======================================================================================
+
+#[test]
+fn test_pallet_xode_staking_withdraw_unbonded_works() {
+    test1_ext().execute_with(|| {
+        let candidate = 1;
+        let delegator = 11;
+
+        let _ = Balances::deposit_creating(&candidate, 1000);
+        let _ = Balances::deposit_creating(&delegator, 1000);
+
+        assert_ok!(XodeStaking::register_candidate(RuntimeOrigin::signed(candidate)));
+        assert_ok!(XodeStaking::stake_candidate(RuntimeOrigin::signed(delegator), candidate, 300));
+        assert_eq!(700, Balances::free_balance(&delegator), "Should have 700 free balance.");
+
+        // Un-stake at block 1, the release block is 1 + (2 sessions * 10 blocks)
+        System::set_block_number(1);
+        assert_ok!(XodeStaking::unstake_candidate(RuntimeOrigin::signed(delegator), candidate));
+        assert_eq!(300, Balances::reserved_balance(delegator), "Stake remains reserved while unbonding.");
+
+        let requests = UnbondingRequests::<Test>::get(&delegator);
+        assert_eq!(requests.len(), 1);
+        assert_eq!(requests[0].candidate, candidate);
+        assert_eq!(requests[0].amount, 300);
+        assert_eq!(requests[0].release_block, 1 + 2 * MINUTES);
+
+        System::set_block_number(1 + 2 * MINUTES);
+        assert_ok!(XodeStaking::withdraw_unbonded(RuntimeOrigin::signed(delegator)));
+        assert_eq!(1000, Balances::free_balance(&delegator), "Should have 1000 free balance.");
+        assert_eq!(0, Balances::reserved_balance(delegator), "Should have 0 reserved balance.");
+        assert!(UnbondingRequests::<Test>::get(&delegator).is_empty());
+    });
+}
+
+#[test]
+fn test_pallet_xode_staking_withdraw_unbonded_not_matured_should_error() {
+    test1_ext().execute_with(|| {
+        let candidate = 1;
+
+        let _ = Balances::deposit_creating(&candidate, 1000);
+
+        assert_ok!(XodeStaking::register_candidate(RuntimeOrigin::signed(candidate)));
+        assert_ok!(XodeStaking::bond_candidate(RuntimeOrigin::signed(candidate), 200));
+        assert_ok!(XodeStaking::bond_candidate(RuntimeOrigin::signed(candidate), 100));
+
+        // One block before the release block
+        System::set_block_number(2 * MINUTES - 1);
+        assert_noop!(
+            XodeStaking::withdraw_unbonded(RuntimeOrigin::signed(candidate)),
+            Error::<Test>::UnbondingRequestsNoneMatured
+        );
+        assert_eq!(200, Balances::reserved_balance(candidate), "Should have 200 reserved balance.");
+    });
+}
+
+#[test]
+fn test_pallet_xode_staking_withdraw_unbonded_retains_unmatured_requests() {
+    test1_ext().execute_with(|| {
+        let candidate = 1;
+
+        let _ = Balances::deposit_creating(&candidate, 1000);
+
+        assert_ok!(XodeStaking::register_candidate(RuntimeOrigin::signed(candidate)));
+        assert_ok!(XodeStaking::bond_candidate(RuntimeOrigin::signed(candidate), 500));
+
+        // First decrease at block 0 (release block 20), second at block 5 (release block 25)
+        assert_ok!(XodeStaking::bond_candidate(RuntimeOrigin::signed(candidate), 400));
+        System::set_block_number(5);
+        assert_ok!(XodeStaking::bond_candidate(RuntimeOrigin::signed(candidate), 300));
+
+        System::set_block_number(2 * MINUTES);
+        assert_ok!(XodeStaking::withdraw_unbonded(RuntimeOrigin::signed(candidate)));
+        assert_eq!(400, Balances::reserved_balance(candidate), "Only the first request is withdrawn.");
+        assert_eq!(UnbondingRequests::<Test>::get(&candidate).len(), 1);
+    });
+}
+
+#[test]
+fn test_pallet_xode_staking_unbonding_requests_full_are_merged() {
+    test1_ext().execute_with(|| {
+        let candidate = 1;
+
+        let _ = Balances::deposit_creating(&candidate, 1000);
+
+        assert_ok!(XodeStaking::register_candidate(RuntimeOrigin::signed(candidate)));
+        assert_ok!(XodeStaking::bond_candidate(RuntimeOrigin::signed(candidate), 500));
+
+        // Fill the unbonding requests, one unit per block
+        for i in 1..=MaxUnbondingRequests::get() {
+            System::set_block_number(i);
+            assert_ok!(XodeStaking::bond_candidate(RuntimeOrigin::signed(candidate), 500 - i as u128));
+        }
+        assert_eq!(UnbondingRequests::<Test>::get(&candidate).len(), MaxUnbondingRequests::get() as usize);
+
+        // The next decrease is merged with the pending request and is not released
+        System::set_block_number(40);
+        assert_ok!(XodeStaking::bond_candidate(RuntimeOrigin::signed(candidate), 400));
+        assert_eq!(500, Balances::reserved_balance(candidate), "The merged amount remains reserved.");
+
+        let requests = UnbondingRequests::<Test>::get(&candidate);
+        assert_eq!(requests.len(), MaxUnbondingRequests::get() as usize);
+        assert_eq!(requests[0].amount, 1 + 68);
+        assert_eq!(requests[0].release_block, 40 + 2 * MINUTES, "The merged request waits for the later release block.");
+    });
+}
+
+// Offline Candidate Function - Unit Tests
+// Run this command: cargo test test_pallet_xode_staking_offline_candidate -- --nocapture
+// ======================================================================================
+
+#[test]
+fn test_pallet_xode_staking_offline_candidate_leaves_waiting_list() {
+    test1_ext().execute_with(|| {
+        let candidate = 1;
+
+        let _ = Balances::deposit_creating(&candidate, 1000);
+
+        assert_ok!(XodeStaking::register_candidate(RuntimeOrigin::signed(candidate)));
+        assert_ok!(XodeStaking::bond_candidate(RuntimeOrigin::signed(candidate), 500));
+        XodeStaking::end_session(0);
+        assert!(WaitingCandidates::<Test>::get().contains(&candidate), "Wait listed.");
+
+        // Removed immediately from the waiting list and not wait listed again while offline
+        assert_ok!(XodeStaking::offline_candidate(RuntimeOrigin::signed(candidate)));
+        assert!(!WaitingCandidates::<Test>::get().contains(&candidate), "No longer wait listed.");
+        XodeStaking::end_session(1);
+        assert!(!WaitingCandidates::<Test>::get().contains(&candidate), "Not wait listed while offline.");
+    });
+}
+
+// Bond Correction Function - Unit Tests
+// Run this command: cargo test test_pallet_xode_staking_bond_correction -- --nocapture
+// ====================================================================================
+
+#[test]
+fn test_pallet_xode_staking_bond_correction_releases_held_bond() {
+    test1_ext().execute_with(|| {
+        let candidate = 1;
+
+        let _ = Balances::deposit_creating(&candidate, 1000);
+
+        assert_ok!(XodeStaking::register_candidate(RuntimeOrigin::signed(candidate)));
+        assert_ok!(XodeStaking::bond_candidate(RuntimeOrigin::signed(candidate), 500));
+        assert_ok!(XodeStaking::bond_candidate(RuntimeOrigin::signed(candidate), 400));
+        assert_ok!(XodeStaking::offline_candidate(RuntimeOrigin::signed(candidate)));
+
+        // The held bond (400) is released, the pending unbonding (100) stays held
+        assert_ok!(XodeStaking::bond_correction(RuntimeOrigin::signed(candidate)));
+        assert_eq!(100, Balances::balance_on_hold(&HoldReason::CandidateBond.into(), &candidate));
+        assert_eq!(900, Balances::free_balance(&candidate), "Should have 900 free balance.");
+
+        let candidates = XodeStaking::proposed_candidates();
+        assert_eq!(candidates[0].bond, 0);
+    });
+}
+
+// Desired Candidate Functions - Unit Tests
+// Run this command: cargo test test_pallet_xode_staking_desired_candidate -- --nocapture
+// =====================================================================================
+
+#[test]
+fn test_pallet_xode_staking_desired_candidate_add_works() {
+    test1_ext().execute_with(|| {
+        let desired_candidate = 1;
+
+        assert_ok!(XodeStaking::add_desired_candidate(RuntimeOrigin::root(), desired_candidate));
+        assert_eq!(DesiredCandidates::<Test>::get().len(), 4);
+        assert!(!WaitingCandidates::<Test>::get().contains(&desired_candidate), "Not yet wait listed.");
+
+        // Wait listed at the next end of session
+        XodeStaking::end_session(0);
+        assert!(WaitingCandidates::<Test>::get().contains(&desired_candidate), "Wait listed.");
+    });
+}
+
+#[test]
+fn test_pallet_xode_staking_desired_candidate_bad_origin_should_error() {
+    test1_ext().execute_with(|| {
+        assert_noop!(
+            XodeStaking::add_desired_candidate(RuntimeOrigin::signed(1), 1),
+            DispatchError::BadOrigin
+        );
+        assert_noop!(
+            XodeStaking::remove_desired_candidate(RuntimeOrigin::signed(1), XAVER_NODES[0]),
+            DispatchError::BadOrigin
+        );
+        assert_noop!(
+            XodeStaking::swap_desired_candidate(RuntimeOrigin::signed(1), XAVER_NODES[0], 1),
+            DispatchError::BadOrigin
+        );
+    });
+}
+
+#[test]
+fn test_pallet_xode_staking_desired_candidate_already_exist_should_error() {
+    test1_ext().execute_with(|| {
+        assert_noop!(
+            XodeStaking::add_desired_candidate(RuntimeOrigin::root(), XAVER_NODES[0]),
+            Error::<Test>::DesiredCandidateAlreadyExist
+        );
+        assert_noop!(
+            XodeStaking::swap_desired_candidate(RuntimeOrigin::root(), XAVER_NODES[0], XAVER_NODES[1]),
+            Error::<Test>::DesiredCandidateAlreadyExist
+        );
+    });
+}
+
+#[test]
+fn test_pallet_xode_staking_desired_candidate_remove_works() {
+    test1_ext().execute_with(|| {
+        assert_ok!(XodeStaking::remove_desired_candidate(RuntimeOrigin::root(), XAVER_NODES[1]));
+        assert_eq!(DesiredCandidates::<Test>::get().to_vec(), vec![XAVER_NODES[0], XAVER_NODES[2]]);
+
+        // No longer wait listed at the next end of session
+        XodeStaking::end_session(0);
+        assert!(!WaitingCandidates::<Test>::get().contains(&XAVER_NODES[1]), "Not wait listed.");
+
+        assert_noop!(
+            XodeStaking::remove_desired_candidate(RuntimeOrigin::root(), XAVER_NODES[1]),
+            Error::<Test>::DesiredCandidateNotFound
+        );
+    });
+}
+
+#[test]
+fn test_pallet_xode_staking_desired_candidate_swap_works() {
+    test1_ext().execute_with(|| {
+        let desired_candidate = 1;
+
+        assert_ok!(XodeStaking::swap_desired_candidate(RuntimeOrigin::root(), XAVER_NODES[0], desired_candidate));
+        // Takes the position (priority) of the old desired candidate
+        assert_eq!(DesiredCandidates::<Test>::get().to_vec(), vec![desired_candidate, XAVER_NODES[1], XAVER_NODES[2]]);
+
+        XodeStaking::end_session(0);
+        assert_eq!(WaitingCandidates::<Test>::get().to_vec(), vec![desired_candidate, XAVER_NODES[1], XAVER_NODES[2]]);
+
+        assert_noop!(
+            XodeStaking::swap_desired_candidate(RuntimeOrigin::root(), XAVER_NODES[0], 2),
+            Error::<Test>::DesiredCandidateNotFound
+        );
+    });
+}
+
+// Delegator State - Unit Tests
+// Run this command: cargo test test_pallet_xode_staking_delegator_state -- --nocapture
+// ===================================================================================
+
+#[test]
+fn test_pallet_xode_staking_delegator_state_follows_stake_and_unstake() {
+    test1_ext().execute_with(|| {
+        let delegator = 11;
+
+        let _ = Balances::deposit_creating(&delegator, 1000);
+        for candidate in 1..3 {
+            assert_ok!(XodeStaking::register_candidate(RuntimeOrigin::signed(candidate)));
+        }
+
+        assert_ok!(XodeStaking::stake_candidate(RuntimeOrigin::signed(delegator), 1, 100));
+        assert_ok!(XodeStaking::stake_candidate(RuntimeOrigin::signed(delegator), 2, 200));
+        assert_ok!(XodeStaking::stake_candidate(RuntimeOrigin::signed(delegator), 1, 50));
+        assert_eq!(XodeStaking::delegations_of(delegator), vec![(1, 150), (2, 200)]);
+
+        assert_ok!(XodeStaking::unstake_candidate(RuntimeOrigin::signed(delegator), 1));
+        assert_eq!(XodeStaking::delegations_of(delegator), vec![(2, 200)]);
+
+        assert_ok!(XodeStaking::unstake_candidate(RuntimeOrigin::signed(delegator), 2));
+        assert!(!DelegatorState::<Test>::contains_key(delegator), "The delegator state is removed.");
+    });
+}
+
+#[test]
+fn test_pallet_xode_staking_delegator_state_max_exceeded_should_error() {
+    test1_ext().execute_with(|| {
+        let delegator = 11;
+        let max_delegations = MaxDelegationsPerDelegator::get() as u64;
+
+        let _ = Balances::deposit_creating(&delegator, 1000);
+        for candidate in 1..=max_delegations + 1 {
+            assert_ok!(XodeStaking::register_candidate(RuntimeOrigin::signed(100 + candidate)));
+        }
+        for candidate in 1..=max_delegations {
+            assert_ok!(XodeStaking::stake_candidate(RuntimeOrigin::signed(delegator), 100 + candidate, 10));
+        }
+
+        assert_noop!(
+            XodeStaking::stake_candidate(RuntimeOrigin::signed(delegator), 100 + max_delegations + 1, 10),
+            Error::<Test>::DelegatorStateMaxExceeded
+        );
+
+        // Adding to an existing delegation is still allowed
+        assert_ok!(XodeStaking::stake_candidate(RuntimeOrigin::signed(delegator), 101, 10));
+        assert_eq!(DelegatorState::<Test>::get(delegator)[0].stake, 20);
+    });
+}
+
+// Decrease Delegation Function - Unit Tests
+// Run this command: cargo test test_pallet_xode_staking_decrease_delegation -- --nocapture
+// =======================================================================================
+
+#[test]
+fn test_pallet_xode_staking_decrease_delegation_works() {
+    test1_ext().execute_with(|| {
+        System::set_block_number(1);
+        let candidate = 1;
+        let delegator = 11;
+
+        let _ = Balances::deposit_creating(&delegator, 1000);
+        assert_ok!(XodeStaking::register_candidate(RuntimeOrigin::signed(candidate)));
+        assert_ok!(XodeStaking::stake_candidate(RuntimeOrigin::signed(delegator), candidate, 500));
+
+        assert_ok!(XodeStaking::decrease_delegation(RuntimeOrigin::signed(delegator), candidate, 200));
+        System::assert_last_event(RuntimeEvent::XodeStaking(Event::DelegationDecreased { 
+            who: delegator, candidate, old: 500, new: 300, total_stake: 300, 
+        }));
+
+        assert_eq!(Delegations::<Test>::get(candidate).unwrap()[0].stake, 300);
+        assert_eq!(XodeStaking::delegations_of(delegator), vec![(candidate, 300)]);
+        assert_eq!(XodeStaking::proposed_candidates()[0].total_stake, 300);
+
+        // The decreased amount stays held until withdrawn
+        assert_eq!(UnbondingRequests::<Test>::get(&delegator)[0].amount, 200);
+        assert_eq!(500, Balances::balance_on_hold(&HoldReason::Delegation.into(), &delegator));
+    });
+}
+
+#[test]
+fn test_pallet_xode_staking_decrease_delegation_to_zero_removes_delegation() {
+    test1_ext().execute_with(|| {
+        let candidate = 1;
+        let delegator = 11;
+
+        let _ = Balances::deposit_creating(&delegator, 1000);
+        assert_ok!(XodeStaking::register_candidate(RuntimeOrigin::signed(candidate)));
+        assert_ok!(XodeStaking::stake_candidate(RuntimeOrigin::signed(delegator), candidate, 500));
+
+        assert_ok!(XodeStaking::decrease_delegation(RuntimeOrigin::signed(delegator), candidate, 500));
+        assert!(Delegations::<Test>::get(candidate).is_none());
+        assert!(XodeStaking::delegations_of(delegator).is_empty());
+        assert_eq!(XodeStaking::proposed_candidates()[0].total_stake, 0);
+    });
+}
+
+#[test]
+fn test_pallet_xode_staking_decrease_delegation_invalid_amount_should_error() {
+    test1_ext().execute_with(|| {
+        let candidate = 1;
+        let delegator = 11;
+
+        let _ = Balances::deposit_creating(&delegator, 1000);
+        assert_ok!(XodeStaking::register_candidate(RuntimeOrigin::signed(candidate)));
+        assert_ok!(XodeStaking::stake_candidate(RuntimeOrigin::signed(delegator), candidate, 500));
+
+        assert_noop!(
+            XodeStaking::decrease_delegation(RuntimeOrigin::signed(delegator), candidate, 0),
+            Error::<Test>::DelegationInvalidAmount
+        );
+        assert_noop!(
+            XodeStaking::decrease_delegation(RuntimeOrigin::signed(delegator), candidate, 501),
+            Error::<Test>::DelegationInsufficientStake
+        );
+        // The remaining stake (5) is below the minimum delegation (10)
+        assert_noop!(
+            XodeStaking::decrease_delegation(RuntimeOrigin::signed(delegator), candidate, 495),
+            Error::<Test>::DelegationBelowMinimum
+        );
+        assert_noop!(
+            XodeStaking::decrease_delegation(RuntimeOrigin::signed(12), candidate, 100),
+            Error::<Test>::DelegationDelegatorDoesNotExist
+        );
+    });
+}
+
+#[test]
+fn test_pallet_xode_staking_stake_candidate_top_up_emits_increase() {
+    test1_ext().execute_with(|| {
+        System::set_block_number(1);
+        let candidate = 1;
+        let delegator = 11;
+
+        let _ = Balances::deposit_creating(&delegator, 1000);
+        assert_ok!(XodeStaking::register_candidate(RuntimeOrigin::signed(candidate)));
+        assert_ok!(XodeStaking::stake_candidate(RuntimeOrigin::signed(delegator), candidate, 500));
+        assert_ok!(XodeStaking::stake_candidate(RuntimeOrigin::signed(delegator), candidate, 100));
+
+        System::assert_last_event(RuntimeEvent::XodeStaking(Event::DelegationIncreased { 
+            who: delegator, candidate, old: 500, new: 600, total_stake: 600, 
+        }));
+    });
+}
+
+// Redelegate Function - Unit Tests
+// Run this command: cargo test test_pallet_xode_staking_redelegate -- --nocapture
+// =======================================================================================
+
+#[test]
+fn test_pallet_xode_staking_redelegate_works() {
+    test1_ext().execute_with(|| {
+        System::set_block_number(1);
+        let from = 1;
+        let to = 2;
+        let delegator = 11;
+
+        let _ = Balances::deposit_creating(&delegator, 1000);
+        assert_ok!(XodeStaking::register_candidate(RuntimeOrigin::signed(from)));
+        assert_ok!(XodeStaking::register_candidate(RuntimeOrigin::signed(to)));
+        assert_ok!(XodeStaking::stake_candidate(RuntimeOrigin::signed(delegator), from, 500));
+
+        assert_ok!(XodeStaking::redelegate(RuntimeOrigin::signed(delegator), from, to, 400));
+        System::assert_last_event(RuntimeEvent::XodeStaking(Event::Redelegated { 
+            who: delegator, from, to, amount: 400, from_total_stake: 100, to_total_stake: 400, 
+        }));
+
+        assert_eq!(Delegations::<Test>::get(from).unwrap()[0].stake, 100);
+        assert_eq!(Delegations::<Test>::get(to).unwrap()[0].stake, 400);
+        assert_eq!(XodeStaking::delegations_of(delegator), vec![(from, 100), (to, 400)]);
+
+        // The new candidate now has the higher total stake and is sorted first
+        let proposed_candidates = XodeStaking::proposed_candidates();
+        assert_eq!(proposed_candidates[0].who, to);
+        assert_eq!(proposed_candidates[0].total_stake, 400);
+        assert_eq!(proposed_candidates[1].who, from);
+        assert_eq!(proposed_candidates[1].total_stake, 100);
+
+        // The held stake is moved, it remains slashable by the previous candidate
+        let requests = UnbondingRequests::<Test>::get(&delegator);
+        assert_eq!(requests.len(), 1);
+        assert_eq!(requests[0].candidate, from);
+        assert_eq!(requests[0].amount, 400);
+        assert_eq!(requests[0].redelegated_to, Some(to));
+        assert_eq!(500, Balances::balance_on_hold(&HoldReason::Delegation.into(), &delegator));
+    });
+}
+
+#[test]
+fn test_pallet_xode_staking_redelegate_fully_staked_works() {
+    test1_ext().execute_with(|| {
+        System::set_block_number(1);
+        let from = 1;
+        let to = 2;
+        let delegator = 11;
+
+        // Everything but the existential deposit is staked
+        let _ = Balances::deposit_creating(&delegator, 1000);
+        assert_ok!(XodeStaking::register_candidate(RuntimeOrigin::signed(from)));
+        assert_ok!(XodeStaking::register_candidate(RuntimeOrigin::signed(to)));
+        assert_ok!(XodeStaking::stake_candidate(RuntimeOrigin::signed(delegator), from, 999));
+        assert_eq!(Balances::free_balance(&delegator), 1);
+
+        assert_ok!(XodeStaking::redelegate(RuntimeOrigin::signed(delegator), from, to, 999));
+        assert_eq!(XodeStaking::delegations_of(delegator), vec![(to, 999)]);
+        assert_eq!(999, Balances::balance_on_hold(&HoldReason::Delegation.into(), &delegator));
+        assert_eq!(Balances::free_balance(&delegator), 1);
+
+        // Once matured, the redelegation is removed but nothing is released
+        let release_block = UnbondingRequests::<Test>::get(&delegator)[0].release_block;
+        System::set_block_number(release_block);
+        assert_ok!(XodeStaking::withdraw_unbonded(RuntimeOrigin::signed(delegator)));
+        assert!(UnbondingRequests::<Test>::get(&delegator).is_empty());
+        assert!(!UnbondingAccounts::<Test>::contains_key(from, delegator));
+        assert_eq!(999, Balances::balance_on_hold(&HoldReason::Delegation.into(), &delegator));
+        assert_eq!(Delegations::<Test>::get(to).unwrap()[0].stake, 999);
+    });
+}
+
+#[test]
+fn test_pallet_xode_staking_redelegate_still_slashed_by_previous_candidate() {
+    test1_ext().execute_with(|| {
+        System::set_block_number(1);
+        let from = 1;
+        let to = 2;
+        let delegator = 11;
+
+        let _ = Balances::deposit_creating(&delegator, 1000);
+        assert_ok!(XodeStaking::register_candidate(RuntimeOrigin::signed(from)));
+        assert_ok!(XodeStaking::register_candidate(RuntimeOrigin::signed(to)));
+        assert_ok!(XodeStaking::stake_candidate(RuntimeOrigin::signed(delegator), from, 500));
+        assert_ok!(XodeStaking::redelegate(RuntimeOrigin::signed(delegator), from, to, 500));
+
+        // The previous candidate is slashed (10%) right after the redelegation
+        let mut candidate_info = XodeStaking::proposed_candidates().into_iter().find(|c| c.who == from).unwrap();
+        XodeStaking::slash_candidate(&mut candidate_info);
+
+        // The slash is taken from the delegation to the new candidate
+        assert_eq!(UnbondingRequests::<Test>::get(&delegator)[0].amount, 450);
+        assert_eq!(Delegations::<Test>::get(to).unwrap()[0].stake, 450);
+        assert_eq!(ProposedCandidates::<Test>::get(to).unwrap().total_stake, 450);
+        assert_eq!(XodeStaking::delegations_of(delegator), vec![(to, 450)]);
+        assert_eq!(450, Balances::balance_on_hold(&HoldReason::Delegation.into(), &delegator));
+        System::assert_has_event(RuntimeEvent::XodeStaking(Event::DelegatorSlashed { who: delegator, candidate: to, amount: 50 }));
+        System::assert_has_event(RuntimeEvent::XodeStaking(Event::UnbondingSlashed { who: delegator, candidate: from, amount: 50 }));
+    });
+}
+
+#[test]
+fn test_pallet_xode_staking_redelegate_then_unstake_still_slashed_by_previous_candidate() {
+    test1_ext().execute_with(|| {
+        System::set_block_number(1);
+        let from = 1;
+        let to = 2;
+        let delegator = 11;
+
+        let _ = Balances::deposit_creating(&delegator, 1000);
+        assert_ok!(XodeStaking::register_candidate(RuntimeOrigin::signed(from)));
+        assert_ok!(XodeStaking::register_candidate(RuntimeOrigin::signed(to)));
+        assert_ok!(XodeStaking::stake_candidate(RuntimeOrigin::signed(delegator), from, 500));
+        assert_ok!(XodeStaking::redelegate(RuntimeOrigin::signed(delegator), from, to, 500));
+        assert_ok!(XodeStaking::unstake_candidate(RuntimeOrigin::signed(delegator), to));
+
+        // The previous candidate is slashed (10%), the un-staked amount is still unbonding
+        let mut candidate_info = XodeStaking::proposed_candidates().into_iter().find(|c| c.who == from).unwrap();
+        XodeStaking::slash_candidate(&mut candidate_info);
+
+        let requests = UnbondingRequests::<Test>::get(&delegator);
+        assert_eq!(requests.len(), 2);
+        assert_eq!((requests[0].candidate, requests[0].amount), (from, 450));
+        assert_eq!((requests[1].candidate, requests[1].amount), (to, 450));
+        assert_eq!(450, Balances::balance_on_hold(&HoldReason::Delegation.into(), &delegator));
+    });
+}
+
+#[test]
+fn test_pallet_xode_staking_redelegate_full_amount_removes_delegation() {
+    test1_ext().execute_with(|| {
+        let from = 1;
+        let to = 2;
+        let delegator = 11;
+
+        let _ = Balances::deposit_creating(&delegator, 1000);
+        assert_ok!(XodeStaking::register_candidate(RuntimeOrigin::signed(from)));
+        assert_ok!(XodeStaking::register_candidate(RuntimeOrigin::signed(to)));
+        assert_ok!(XodeStaking::stake_candidate(RuntimeOrigin::signed(delegator), from, 500));
+        assert_ok!(XodeStaking::stake_candidate(RuntimeOrigin::signed(delegator), to, 100));
+
+        assert_ok!(XodeStaking::redelegate(RuntimeOrigin::signed(delegator), from, to, 500));
+
+        assert!(Delegations::<Test>::get(from).is_none());
+        assert_eq!(Delegations::<Test>::get(to).unwrap()[0].stake, 600);
+        assert_eq!(XodeStaking::delegations_of(delegator), vec![(to, 600)]);
+    });
+}
+
+#[test]
+fn test_pallet_xode_staking_redelegate_invalid_should_error() {
+    test1_ext().execute_with(|| {
+        let from = 1;
+        let to = 2;
+        let delegator = 11;
+
+        let _ = Balances::deposit_creating(&delegator, 1000);
+        assert_ok!(XodeStaking::register_candidate(RuntimeOrigin::signed(from)));
+        assert_ok!(XodeStaking::register_candidate(RuntimeOrigin::signed(to)));
+        assert_ok!(XodeStaking::stake_candidate(RuntimeOrigin::signed(delegator), from, 500));
+
+        assert_noop!(
+            XodeStaking::redelegate(RuntimeOrigin::signed(delegator), from, to, 0),
+            Error::<Test>::DelegationInvalidAmount
+        );
+        assert_noop!(
+            XodeStaking::redelegate(RuntimeOrigin::signed(delegator), from, from, 100),
+            Error::<Test>::DelegationSameCandidate
+        );
+        assert_noop!(
+            XodeStaking::redelegate(RuntimeOrigin::signed(delegator), from, 3, 100),
+            Error::<Test>::DelegationCandidateDoesNotExist
+        );
+        assert_noop!(
+            XodeStaking::redelegate(RuntimeOrigin::signed(delegator), from, to, 501),
+            Error::<Test>::DelegationInsufficientStake
+        );
+        // The remaining stake (5) is below the minimum delegation (10)
+        assert_noop!(
+            XodeStaking::redelegate(RuntimeOrigin::signed(delegator), from, to, 495),
+            Error::<Test>::DelegationBelowMinimum
+        );
+        // The moved stake (5) is below the minimum delegation (10)
+        assert_noop!(
+            XodeStaking::redelegate(RuntimeOrigin::signed(delegator), from, to, 5),
+            Error::<Test>::DelegationBelowMinimum
+        );
+        assert_noop!(
+            XodeStaking::redelegate(RuntimeOrigin::signed(12), from, to, 100),
+            Error::<Test>::DelegationDelegatorDoesNotExist
+        );
+    });
+}
+
+// Release Delegations Function - Unit Tests
+// Run this command: cargo test test_pallet_xode_staking_release_delegations -- --nocapture
+// =======================================================================================
+
+#[test]
+fn test_pallet_xode_staking_release_delegations_works() {
+    test1_ext().execute_with(|| {
+        System::set_block_number(1);
+        let candidate = 1;
+        let delegators = [11, 12];
+
+        assert_ok!(XodeStaking::register_candidate(RuntimeOrigin::signed(candidate)));
+        for delegator in delegators {
+            let _ = Balances::deposit_creating(&delegator, 1000);
+            assert_ok!(XodeStaking::stake_candidate(RuntimeOrigin::signed(delegator), candidate, 100));
+        }
+
+        // The candidate has no bond, hence it is removed and marked for releasing
+        assert_ok!(XodeStaking::prepare_authors());
+        assert!(XodeStaking::proposed_candidates().is_empty());
+        assert_eq!(ReleasingCandidates::<Test>::get(candidate), Some(1));
+
+        XodeStaking::on_idle(System::block_number(), Weight::MAX);
+
+        // The stakes are scheduled for unbonding, they remain held until withdrawn
+        for delegator in delegators {
+            System::assert_has_event(RuntimeEvent::XodeStaking(Event::DelegationReleased { 
+                who: delegator, candidate, amount: 100, 
+            }));
+            assert_eq!(100, Balances::balance_on_hold(&HoldReason::Delegation.into(), &delegator));
+            assert_eq!(UnbondingRequests::<Test>::get(&delegator)[0].amount, 100);
+            assert!(DelegatorState::<Test>::get(&delegator).is_empty());
+        }
+        assert!(Delegations::<Test>::get(candidate).is_none());
+        assert!(ReleasingCandidates::<Test>::get(candidate).is_none());
+
+        // Unbonding delay (2 sessions) elapsed
+        System::set_block_number(1 + 2 * MINUTES);
+        for delegator in delegators {
+            assert_ok!(XodeStaking::withdraw_unbonded(RuntimeOrigin::signed(delegator)));
+            assert_eq!(0, Balances::balance_on_hold(&HoldReason::Delegation.into(), &delegator));
+            assert_eq!(1000, Balances::free_balance(&delegator));
+        }
+    });
+}
+
+#[test]
+fn test_pallet_xode_staking_release_delegations_clears_rewards() {
+    test1_ext().execute_with(|| {
+        System::set_block_number(1);
+        let candidate = 1;
+        let delegator = 11;
+
+        let _ = Balances::deposit_creating(&candidate, 1000);
+        let _ = Balances::deposit_creating(&delegator, 1000);
+        assert_ok!(XodeStaking::register_candidate(RuntimeOrigin::signed(candidate)));
+        assert_ok!(XodeStaking::bond_candidate(RuntimeOrigin::signed(candidate), 100));
+        assert_ok!(XodeStaking::set_commission_of_candidate(RuntimeOrigin::signed(candidate), 100));
+        assert_ok!(XodeStaking::stake_candidate(RuntimeOrigin::signed(delegator), candidate, 100));
+        let _ = XodeStaking::reward_author(candidate, 300);
+        assert!(!RewardPerStake::<Test>::get(candidate).is_zero());
+
+        assert_ok!(XodeStaking::offline_candidate(RuntimeOrigin::signed(candidate)));
+        assert_ok!(XodeStaking::prepare_authors());
+        XodeStaking::on_idle(System::block_number(), Weight::MAX);
+
+        // The rewards are settled before the reward per stake and the checkpoints are cleared
+        assert!(ReleasingCandidates::<Test>::get(candidate).is_none());
+        assert!(!RewardPerStake::<Test>::contains_key(candidate));
+        assert!(!RewardCheckpoints::<Test>::contains_key(delegator, candidate));
+        assert_eq!(XodeStaking::pending_rewards_of(delegator, candidate), 300);
+
+        assert_ok!(XodeStaking::claim_rewards(RuntimeOrigin::signed(delegator), candidate));
+        assert_eq!(Balances::free_balance(&delegator), 900 + 300);
+    });
+}
+
+#[test]
+fn test_pallet_xode_staking_release_delegations_removed_candidate_bond_unbonding() {
+    test1_ext().execute_with(|| {
+        System::set_block_number(1);
+        let candidate = 1;
+
+        let _ = Balances::deposit_creating(&candidate, 1000);
+        assert_ok!(XodeStaking::register_candidate(RuntimeOrigin::signed(candidate)));
+        assert_ok!(XodeStaking::bond_candidate(RuntimeOrigin::signed(candidate), 200));
+        assert_ok!(XodeStaking::offline_candidate(RuntimeOrigin::signed(candidate)));
+
+        // The offline candidate is removed, its bond is scheduled for unbonding
+        assert_ok!(XodeStaking::prepare_authors());
+        assert!(XodeStaking::proposed_candidates().is_empty());
+        assert_eq!(200, Balances::balance_on_hold(&HoldReason::CandidateBond.into(), &candidate));
+        assert_eq!(UnbondingRequests::<Test>::get(&candidate)[0].amount, 200);
+
+        System::set_block_number(1 + 2 * MINUTES);
+        assert_ok!(XodeStaking::withdraw_unbonded(RuntimeOrigin::signed(candidate)));
+        assert_eq!(0, Balances::balance_on_hold(&HoldReason::CandidateBond.into(), &candidate));
+        assert_eq!(1000, Balances::free_balance(&candidate));
+    });
+}
+
+#[test]
+fn test_pallet_xode_staking_release_delegations_bounded_per_block() {
+    test1_ext().execute_with(|| {
+        System::set_block_number(1);
+        let candidate = 1;
+
+        assert_ok!(XodeStaking::register_candidate(RuntimeOrigin::signed(candidate)));
+        for delegator in 11..17 {
+            let _ = Balances::deposit_creating(&delegator, 1000);
+            assert_ok!(XodeStaking::stake_candidate(RuntimeOrigin::signed(delegator), candidate, 100));
+        }
+        assert_ok!(XodeStaking::prepare_authors());
+
+        // Nothing is released if there is no remaining weight
+        assert_eq!(XodeStaking::on_idle(System::block_number(), Weight::zero()), Weight::zero());
+        assert_eq!(Delegations::<Test>::get(candidate).unwrap().len(), 6);
+
+        // Only the maximum releases per block (4) are released
+        XodeStaking::on_idle(System::block_number(), Weight::MAX);
+        assert_eq!(Delegations::<Test>::get(candidate).unwrap().len(), 2);
+        assert!(ReleasingCandidates::<Test>::get(candidate).is_some());
+
+        // The remaining are released on the next block
+        System::set_block_number(2);
+        XodeStaking::on_idle(System::block_number(), Weight::MAX);
+        assert!(Delegations::<Test>::get(candidate).is_none());
+        assert!(ReleasingCandidates::<Test>::get(candidate).is_none());
+    });
+}
+
+#[test]
+fn test_pallet_xode_staking_release_delegations_kept_do_not_block_others() {
+    test1_ext().execute_with(|| {
+        System::set_block_number(1);
+        let stuck_candidate = 1;
+        let candidate = 2;
+        let stuck_delegators = 11..=(11 + MaxDelegationReleasesPerBlock::get() as u64);
+        let delegators = [21, 22];
+
+        assert_ok!(XodeStaking::register_candidate(RuntimeOrigin::signed(stuck_candidate)));
+        assert_ok!(XodeStaking::register_candidate(RuntimeOrigin::signed(candidate)));
+        for delegator in stuck_delegators.clone() {
+            let _ = Balances::deposit_creating(&delegator, 1000);
+            assert_ok!(XodeStaking::stake_candidate(RuntimeOrigin::signed(delegator), stuck_candidate, 100));
+
+            // The unbonding requests are full and none can be merged with the release
+            let request = UnbondingRequest { candidate: 99, amount: 1, release_block: 100, redelegated_to: Some(98) };
+            UnbondingRequests::<Test>::insert(delegator, BoundedVec::truncate_from(vec![request; MaxUnbondingRequests::get() as usize]));
+            UnbondingAccounts::<Test>::insert(99, delegator, ());
+        }
+        for delegator in delegators {
+            let _ = Balances::deposit_creating(&delegator, 1000);
+            assert_ok!(XodeStaking::stake_candidate(RuntimeOrigin::signed(delegator), candidate, 100));
+        }
+        assert_ok!(XodeStaking::prepare_authors());
+
+        // The kept delegations (more than the maximum releases per block) do not block the other candidate
+        XodeStaking::on_idle(System::block_number(), Weight::MAX);
+        assert!(ReleasingCandidates::<Test>::get(candidate).is_none());
+        for delegator in delegators {
+            System::assert_has_event(RuntimeEvent::XodeStaking(Event::DelegationReleased { 
+                who: delegator, candidate, amount: 100, 
+            }));
+        }
+        assert!(ReleasingCandidates::<Test>::get(stuck_candidate).is_some());
+        assert_eq!(Delegations::<Test>::get(stuck_candidate).unwrap().len(), stuck_delegators.clone().count());
+
+        // Once the unbonding requests are withdrawn, the kept delegations are released
+        for delegator in stuck_delegators.clone() {
+            UnbondingRequests::<Test>::remove(delegator);
+            UnbondingAccounts::<Test>::remove(99, delegator);
+        }
+        System::set_block_number(2);
+        XodeStaking::on_idle(System::block_number(), Weight::MAX);
+        System::set_block_number(3);
+        XodeStaking::on_idle(System::block_number(), Weight::MAX);
+        assert!(Delegations::<Test>::get(stuck_candidate).is_none());
+        assert!(ReleasingCandidates::<Test>::get(stuck_candidate).is_none());
+    });
+}
+
+#[test]
+fn test_pallet_xode_staking_release_delegations_register_while_releasing_should_error() {
+    test1_ext().execute_with(|| {
+        System::set_block_number(1);
+        let candidate = 1;
+        let delegator = 11;
+
+        let _ = Balances::deposit_creating(&delegator, 1000);
+        assert_ok!(XodeStaking::register_candidate(RuntimeOrigin::signed(candidate)));
+        assert_ok!(XodeStaking::stake_candidate(RuntimeOrigin::signed(delegator), candidate, 100));
+        assert_ok!(XodeStaking::prepare_authors());
+
+        assert_noop!(
+            XodeStaking::register_candidate(RuntimeOrigin::signed(candidate)),
+            Error::<Test>::ProposedCandidateStillReleasing
+        );
+
+        XodeStaking::on_idle(System::block_number(), Weight::MAX);
+        assert_ok!(XodeStaking::register_candidate(RuntimeOrigin::signed(candidate)));
+    });
+}
+
+// Schedule Commission Change Function - Unit Tests
+// Run this command: cargo test test_pallet_xode_staking_schedule_commission_change -- --nocapture
+// =======================================================================================
+
+#[test]
+fn test_pallet_xode_staking_schedule_commission_change_works() {
+    test1_ext().execute_with(|| {
+        System::set_block_number(1);
+        let candidate = 1;
+        let delegator = 11;
+
+        let _ = Balances::deposit_creating(&candidate, 1000);
+        let _ = Balances::deposit_creating(&delegator, 1000);
+        assert_ok!(XodeStaking::register_candidate(RuntimeOrigin::signed(candidate)));
+        assert_ok!(XodeStaking::bond_candidate(RuntimeOrigin::signed(candidate), 100));
+        assert_ok!(XodeStaking::set_commission_of_candidate(RuntimeOrigin::signed(candidate), 20));
+        assert_ok!(XodeStaking::stake_candidate(RuntimeOrigin::signed(delegator), candidate, 100));
+
+        // The decrease is applied after the delay (2 sessions)
+        assert_ok!(XodeStaking::schedule_commission_change(RuntimeOrigin::signed(candidate), 10));
+        System::assert_last_event(RuntimeEvent::XodeStaking(Event::CommissionChangeScheduled { 
+            who: candidate, old: 20, new: 10, apply_session: 2, 
+        }));
+        assert_eq!(PendingCommissions::<Test>::get(candidate).unwrap().commission, 10);
+        assert!(CommissionChangeQueue::<Test>::contains_key(2, candidate));
+
+        XodeStaking::end_session(1);
+        assert_eq!(XodeStaking::proposed_candidates()[0].commission, 20);
+
+        XodeStaking::end_session(2);
+        System::assert_has_event(RuntimeEvent::XodeStaking(Event::CommissionChangeApplied { 
+            who: candidate, old: 20, new: 10, 
+        }));
+        assert_eq!(XodeStaking::proposed_candidates()[0].commission, 10);
+        assert!(PendingCommissions::<Test>::get(candidate).is_none());
+        assert!(!CommissionChangeQueue::<Test>::contains_key(2, candidate));
+    });
+}
+
+#[test]
+fn test_pallet_xode_staking_schedule_commission_change_replaced() {
+    test1_ext().execute_with(|| {
+        System::set_block_number(1);
+        let candidate = 1;
+
+        assert_ok!(XodeStaking::register_candidate(RuntimeOrigin::signed(candidate)));
+        assert_ok!(XodeStaking::set_commission_of_candidate(RuntimeOrigin::signed(candidate), 20));
+        assert_ok!(XodeStaking::schedule_commission_change(RuntimeOrigin::signed(candidate), 10));
+
+        // Scheduling again in a later session moves the change to the new apply session
+        pallet_session::CurrentIndex::<Test>::put(1);
+        assert_ok!(XodeStaking::schedule_commission_change(RuntimeOrigin::signed(candidate), 15));
+        assert!(!CommissionChangeQueue::<Test>::contains_key(2, candidate));
+        assert!(CommissionChangeQueue::<Test>::contains_key(3, candidate));
+
+        // Only the changes queued for the ending session are applied
+        XodeStaking::apply_commission_changes(2);
+        assert_eq!(XodeStaking::proposed_candidates()[0].commission, 20);
+        XodeStaking::apply_commission_changes(3);
+        assert_eq!(XodeStaking::proposed_candidates()[0].commission, 15);
+        assert_eq!(CommissionChangeQueue::<Test>::iter().count(), 0);
+    });
+}
+
+#[test]
+fn test_pallet_xode_staking_schedule_commission_change_should_error() {
+    test1_ext().execute_with(|| {
+        let candidate = 1;
+
+        assert_ok!(XodeStaking::register_candidate(RuntimeOrigin::signed(candidate)));
+        assert_ok!(XodeStaking::set_commission_of_candidate(RuntimeOrigin::signed(candidate), 30));
+
+        assert_noop!(
+            XodeStaking::schedule_commission_change(RuntimeOrigin::signed(candidate), 0),
+            Error::<Test>::ProposedCandidateInvalidCommission
+        );
+        // The maximum change is 5 percent per session for 2 sessions
+        assert_noop!(
+            XodeStaking::schedule_commission_change(RuntimeOrigin::signed(candidate), 19),
+            Error::<Test>::ProposedCandidateCommissionChangeExceeded
+        );
+        assert_noop!(
+            XodeStaking::schedule_commission_change(RuntimeOrigin::signed(2), 10),
+            Error::<Test>::ProposedCandidateNotFound
+        );
+    });
+}
+
+#[test]
+fn test_pallet_xode_staking_set_commission_lower_should_error() {
+    test1_ext().execute_with(|| {
+        let candidate = 1;
+
+        assert_ok!(XodeStaking::register_candidate(RuntimeOrigin::signed(candidate)));
+
+        // The first commission is set immediately
+        assert_ok!(XodeStaking::set_commission_of_candidate(RuntimeOrigin::signed(candidate), 10));
+        assert_eq!(XodeStaking::proposed_candidates()[0].commission, 10);
+
+        // Lowering the share of the delegators must be scheduled, even without delegations
+        assert_noop!(
+            XodeStaking::set_commission_of_candidate(RuntimeOrigin::signed(candidate), 5),
+            Error::<Test>::ProposedCandidateCommissionNotScheduled
+        );
+        assert_noop!(
+            XodeStaking::set_commission_of_candidate(RuntimeOrigin::signed(2), 5),
+            Error::<Test>::ProposedCandidateNotFound
+        );
+
+        // Raising the share of the delegators is immediate
+        assert_ok!(XodeStaking::set_commission_of_candidate(RuntimeOrigin::signed(candidate), 50));
+        assert_eq!(XodeStaking::proposed_candidates()[0].commission, 50);
+    });
+}
+
+#[test]
+fn test_pallet_xode_staking_schedule_commission_change_discarded_after_raising() {
+    test1_ext().execute_with(|| {
+        System::set_block_number(1);
+        let candidate = 1;
+
+        assert_ok!(XodeStaking::register_candidate(RuntimeOrigin::signed(candidate)));
+        assert_ok!(XodeStaking::set_commission_of_candidate(RuntimeOrigin::signed(candidate), 50));
+
+        // The scheduled change is within the maximum change (10) of the commission at schedule time
+        assert_ok!(XodeStaking::schedule_commission_change(RuntimeOrigin::signed(candidate), 40));
+
+        // Raising the commission in between makes the pending change exceed the maximum change
+        assert_ok!(XodeStaking::set_commission_of_candidate(RuntimeOrigin::signed(candidate), 60));
+
+        XodeStaking::apply_commission_changes(2);
+        System::assert_has_event(RuntimeEvent::XodeStaking(Event::CommissionChangeDiscarded { 
+            who: candidate, old: 60, new: 40, 
+        }));
+        assert_eq!(XodeStaking::proposed_candidates()[0].commission, 60);
+        assert!(PendingCommissions::<Test>::get(candidate).is_none());
+    });
+}
+
+// Set Candidate Metadata Function - Unit Tests
+// Run this command: cargo test test_pallet_xode_staking_set_candidate_metadata -- --nocapture
+// =======================================================================================
+
+#[test]
+fn test_pallet_xode_staking_set_candidate_metadata_works() {
+    test1_ext().execute_with(|| {
+        System::set_block_number(1);
+        let candidate = 1;
+
+        let _ = Balances::deposit_creating(&candidate, 1000);
+        assert_ok!(XodeStaking::register_candidate(RuntimeOrigin::signed(candidate)));
+
+        // Deposit: base (10) + 1 per byte (4 + 11 + 8 + 24)
+        assert_ok!(XodeStaking::set_candidate_metadata(
+            RuntimeOrigin::signed(candidate),
+            b"Xode".to_vec().try_into().unwrap(),
+            b"xode.net/io".to_vec().try_into().unwrap(),
+            b"ops@xode".to_vec().try_into().unwrap(),
+            b"/dns/node.xode/tcp/30333".to_vec().try_into().unwrap(),
+        ));
+        System::assert_last_event(RuntimeEvent::XodeStaking(Event::CandidateMetadataSet { who: candidate, deposit: 57 }));
+        assert_eq!(57, Balances::balance_on_hold(&HoldReason::CandidateMetadata.into(), &candidate));
+        assert_eq!(CandidateMetadata::<Test>::get(candidate).unwrap().display_name.to_vec(), b"Xode".to_vec());
+
+        // Replacing the metadata releases the difference
+        assert_ok!(XodeStaking::set_candidate_metadata(
+            RuntimeOrigin::signed(candidate),
+            b"Xode".to_vec().try_into().unwrap(),
+            Default::default(),
+            Default::default(),
+            Default::default(),
+        ));
+        assert_eq!(14, Balances::balance_on_hold(&HoldReason::CandidateMetadata.into(), &candidate));
+    });
+}
+
+#[test]
+fn test_pallet_xode_staking_set_candidate_metadata_cleared_on_removal() {
+    test1_ext().execute_with(|| {
+        System::set_block_number(1);
+        let candidate = 1;
+
+        let _ = Balances::deposit_creating(&candidate, 1000);
+        assert_ok!(XodeStaking::register_candidate(RuntimeOrigin::signed(candidate)));
+        assert_ok!(XodeStaking::set_candidate_metadata(
+            RuntimeOrigin::signed(candidate),
+            b"Xode".to_vec().try_into().unwrap(),
+            Default::default(),
+            Default::default(),
+            Default::default(),
+        ));
+
+        // The candidate has no bond, hence it is removed together with its metadata
+        assert_ok!(XodeStaking::prepare_authors());
+        System::assert_has_event(RuntimeEvent::XodeStaking(Event::CandidateMetadataCleared { who: candidate, deposit: 14 }));
+        assert!(CandidateMetadata::<Test>::get(candidate).is_none());
+        assert_eq!(0, Balances::balance_on_hold(&HoldReason::CandidateMetadata.into(), &candidate));
+        assert_eq!(1000, Balances::free_balance(&candidate));
+    });
+}
+
+#[test]
+fn test_pallet_xode_staking_set_candidate_metadata_should_error() {
+    test1_ext().execute_with(|| {
+        let candidate = 1;
+
+        assert_noop!(
+            XodeStaking::set_candidate_metadata(
+                RuntimeOrigin::signed(candidate), 
+                Default::default(), Default::default(), Default::default(), Default::default(),
+            ),
+            Error::<Test>::ProposedCandidateNotFound
+        );
+
+        // No balance for the deposit
+        assert_ok!(XodeStaking::register_candidate(RuntimeOrigin::signed(candidate)));
+        assert_noop!(
+            XodeStaking::set_candidate_metadata(
+                RuntimeOrigin::signed(candidate), 
+                Default::default(), Default::default(), Default::default(), Default::default(),
+            ),
+            Error::<Test>::ProposedCandidateInsufficientDeposit
+        );
+    });
+}
+
+// Inflation Function - Unit Tests
+// Run this command: cargo test test_pallet_xode_staking_inflation -- --nocapture
+// =======================================================================================
+
+#[test]
+fn test_pallet_xode_staking_inflation_set_works() {
+    test1_ext().execute_with(|| {
+        System::set_block_number(1);
+        let _ = Balances::deposit_creating(&1, 1_000_000_000_000_000);
+
+        assert_noop!(
+            XodeStaking::set_inflation(RuntimeOrigin::signed(1), Perbill::from_percent(10), u128::MAX),
+            DispatchError::BadOrigin
+        );
+        assert_eq!(XodeStaking::block_inflation(), 0);
+
+        assert_ok!(XodeStaking::set_inflation(RuntimeOrigin::root(), Perbill::from_percent(10), u128::MAX));
+        System::assert_last_event(RuntimeEvent::XodeStaking(Event::InflationSet { 
+            annual_inflation: Perbill::from_percent(10), issuance_cap: u128::MAX, 
+        }));
+
+        // 10% of the total issuance divided by the blocks per year
+        let total_issuance = Balances::total_issuance();
+        assert_eq!(XodeStaking::block_inflation(), total_issuance / 10 / BlocksPerYear::get() as u128);
+
+        // Capped by the issuance cap
+        assert_ok!(XodeStaking::set_inflation(RuntimeOrigin::root(), Perbill::from_percent(10), total_issuance + 5));
+        assert_eq!(XodeStaking::block_inflation(), 5);
+        assert_ok!(XodeStaking::set_inflation(RuntimeOrigin::root(), Perbill::from_percent(10), total_issuance));
+        assert_eq!(XodeStaking::block_inflation(), 0);
+    });
+}
+
+#[test]
+fn test_pallet_xode_staking_inflation_reward_author_works() {
+    test1_ext().execute_with(|| {
+        System::set_block_number(1);
+        let candidate = 1;
+        let delegator = 11;
+
+        let _ = Balances::deposit_creating(&candidate, 1000);
+        let _ = Balances::deposit_creating(&delegator, 1000);
+        assert_ok!(XodeStaking::register_candidate(RuntimeOrigin::signed(candidate)));
+        assert_ok!(XodeStaking::bond_candidate(RuntimeOrigin::signed(candidate), 100));
+        assert_ok!(XodeStaking::set_commission_of_candidate(RuntimeOrigin::signed(candidate), 50));
+        assert_ok!(XodeStaking::stake_candidate(RuntimeOrigin::signed(delegator), candidate, 100));
+
+        let total_issuance = Balances::total_issuance();
+        let _ = XodeStaking::reward_author(candidate, 1000);
+        assert_eq!(SessionInflation::<Test>::get(), 1000);
+
+        // The sole delegator receives the commission (50%) of the reward, the rest goes to the author
+        assert_eq!(XodeStaking::delegator_share(candidate, delegator, 1000), 500);
+        assert_eq!(Balances::free_balance(&candidate), 900 + 500);
+        assert_eq!(Balances::total_issuance(), total_issuance + 1000);
+
+        // The delegators' portion stays in the pot until claimed
+        assert_eq!(Balances::free_balance(&XodeStaking::account_id()), 500);
+        assert_ok!(XodeStaking::claim_rewards(RuntimeOrigin::signed(delegator), candidate));
+        assert_eq!(Balances::free_balance(&delegator), 900 + 500);
+        assert_eq!(Balances::free_balance(&XodeStaking::account_id()), 0);
+
+        // The inflation of the session is reported once at the end of the session
+        let _ = XodeStaking::reward_author(candidate, 1000);
+        XodeStaking::end_session(1);
+        System::assert_has_event(RuntimeEvent::XodeStaking(Event::InflationMinted { session: 1, amount: 2000 }));
+        assert_eq!(SessionInflation::<Test>::get(), 0);
+    });
+}
+
+#[test]
+fn test_pallet_xode_staking_inflation_reward_unknown_author() {
+    test1_ext().execute_with(|| {
+        System::set_block_number(1);
+        let author = 99;
+
+        let total_issuance = Balances::total_issuance();
+        let _ = XodeStaking::reward_author(author, 1000);
+        assert_eq!(Balances::total_issuance(), total_issuance);
+        assert_eq!(SessionInflation::<Test>::get(), 0);
+    });
+}
+
+// Claim Rewards Function - Unit Tests
+// Run this command: cargo test test_pallet_xode_staking_claim_rewards -- --nocapture
+// =======================================================================================
+
+#[test]
+fn test_pallet_xode_staking_claim_rewards_works() {
+    test1_ext().execute_with(|| {
+        System::set_block_number(1);
+        let candidate = 1;
+
+        let _ = Balances::deposit_creating(&candidate, 1000);
+        let _ = Balances::deposit_creating(&11, 1000);
+        let _ = Balances::deposit_creating(&12, 1000);
+        assert_ok!(XodeStaking::register_candidate(RuntimeOrigin::signed(candidate)));
+        assert_ok!(XodeStaking::bond_candidate(RuntimeOrigin::signed(candidate), 100));
+        assert_ok!(XodeStaking::set_commission_of_candidate(RuntimeOrigin::signed(candidate), 100));
+        assert_ok!(XodeStaking::stake_candidate(RuntimeOrigin::signed(11), candidate, 100));
+
+        // Only delegator 11 has a stake, it accrues the whole reward
+        let _ = XodeStaking::reward_author(candidate, 300);
+        assert_eq!(XodeStaking::pending_rewards_of(11, candidate), 300);
+
+        // Delegator 12 joins, the rewards are now split by stake (100 : 200)
+        assert_ok!(XodeStaking::stake_candidate(RuntimeOrigin::signed(12), candidate, 200));
+        assert_eq!(XodeStaking::pending_rewards_of(12, candidate), 0);
+        let _ = XodeStaking::reward_author(candidate, 300);
+        assert_eq!(XodeStaking::pending_rewards_of(11, candidate), 400);
+        assert_eq!(XodeStaking::pending_rewards_of(12, candidate), 200);
+
+        // Rewards are still claimable after un-staking
+        assert_ok!(XodeStaking::unstake_candidate(RuntimeOrigin::signed(11), candidate));
+        assert_eq!(XodeStaking::pending_rewards_of(11, candidate), 400);
+
+        assert_ok!(XodeStaking::claim_rewards(RuntimeOrigin::signed(11), candidate));
+        System::assert_last_event(RuntimeEvent::XodeStaking(Event::RewardsClaimed { 
+            who: 11, candidate, amount: 400, 
+        }));
+        assert_eq!(Balances::free_balance(&11), 900 + 400);
+        assert_eq!(XodeStaking::pending_rewards_of(11, candidate), 0);
+
+        assert_noop!(
+            XodeStaking::claim_rewards(RuntimeOrigin::signed(11), candidate),
+            Error::<Test>::RewardsNothingToClaim
+        );
+    });
+}
+
+// Auto Compound Function - Unit Tests
+// Run this command: cargo test test_pallet_xode_staking_auto_compound -- --nocapture
+// =======================================================================================
+
+#[test]
+fn test_pallet_xode_staking_auto_compound_set_works() {
+    test1_ext().execute_with(|| {
+        System::set_block_number(1);
+        let candidate = 1;
+
+        let _ = Balances::deposit_creating(&candidate, 1000);
+        let _ = Balances::deposit_creating(&11, 1000);
+        assert_ok!(XodeStaking::register_candidate(RuntimeOrigin::signed(candidate)));
+        assert_ok!(XodeStaking::stake_candidate(RuntimeOrigin::signed(11), candidate, 100));
+
+        assert_ok!(XodeStaking::set_auto_compound(RuntimeOrigin::signed(11), candidate, Percent::from_percent(50)));
+        System::assert_last_event(RuntimeEvent::XodeStaking(Event::AutoCompoundSet { 
+            who: 11, candidate, auto_compound: Percent::from_percent(50), 
+        }));
+        assert_eq!(Delegations::<Test>::get(&candidate).unwrap()[0].auto_compound, Percent::from_percent(50));
+
+        // Only the delegators of the candidate can set the auto compound
+        assert_noop!(
+            XodeStaking::set_auto_compound(RuntimeOrigin::signed(12), candidate, Percent::from_percent(50)),
+            Error::<Test>::DelegationDelegatorDoesNotExist
+        );
+        assert_noop!(
+            XodeStaking::set_auto_compound(RuntimeOrigin::signed(11), 2, Percent::from_percent(50)),
+            Error::<Test>::DelegationsDoesNotExist
+        );
+    });
+}
+
+#[test]
+fn test_pallet_xode_staking_auto_compound_restakes_rewards() {
+    test1_ext().execute_with(|| {
+        System::set_block_number(1);
+        let candidate = 1;
+
+        let _ = Balances::deposit_creating(&candidate, 1000);
+        let _ = Balances::deposit_creating(&11, 1000);
+        let _ = Balances::deposit_creating(&12, 1000);
+        assert_ok!(XodeStaking::register_candidate(RuntimeOrigin::signed(candidate)));
+        assert_ok!(XodeStaking::bond_candidate(RuntimeOrigin::signed(candidate), 100));
+        assert_ok!(XodeStaking::set_commission_of_candidate(RuntimeOrigin::signed(candidate), 100));
+        assert_ok!(XodeStaking::stake_candidate(RuntimeOrigin::signed(11), candidate, 100));
+        assert_ok!(XodeStaking::stake_candidate(RuntimeOrigin::signed(12), candidate, 100));
+        assert_ok!(XodeStaking::set_auto_compound(RuntimeOrigin::signed(11), candidate, Percent::from_percent(50)));
+
+        // Both delegators accrue 200, only delegator 11 compounds (half of it)
+        let _ = XodeStaking::reward_author(candidate, 400);
+        assert_ok!(XodeStaking::add_author(candidate));
+        let _ = XodeStaking::queue_compounding();
+        XodeStaking::on_idle(System::block_number(), Weight::MAX);
+        System::assert_last_event(RuntimeEvent::XodeStaking(Event::DelegationCompounded { 
+            who: 11, candidate, amount: 100, 
+        }));
+
+        let delegations = Delegations::<Test>::get(&candidate).unwrap();
+        assert_eq!(delegations.iter().find(|d| d.delegator == 11).unwrap().stake, 200);
+        assert_eq!(delegations.iter().find(|d| d.delegator == 12).unwrap().stake, 100);
+        assert_eq!(XodeStaking::delegations_of(11), vec![(candidate, 200)]);
+        assert_eq!(Balances::balance_on_hold(&HoldReason::Delegation.into(), &11), 200);
+        assert_eq!(XodeStaking::proposed_candidates()[0].total_stake, 300);
+
+        // The rest of the rewards remains claimable
+        assert_eq!(XodeStaking::pending_rewards_of(11, candidate), 100);
+        assert_eq!(XodeStaking::pending_rewards_of(12, candidate), 200);
+        assert_eq!(Balances::free_balance(&XodeStaking::account_id()), 300);
+        assert!(CompoundingCandidates::<Test>::get(candidate).is_none());
+    });
+}
+
+#[test]
+fn test_pallet_xode_staking_auto_compound_bounded_per_block() {
+    test1_ext().execute_with(|| {
+        System::set_block_number(1);
+        let candidate = 1;
+        let delegators = [11, 12, 13];
+
+        let _ = Balances::deposit_creating(&candidate, 1000);
+        assert_ok!(XodeStaking::register_candidate(RuntimeOrigin::signed(candidate)));
+        assert_ok!(XodeStaking::bond_candidate(RuntimeOrigin::signed(candidate), 100));
+        assert_ok!(XodeStaking::set_commission_of_candidate(RuntimeOrigin::signed(candidate), 100));
+        for delegator in delegators {
+            let _ = Balances::deposit_creating(&delegator, 1000);
+            assert_ok!(XodeStaking::stake_candidate(RuntimeOrigin::signed(delegator), candidate, 100));
+            assert_ok!(XodeStaking::set_auto_compound(RuntimeOrigin::signed(delegator), candidate, Percent::from_percent(100)));
+        }
+        let _ = XodeStaking::reward_author(candidate, 300);
+        assert_ok!(XodeStaking::add_author(candidate));
+
+        // Nothing is compounded at the end of the session, the candidate is only queued
+        XodeStaking::end_session(0);
+        assert_eq!(CompoundingCandidates::<Test>::get(candidate), Some(0));
+        assert_eq!(XodeStaking::proposed_candidates()[0].total_stake, 300);
+
+        // Nothing is compounded if there is no remaining weight
+        assert_eq!(XodeStaking::on_idle(System::block_number(), Weight::zero()), Weight::zero());
+        assert_eq!(CompoundingCandidates::<Test>::get(candidate), Some(0));
+
+        // Only a single delegation fits in the remaining weight
+        let db_weight = <Test as frame_system::Config>::DbWeight::get();
+        let weight = db_weight.reads_writes(3, 1)
+            .saturating_add(db_weight.reads_writes(3, 3))
+            .saturating_add(db_weight.reads_writes(7, 6))
+            .saturating_add(db_weight.reads_writes(1, 1));
+        XodeStaking::on_idle(System::block_number(), weight);
+        assert_eq!(CompoundingCandidates::<Test>::get(candidate), Some(1));
+        assert_eq!(XodeStaking::proposed_candidates()[0].total_stake, 400);
+
+        // The remaining delegations are compounded on the next block
+        System::set_block_number(2);
+        XodeStaking::on_idle(System::block_number(), Weight::MAX);
+        assert!(CompoundingCandidates::<Test>::get(candidate).is_none());
+        for delegator in delegators {
+            assert_eq!(XodeStaking::delegations_of(delegator), vec![(candidate, 200)]);
+            assert_eq!(XodeStaking::pending_rewards_of(delegator, candidate), 0);
+        }
+        assert_eq!(XodeStaking::proposed_candidates()[0].total_stake, 600);
+    });
+}
+
+#[test]
+fn test_pallet_xode_staking_auto_compound_failure_reverts_delegation() {
+    test1_ext().execute_with(|| {
+        System::set_block_number(1);
+        let candidate = 1;
+        let delegator = 11;
+
+        let _ = Balances::deposit_creating(&candidate, 1000);
+        let _ = Balances::deposit_creating(&delegator, 1000);
+        assert_ok!(XodeStaking::register_candidate(RuntimeOrigin::signed(candidate)));
+        assert_ok!(XodeStaking::bond_candidate(RuntimeOrigin::signed(candidate), 100));
+        assert_ok!(XodeStaking::set_commission_of_candidate(RuntimeOrigin::signed(candidate), 100));
+        assert_ok!(XodeStaking::stake_candidate(RuntimeOrigin::signed(delegator), candidate, 100));
+        assert_ok!(XodeStaking::set_auto_compound(RuntimeOrigin::signed(delegator), candidate, Percent::from_percent(50)));
+
+        // The pot cannot pay the compounded amount
+        let _ = XodeStaking::reward_author(candidate, 400);
+        let _ = Balances::make_free_balance_be(&XodeStaking::account_id(), 0);
+        assert_ok!(XodeStaking::add_author(candidate));
+        let _ = XodeStaking::queue_compounding();
+        XodeStaking::on_idle(System::block_number(), Weight::MAX);
+
+        // Nothing is compounded and the rewards remain claimable
+        assert_eq!(Delegations::<Test>::get(&candidate).unwrap()[0].stake, 100);
+        assert_eq!(XodeStaking::delegations_of(delegator), vec![(candidate, 100)]);
+        assert_eq!(Balances::balance_on_hold(&HoldReason::Delegation.into(), &delegator), 100);
+        assert_eq!(XodeStaking::pending_rewards_of(delegator, candidate), 400);
+        assert_eq!(XodeStaking::proposed_candidates()[0].total_stake, 100);
+    });
+}
+
+// Minimum Delegation and Kick Lowest Delegator - Unit Tests
+// Run this command: cargo test test_pallet_xode_staking_stake_candidate -- --nocapture
+// =======================================================================================
+
+#[test]
+fn test_pallet_xode_staking_stake_candidate_below_minimum() {
+    test1_ext().execute_with(|| {
+        System::set_block_number(1);
+        let candidate = 1;
+        let delegator = 11;
+
+        let _ = Balances::deposit_creating(&candidate, 1000);
+        let _ = Balances::deposit_creating(&delegator, 1000);
+        assert_ok!(XodeStaking::register_candidate(RuntimeOrigin::signed(candidate)));
+
+        assert_noop!(
+            XodeStaking::stake_candidate(RuntimeOrigin::signed(delegator), candidate, 9),
+            Error::<Test>::DelegationBelowMinimum
+        );
+        assert_eq!(Balances::balance_on_hold(&HoldReason::Delegation.into(), &delegator), 0);
+
+        assert_ok!(XodeStaking::stake_candidate(RuntimeOrigin::signed(delegator), candidate, 10));
+        // Increasing an existing delegation is not subject to the minimum of a new delegation
+        assert_ok!(XodeStaking::stake_candidate(RuntimeOrigin::signed(delegator), candidate, 1));
+        assert_eq!(XodeStaking::delegations_of(delegator), vec![(candidate, 11)]);
+    });
+}
+
+#[test]
+fn test_pallet_xode_staking_stake_candidate_kicks_lowest_delegator() {
+    test1_ext().execute_with(|| {
+        System::set_block_number(1);
+        let candidate = 1;
+        let max_delegates = <Test as crate::Config>::MaxProposedCandidateDelegates::get() as u64;
+
+        let _ = Balances::deposit_creating(&candidate, 1000);
+        assert_ok!(XodeStaking::register_candidate(RuntimeOrigin::signed(candidate)));
+
+        // Fill the candidate, delegator 100 has the lowest stake
+        for delegator in 100..100 + max_delegates {
+            let _ = Balances::deposit_creating(&delegator, 1000);
+            let stake = if delegator == 100 { 10 } else { 50 };
+            assert_ok!(XodeStaking::stake_candidate(RuntimeOrigin::signed(delegator), candidate, stake));
+        }
+        let newcomer = 1000;
+        let _ = Balances::deposit_creating(&newcomer, 1000);
+
+        // Not higher than the lowest delegation, the candidate remains full
+        assert_noop!(
+            XodeStaking::stake_candidate(RuntimeOrigin::signed(newcomer), candidate, 10),
+            Error::<Test>::DelegationsMaxExceeded
+        );
+
+        assert_ok!(XodeStaking::stake_candidate(RuntimeOrigin::signed(newcomer), candidate, 20));
+        System::assert_has_event(RuntimeEvent::XodeStaking(Event::DelegationKicked { 
+            who: 100, candidate, amount: 10, 
+        }));
+
+        let delegations = Delegations::<Test>::get(&candidate).unwrap();
+        assert_eq!(delegations.len() as u64, max_delegates);
+        assert!(delegations.iter().all(|d| d.delegator != 100));
+        assert!(DelegatorState::<Test>::get(100).is_empty());
+        assert_eq!(XodeStaking::proposed_candidates()[0].total_stake, 50 * (max_delegates as u128 - 1) + 20);
+
+        // The kicked stake is queued for unbonding
+        let requests = UnbondingRequests::<Test>::get(100);
+        assert_eq!(requests.len(), 1);
+        assert_eq!(requests[0].amount, 10);
+        assert_eq!(Balances::balance_on_hold(&HoldReason::Delegation.into(), &100), 10);
+    });
+}
+
+// Events - Unit Tests
+// Run this command: cargo test test_pallet_xode_staking_events -- --nocapture
+// =======================================================================================
+
+#[test]
+fn test_pallet_xode_staking_events_carry_amounts_and_totals() {
+    test1_ext().execute_with(|| {
+        System::set_block_number(1);
+        let candidate = 1;
+        let delegator = 11;
+
+        let _ = Balances::deposit_creating(&candidate, 1000);
+        let _ = Balances::deposit_creating(&delegator, 1000);
+        assert_ok!(XodeStaking::register_candidate(RuntimeOrigin::signed(candidate)));
+        System::assert_last_event(RuntimeEvent::XodeStaking(Event::ProposedCandidateAdded { who: candidate }));
+
+        assert_ok!(XodeStaking::bond_candidate(RuntimeOrigin::signed(candidate), 100));
+        System::assert_last_event(RuntimeEvent::XodeStaking(Event::ProposedCandidateBonded { 
+            who: candidate, old: 0, new: 100, 
+        }));
+
+        assert_ok!(XodeStaking::set_commission_of_candidate(RuntimeOrigin::signed(candidate), 10));
+        System::assert_last_event(RuntimeEvent::XodeStaking(Event::ProposedCandidateCommissionSet { 
+            who: candidate, old: 0, new: 10, 
+        }));
+
+        assert_ok!(XodeStaking::stake_candidate(RuntimeOrigin::signed(delegator), candidate, 500));
+        System::assert_has_event(RuntimeEvent::XodeStaking(Event::ProposedCandidateTotalStake { 
+            who: candidate, total_stake: 500, 
+        }));
+        System::assert_last_event(RuntimeEvent::XodeStaking(Event::DelegationAdded { 
+            who: delegator, candidate, amount: 500, total_stake: 500, 
+        }));
+
+        assert_ok!(XodeStaking::unstake_candidate(RuntimeOrigin::signed(delegator), candidate));
+        System::assert_last_event(RuntimeEvent::XodeStaking(Event::DelegationRevoked { 
+            who: delegator, candidate, amount: 500, total_stake: 0, 
+        }));
+    });
+}
+
+#[test]
+fn test_pallet_xode_staking_events_session_rotated() {
+    test1_ext().execute_with(|| {
+        System::set_block_number(1);
+        let stale_author = 99;
+
+        // An author that is no longer wait listed is removed at the end of the session
+        let _ = XodeStaking::add_invulnerable(stale_author);
+        XodeStaking::end_session(1);
+
+        let selected = pallet_collator_selection::Invulnerables::<Test>::get().to_vec();
+        assert!(!selected.contains(&stale_author));
+        System::assert_last_event(RuntimeEvent::XodeStaking(Event::SessionRotated { 
+            session: 1, selected, removed: vec![stale_author], 
+        }));
+    });
+}
@@ -1,16 +1,16 @@
 //! Autogenerated weights for pallet_xode_staking
 //!
 //! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 43.1.0
-//! DATE: 2025-11-25, STEPS: `50`, REPEAT: `20`, LOW RANGE: `[]`, HIGH RANGE: `[]`
+//! DATE: 2026-10-17, STEPS: `50`, REPEAT: `20`, LOW RANGE: `[]`, HIGH RANGE: `[]`
 //! WORST CASE MAP SIZE: `1000000`
-//! HOSTNAME: `hgminerva-vivobook`, CPU: `13th Gen Intel(R) Core(TM) i9-13900H`
+//! HOSTNAME: `vm`, CPU: `Intel(R) Xeon(R) Processor`
 //! EXECUTION: , WASM-EXECUTION: Compiled, CHAIN: None, DB CACHE: 1024
 
 // Executed Command:
-// target/release/xode-node
-// benchmark
-// pallet
-// --execution=wasm
+// /tmp/bench/target/release/bench
+// --runtime
+// /tmp/xode_runtime.wasm
+// --genesis-builder=runtime
 // --wasm-execution=compiled
 // --heap-pages=4096
 // --pallet
@@ -44,220 +44,753 @@ pub trait WeightInfo {
   fn offline_candidate() -> Weight;
   fn online_candidate() -> Weight;
   fn bond_correction() -> Weight;
+  fn withdraw_unbonded() -> Weight;
+  fn add_desired_candidate() -> Weight;
+  fn remove_desired_candidate() -> Weight;
+  fn swap_desired_candidate() -> Weight;
+  fn decrease_delegation() -> Weight;
+  fn redelegate() -> Weight;
+  fn release_delegation() -> Weight;
+  fn schedule_commission_change() -> Weight;
+  fn set_candidate_metadata() -> Weight;
+  fn set_inflation() -> Weight;
+  fn claim_rewards() -> Weight;
+  fn set_auto_compound() -> Weight;
+  fn heartbeat() -> Weight;
 }
 
 /// Weights for pallet_xode_staking using the Substrate node and recommended hardware.
 pub struct SubstrateWeight<T>(PhantomData<T>);
 impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
   /// Storage: `XodeStaking::ProposedCandidates` (r:1 w:1)
-  /// Proof: `XodeStaking::ProposedCandidates` (`max_values`: Some(1), `max_size`: Some(7702), added: 8197, mode: `MaxEncodedLen`)
+  /// Proof: `XodeStaking::ProposedCandidates` (`max_values`: None, `max_size`: Some(117), added: 2592, mode: `MaxEncodedLen`)
+  /// Storage: `XodeStaking::ReleasingCandidates` (r:1 w:0)
+  /// Proof: `XodeStaking::ReleasingCandidates` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+  /// Storage: `XodeStaking::SortedCandidates` (r:1 w:1)
+  /// Proof: `XodeStaking::SortedCandidates` (`max_values`: Some(1), `max_size`: Some(5302), added: 5797, mode: `MaxEncodedLen`)
   fn register_candidate() -> Weight {
     // Proof Size summary in bytes:
-    //  Measured:  `27`
-    //  Estimated: `9187`
-    // Minimum execution time: 9_422_000 picoseconds.
-    Weight::from_parts(9_795_000, 9187)
-      .saturating_add(T::DbWeight::get().reads(1_u64))
-      .saturating_add(T::DbWeight::get().writes(1_u64))
+    //  Measured:  `5927`
+    //  Estimated: `6787`
+    // Minimum execution time: 41_454_000 picoseconds.
+    Weight::from_parts(45_992_000, 6787)
+      .saturating_add(T::DbWeight::get().reads(3_u64))
+      .saturating_add(T::DbWeight::get().writes(2_u64))
   }
   /// Storage: `XodeStaking::ProposedCandidates` (r:1 w:1)
-  /// Proof: `XodeStaking::ProposedCandidates` (`max_values`: Some(1), `max_size`: Some(7702), added: 8197, mode: `MaxEncodedLen`)
+  /// Proof: `XodeStaking::ProposedCandidates` (`max_values`: None, `max_size`: Some(117), added: 2592, mode: `MaxEncodedLen`)
+  /// Storage: `Balances::Holds` (r:1 w:1)
+  /// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(157), added: 2632, mode: `MaxEncodedLen`)
+  /// Storage: `XodeStaking::SortedCandidates` (r:1 w:1)
+  /// Proof: `XodeStaking::SortedCandidates` (`max_values`: Some(1), `max_size`: Some(5302), added: 5797, mode: `MaxEncodedLen`)
   fn bond_candidate() -> Weight {
     // Proof Size summary in bytes:
-    //  Measured:  `185`
-    //  Estimated: `9187`
-    // Minimum execution time: 25_590_000 picoseconds.
-    Weight::from_parts(26_115_000, 9187)
-      .saturating_add(T::DbWeight::get().reads(1_u64))
-      .saturating_add(T::DbWeight::get().writes(1_u64))
+    //  Measured:  `6104`
+    //  Estimated: `6787`
+    // Minimum execution time: 91_292_000 picoseconds.
+    Weight::from_parts(152_187_000, 6787)
+      .saturating_add(T::DbWeight::get().reads(3_u64))
+      .saturating_add(T::DbWeight::get().writes(3_u64))
   }
   /// Storage: `XodeStaking::ProposedCandidates` (r:1 w:1)
-  /// Proof: `XodeStaking::ProposedCandidates` (`max_values`: Some(1), `max_size`: Some(7702), added: 8197, mode: `MaxEncodedLen`)
+  /// Proof: `XodeStaking::ProposedCandidates` (`max_values`: None, `max_size`: Some(117), added: 2592, mode: `MaxEncodedLen`)
+  /// Storage: `XodeStaking::SortedCandidates` (r:1 w:1)
+  /// Proof: `XodeStaking::SortedCandidates` (`max_values`: Some(1), `max_size`: Some(5302), added: 5797, mode: `MaxEncodedLen`)
   fn set_commission_of_candidate() -> Weight {
     // Proof Size summary in bytes:
-    //  Measured:  `185`
-    //  Estimated: `9187`
-    // Minimum execution time: 11_391_000 picoseconds.
-    Weight::from_parts(12_123_000, 9187)
-      .saturating_add(T::DbWeight::get().reads(1_u64))
-      .saturating_add(T::DbWeight::get().writes(1_u64))
+    //  Measured:  `6104`
+    //  Estimated: `6787`
+    // Minimum execution time: 53_051_000 picoseconds.
+    Weight::from_parts(58_764_000, 6787)
+      .saturating_add(T::DbWeight::get().reads(2_u64))
+      .saturating_add(T::DbWeight::get().writes(2_u64))
   }
   /// Storage: `XodeStaking::ProposedCandidates` (r:1 w:1)
-  /// Proof: `XodeStaking::ProposedCandidates` (`max_values`: Some(1), `max_size`: Some(7702), added: 8197, mode: `MaxEncodedLen`)
+  /// Proof: `XodeStaking::ProposedCandidates` (`max_values`: None, `max_size`: Some(117), added: 2592, mode: `MaxEncodedLen`)
+  /// Storage: `XodeStaking::SortedCandidates` (r:1 w:1)
+  /// Proof: `XodeStaking::SortedCandidates` (`max_values`: Some(1), `max_size`: Some(5302), added: 5797, mode: `MaxEncodedLen`)
   fn leave_candidate() -> Weight {
     // Proof Size summary in bytes:
-    //  Measured:  `219`
-    //  Estimated: `9187`
-    // Minimum execution time: 10_783_000 picoseconds.
-    Weight::from_parts(11_633_000, 9187)
-      .saturating_add(T::DbWeight::get().reads(1_u64))
-      .saturating_add(T::DbWeight::get().writes(1_u64))
+    //  Measured:  `6104`
+    //  Estimated: `6787`
+    // Minimum execution time: 33_512_000 picoseconds.
+    Weight::from_parts(37_257_000, 6787)
+      .saturating_add(T::DbWeight::get().reads(2_u64))
+      .saturating_add(T::DbWeight::get().writes(2_u64))
   }
   /// Storage: `XodeStaking::ProposedCandidates` (r:1 w:1)
-  /// Proof: `XodeStaking::ProposedCandidates` (`max_values`: Some(1), `max_size`: Some(7702), added: 8197, mode: `MaxEncodedLen`)
+  /// Proof: `XodeStaking::ProposedCandidates` (`max_values`: None, `max_size`: Some(117), added: 2592, mode: `MaxEncodedLen`)
+  /// Storage: `Balances::Holds` (r:1 w:1)
+  /// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(157), added: 2632, mode: `MaxEncodedLen`)
   /// Storage: `XodeStaking::Delegations` (r:1 w:1)
-  /// Proof: `XodeStaking::Delegations` (`max_values`: None, `max_size`: Some(4842), added: 7317, mode: `MaxEncodedLen`)
+  /// Proof: `XodeStaking::Delegations` (`max_values`: None, `max_size`: Some(4942), added: 7417, mode: `MaxEncodedLen`)
+  /// Storage: `XodeStaking::DelegatorState` (r:2 w:2)
+  /// Proof: `XodeStaking::DelegatorState` (`max_values`: None, `max_size`: Some(1001), added: 3476, mode: `MaxEncodedLen`)
+  /// Storage: `XodeStaking::RewardPerStake` (r:1 w:0)
+  /// Proof: `XodeStaking::RewardPerStake` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+  /// Storage: `XodeStaking::RewardCheckpoints` (r:2 w:2)
+  /// Proof: `XodeStaking::RewardCheckpoints` (`max_values`: None, `max_size`: Some(96), added: 2571, mode: `MaxEncodedLen`)
+  /// Storage: `XodeStaking::UnbondingRequests` (r:1 w:1)
+  /// Proof: `XodeStaking::UnbondingRequests` (`max_values`: None, `max_size`: Some(2761), added: 5236, mode: `MaxEncodedLen`)
+  /// Storage: `XodeStaking::SortedCandidates` (r:1 w:1)
+  /// Proof: `XodeStaking::SortedCandidates` (`max_values`: Some(1), `max_size`: Some(5302), added: 5797, mode: `MaxEncodedLen`)
+  /// Storage: `XodeStaking::UnbondingAccounts` (r:0 w:1)
+  /// Proof: `XodeStaking::UnbondingAccounts` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
   fn stake_candidate() -> Weight {
     // Proof Size summary in bytes:
-    //  Measured:  `108`
-    //  Estimated: `9187`
-    // Minimum execution time: 62_467_000 picoseconds.
-    Weight::from_parts(65_009_000, 9187)
-      .saturating_add(T::DbWeight::get().reads(2_u64))
-      .saturating_add(T::DbWeight::get().writes(2_u64))
+    //  Measured:  `13357`
+    //  Estimated: `8407`
+    // Minimum execution time: 224_461_000 picoseconds.
+    Weight::from_parts(236_952_000, 8407)
+      .saturating_add(T::DbWeight::get().reads(10_u64))
+      .saturating_add(T::DbWeight::get().writes(10_u64))
   }
   /// Storage: `XodeStaking::Delegations` (r:1 w:1)
-  /// Proof: `XodeStaking::Delegations` (`max_values`: None, `max_size`: Some(4842), added: 7317, mode: `MaxEncodedLen`)
+  /// Proof: `XodeStaking::Delegations` (`max_values`: None, `max_size`: Some(4942), added: 7417, mode: `MaxEncodedLen`)
+  /// Storage: `XodeStaking::DelegatorState` (r:1 w:1)
+  /// Proof: `XodeStaking::DelegatorState` (`max_values`: None, `max_size`: Some(1001), added: 3476, mode: `MaxEncodedLen`)
+  /// Storage: `XodeStaking::RewardPerStake` (r:1 w:0)
+  /// Proof: `XodeStaking::RewardPerStake` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+  /// Storage: `XodeStaking::RewardCheckpoints` (r:1 w:1)
+  /// Proof: `XodeStaking::RewardCheckpoints` (`max_values`: None, `max_size`: Some(96), added: 2571, mode: `MaxEncodedLen`)
+  /// Storage: `XodeStaking::UnbondingRequests` (r:1 w:1)
+  /// Proof: `XodeStaking::UnbondingRequests` (`max_values`: None, `max_size`: Some(2761), added: 5236, mode: `MaxEncodedLen`)
   /// Storage: `XodeStaking::ProposedCandidates` (r:1 w:1)
-  /// Proof: `XodeStaking::ProposedCandidates` (`max_values`: Some(1), `max_size`: Some(7702), added: 8197, mode: `MaxEncodedLen`)
+  /// Proof: `XodeStaking::ProposedCandidates` (`max_values`: None, `max_size`: Some(117), added: 2592, mode: `MaxEncodedLen`)
+  /// Storage: `XodeStaking::SortedCandidates` (r:1 w:1)
+  /// Proof: `XodeStaking::SortedCandidates` (`max_values`: Some(1), `max_size`: Some(5302), added: 5797, mode: `MaxEncodedLen`)
+  /// Storage: `XodeStaking::UnbondingAccounts` (r:0 w:1)
+  /// Proof: `XodeStaking::UnbondingAccounts` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
   fn unstake_candidate() -> Weight {
     // Proof Size summary in bytes:
-    //  Measured:  `300`
-    //  Estimated: `9187`
-    // Minimum execution time: 40_944_000 picoseconds.
-    Weight::from_parts(42_495_000, 9187)
+    //  Measured:  `6450`
+    //  Estimated: `8407`
+    // Minimum execution time: 87_651_000 picoseconds.
+    Weight::from_parts(136_291_000, 8407)
+      .saturating_add(T::DbWeight::get().reads(7_u64))
+      .saturating_add(T::DbWeight::get().writes(7_u64))
+  }
+  /// Storage: `XodeStaking::ProposedCandidates` (r:1 w:1)
+  /// Proof: `XodeStaking::ProposedCandidates` (`max_values`: None, `max_size`: Some(117), added: 2592, mode: `MaxEncodedLen`)
+  /// Storage: `XodeStaking::WaitingCandidates` (r:1 w:0)
+  /// Proof: `XodeStaking::WaitingCandidates` (`max_values`: Some(1), `max_size`: Some(3202), added: 3697, mode: `MaxEncodedLen`)
+  /// Storage: `XodeStaking::SortedCandidates` (r:1 w:1)
+  /// Proof: `XodeStaking::SortedCandidates` (`max_values`: Some(1), `max_size`: Some(5302), added: 5797, mode: `MaxEncodedLen`)
+  fn offline_candidate() -> Weight {
+    // Proof Size summary in bytes:
+    //  Measured:  `6105`
+    //  Estimated: `6787`
+    // Minimum execution time: 40_733_000 picoseconds.
+    Weight::from_parts(71_126_000, 6787)
+      .saturating_add(T::DbWeight::get().reads(3_u64))
+      .saturating_add(T::DbWeight::get().writes(2_u64))
+  }
+  /// Storage: `XodeStaking::ProposedCandidates` (r:1 w:1)
+  /// Proof: `XodeStaking::ProposedCandidates` (`max_values`: None, `max_size`: Some(117), added: 2592, mode: `MaxEncodedLen`)
+  /// Storage: `XodeStaking::SortedCandidates` (r:1 w:1)
+  /// Proof: `XodeStaking::SortedCandidates` (`max_values`: Some(1), `max_size`: Some(5302), added: 5797, mode: `MaxEncodedLen`)
+  fn online_candidate() -> Weight {
+    // Proof Size summary in bytes:
+    //  Measured:  `6051`
+    //  Estimated: `6787`
+    // Minimum execution time: 57_007_000 picoseconds.
+    Weight::from_parts(63_189_000, 6787)
       .saturating_add(T::DbWeight::get().reads(2_u64))
       .saturating_add(T::DbWeight::get().writes(2_u64))
   }
   /// Storage: `XodeStaking::ProposedCandidates` (r:1 w:1)
-  /// Proof: `XodeStaking::ProposedCandidates` (`max_values`: Some(1), `max_size`: Some(7702), added: 8197, mode: `MaxEncodedLen`)
-  fn offline_candidate() -> Weight {
+  /// Proof: `XodeStaking::ProposedCandidates` (`max_values`: None, `max_size`: Some(117), added: 2592, mode: `MaxEncodedLen`)
+  /// Storage: `XodeStaking::SortedCandidates` (r:1 w:1)
+  /// Proof: `XodeStaking::SortedCandidates` (`max_values`: Some(1), `max_size`: Some(5302), added: 5797, mode: `MaxEncodedLen`)
+  fn bond_correction() -> Weight {
+    // Proof Size summary in bytes:
+    //  Measured:  `6104`
+    //  Estimated: `6787`
+    // Minimum execution time: 33_798_000 picoseconds.
+    Weight::from_parts(39_840_000, 6787)
+      .saturating_add(T::DbWeight::get().reads(2_u64))
+      .saturating_add(T::DbWeight::get().writes(2_u64))
+  }
+  /// Storage: `XodeStaking::UnbondingRequests` (r:1 w:1)
+  /// Proof: `XodeStaking::UnbondingRequests` (`max_values`: None, `max_size`: Some(2761), added: 5236, mode: `MaxEncodedLen`)
+  /// Storage: `Balances::Holds` (r:1 w:1)
+  /// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(157), added: 2632, mode: `MaxEncodedLen`)
+  /// Storage: `XodeStaking::UnbondingAccounts` (r:0 w:32)
+  /// Proof: `XodeStaking::UnbondingAccounts` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+  fn withdraw_unbonded() -> Weight {
     // Proof Size summary in bytes:
-    //  Measured:  `108`
-    //  Estimated: `9187`
-    // Minimum execution time: 13_451_000 picoseconds.
-    Weight::from_parts(13_986_000, 9187)
+    //  Measured:  `1926`
+    //  Estimated: `6226`
+    // Minimum execution time: 171_325_000 picoseconds.
+    Weight::from_parts(180_705_000, 6226)
+      .saturating_add(T::DbWeight::get().reads(2_u64))
+      .saturating_add(T::DbWeight::get().writes(34_u64))
+  }
+  /// Storage: `XodeStaking::DesiredCandidates` (r:1 w:1)
+  /// Proof: `XodeStaking::DesiredCandidates` (`max_values`: Some(1), `max_size`: Some(3202), added: 3697, mode: `MaxEncodedLen`)
+  fn add_desired_candidate() -> Weight {
+    // Proof Size summary in bytes:
+    //  Measured:  `131`
+    //  Estimated: `4687`
+    // Minimum execution time: 25_937_000 picoseconds.
+    Weight::from_parts(28_703_000, 4687)
       .saturating_add(T::DbWeight::get().reads(1_u64))
       .saturating_add(T::DbWeight::get().writes(1_u64))
   }
-  /// Storage: `XodeStaking::ProposedCandidates` (r:1 w:1)
-  /// Proof: `XodeStaking::ProposedCandidates` (`max_values`: Some(1), `max_size`: Some(7702), added: 8197, mode: `MaxEncodedLen`)
-  fn online_candidate() -> Weight {
+  /// Storage: `XodeStaking::DesiredCandidates` (r:1 w:1)
+  /// Proof: `XodeStaking::DesiredCandidates` (`max_values`: Some(1), `max_size`: Some(3202), added: 3697, mode: `MaxEncodedLen`)
+  fn remove_desired_candidate() -> Weight {
+    // Proof Size summary in bytes:
+    //  Measured:  `163`
+    //  Estimated: `4687`
+    // Minimum execution time: 25_229_000 picoseconds.
+    Weight::from_parts(26_723_000, 4687)
+      .saturating_add(T::DbWeight::get().reads(1_u64))
+      .saturating_add(T::DbWeight::get().writes(1_u64))
+  }
+  /// Storage: `XodeStaking::DesiredCandidates` (r:1 w:1)
+  /// Proof: `XodeStaking::DesiredCandidates` (`max_values`: Some(1), `max_size`: Some(3202), added: 3697, mode: `MaxEncodedLen`)
+  fn swap_desired_candidate() -> Weight {
     // Proof Size summary in bytes:
-    //  Measured:  `108`
-    //  Estimated: `9187`
-    // Minimum execution time: 13_771_000 picoseconds.
-    Weight::from_parts(14_293_000, 9187)
+    //  Measured:  `163`
+    //  Estimated: `4687`
+    // Minimum execution time: 18_656_000 picoseconds.
+    Weight::from_parts(25_311_000, 4687)
       .saturating_add(T::DbWeight::get().reads(1_u64))
       .saturating_add(T::DbWeight::get().writes(1_u64))
   }
+  /// Storage: `XodeStaking::Delegations` (r:1 w:1)
+  /// Proof: `XodeStaking::Delegations` (`max_values`: None, `max_size`: Some(4942), added: 7417, mode: `MaxEncodedLen`)
+  /// Storage: `XodeStaking::DelegatorState` (r:1 w:1)
+  /// Proof: `XodeStaking::DelegatorState` (`max_values`: None, `max_size`: Some(1001), added: 3476, mode: `MaxEncodedLen`)
+  /// Storage: `XodeStaking::RewardPerStake` (r:1 w:0)
+  /// Proof: `XodeStaking::RewardPerStake` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+  /// Storage: `XodeStaking::RewardCheckpoints` (r:1 w:1)
+  /// Proof: `XodeStaking::RewardCheckpoints` (`max_values`: None, `max_size`: Some(96), added: 2571, mode: `MaxEncodedLen`)
+  /// Storage: `XodeStaking::UnbondingRequests` (r:1 w:1)
+  /// Proof: `XodeStaking::UnbondingRequests` (`max_values`: None, `max_size`: Some(2761), added: 5236, mode: `MaxEncodedLen`)
   /// Storage: `XodeStaking::ProposedCandidates` (r:1 w:1)
-  /// Proof: `XodeStaking::ProposedCandidates` (`max_values`: Some(1), `max_size`: Some(7702), added: 8197, mode: `MaxEncodedLen`)
-  fn bond_correction() -> Weight {
+  /// Proof: `XodeStaking::ProposedCandidates` (`max_values`: None, `max_size`: Some(117), added: 2592, mode: `MaxEncodedLen`)
+  /// Storage: `XodeStaking::SortedCandidates` (r:1 w:1)
+  /// Proof: `XodeStaking::SortedCandidates` (`max_values`: Some(1), `max_size`: Some(5302), added: 5797, mode: `MaxEncodedLen`)
+  /// Storage: `XodeStaking::UnbondingAccounts` (r:0 w:1)
+  /// Proof: `XodeStaking::UnbondingAccounts` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+  fn decrease_delegation() -> Weight {
+    // Proof Size summary in bytes:
+    //  Measured:  `6450`
+    //  Estimated: `8407`
+    // Minimum execution time: 83_445_000 picoseconds.
+    Weight::from_parts(91_248_000, 8407)
+      .saturating_add(T::DbWeight::get().reads(7_u64))
+      .saturating_add(T::DbWeight::get().writes(7_u64))
+  }
+  /// Storage: `XodeStaking::ProposedCandidates` (r:2 w:2)
+  /// Proof: `XodeStaking::ProposedCandidates` (`max_values`: None, `max_size`: Some(117), added: 2592, mode: `MaxEncodedLen`)
+  /// Storage: `XodeStaking::Delegations` (r:2 w:2)
+  /// Proof: `XodeStaking::Delegations` (`max_values`: None, `max_size`: Some(4942), added: 7417, mode: `MaxEncodedLen`)
+  /// Storage: `XodeStaking::DelegatorState` (r:2 w:2)
+  /// Proof: `XodeStaking::DelegatorState` (`max_values`: None, `max_size`: Some(1001), added: 3476, mode: `MaxEncodedLen`)
+  /// Storage: `XodeStaking::RewardPerStake` (r:2 w:0)
+  /// Proof: `XodeStaking::RewardPerStake` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+  /// Storage: `XodeStaking::RewardCheckpoints` (r:3 w:3)
+  /// Proof: `XodeStaking::RewardCheckpoints` (`max_values`: None, `max_size`: Some(96), added: 2571, mode: `MaxEncodedLen`)
+  /// Storage: `XodeStaking::UnbondingRequests` (r:2 w:2)
+  /// Proof: `XodeStaking::UnbondingRequests` (`max_values`: None, `max_size`: Some(2761), added: 5236, mode: `MaxEncodedLen`)
+  /// Storage: `XodeStaking::SortedCandidates` (r:1 w:1)
+  /// Proof: `XodeStaking::SortedCandidates` (`max_values`: Some(1), `max_size`: Some(5302), added: 5797, mode: `MaxEncodedLen`)
+  /// Storage: `XodeStaking::UnbondingAccounts` (r:0 w:2)
+  /// Proof: `XodeStaking::UnbondingAccounts` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+  fn redelegate() -> Weight {
+    // Proof Size summary in bytes:
+    //  Measured:  `13268`
+    //  Estimated: `15824`
+    // Minimum execution time: 211_873_000 picoseconds.
+    Weight::from_parts(224_797_000, 15824)
+      .saturating_add(T::DbWeight::get().reads(14_u64))
+      .saturating_add(T::DbWeight::get().writes(14_u64))
+  }
+  /// Storage: `XodeStaking::ReleasingCursor` (r:1 w:1)
+  /// Proof: `XodeStaking::ReleasingCursor` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
+  /// Storage: `XodeStaking::ReleasingCandidates` (r:2 w:1)
+  /// Proof: `XodeStaking::ReleasingCandidates` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+  /// Storage: `XodeStaking::Delegations` (r:1 w:1)
+  /// Proof: `XodeStaking::Delegations` (`max_values`: None, `max_size`: Some(4942), added: 7417, mode: `MaxEncodedLen`)
+  /// Storage: `XodeStaking::UnbondingRequests` (r:1 w:1)
+  /// Proof: `XodeStaking::UnbondingRequests` (`max_values`: None, `max_size`: Some(2761), added: 5236, mode: `MaxEncodedLen`)
+  /// Storage: `XodeStaking::DelegatorState` (r:1 w:1)
+  /// Proof: `XodeStaking::DelegatorState` (`max_values`: None, `max_size`: Some(1001), added: 3476, mode: `MaxEncodedLen`)
+  /// Storage: `XodeStaking::RewardPerStake` (r:1 w:1)
+  /// Proof: `XodeStaking::RewardPerStake` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+  /// Storage: `XodeStaking::RewardCheckpoints` (r:1 w:1)
+  /// Proof: `XodeStaking::RewardCheckpoints` (`max_values`: None, `max_size`: Some(96), added: 2571, mode: `MaxEncodedLen`)
+  /// Storage: `XodeStaking::ProposedCandidates` (r:1 w:0)
+  /// Proof: `XodeStaking::ProposedCandidates` (`max_values`: None, `max_size`: Some(117), added: 2592, mode: `MaxEncodedLen`)
+  /// Storage: `XodeStaking::UnbondingAccounts` (r:0 w:1)
+  /// Proof: `XodeStaking::UnbondingAccounts` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+  fn release_delegation() -> Weight {
+    // Proof Size summary in bytes:
+    //  Measured:  `500`
+    //  Estimated: `8407`
+    // Minimum execution time: 114_803_000 picoseconds.
+    Weight::from_parts(129_909_000, 8407)
+      .saturating_add(T::DbWeight::get().reads(9_u64))
+      .saturating_add(T::DbWeight::get().writes(8_u64))
+  }
+  /// Storage: `XodeStaking::ProposedCandidates` (r:1 w:0)
+  /// Proof: `XodeStaking::ProposedCandidates` (`max_values`: None, `max_size`: Some(117), added: 2592, mode: `MaxEncodedLen`)
+  /// Storage: `Session::CurrentIndex` (r:1 w:0)
+  /// Proof: `Session::CurrentIndex` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+  /// Storage: `XodeStaking::PendingCommissions` (r:1 w:1)
+  /// Proof: `XodeStaking::PendingCommissions` (`max_values`: None, `max_size`: Some(45), added: 2520, mode: `MaxEncodedLen`)
+  /// Storage: `XodeStaking::CommissionChangeQueue` (r:0 w:1)
+  /// Proof: `XodeStaking::CommissionChangeQueue` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+  fn schedule_commission_change() -> Weight {
     // Proof Size summary in bytes:
-    //  Measured:  `185`
-    //  Estimated: `9187`
-    // Minimum execution time: 13_931_000 picoseconds.
-    Weight::from_parts(15_167_000, 9187)
+    //  Measured:  `402`
+    //  Estimated: `3582`
+    // Minimum execution time: 39_677_000 picoseconds.
+    Weight::from_parts(48_189_000, 3582)
+      .saturating_add(T::DbWeight::get().reads(3_u64))
+      .saturating_add(T::DbWeight::get().writes(2_u64))
+  }
+  /// Storage: `XodeStaking::ProposedCandidates` (r:1 w:0)
+  /// Proof: `XodeStaking::ProposedCandidates` (`max_values`: None, `max_size`: Some(117), added: 2592, mode: `MaxEncodedLen`)
+  /// Storage: `XodeStaking::CandidateMetadata` (r:1 w:1)
+  /// Proof: `XodeStaking::CandidateMetadata` (`max_values`: None, `max_size`: Some(320), added: 2795, mode: `MaxEncodedLen`)
+  /// Storage: `Balances::Holds` (r:1 w:1)
+  /// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(157), added: 2632, mode: `MaxEncodedLen`)
+  fn set_candidate_metadata() -> Weight {
+    // Proof Size summary in bytes:
+    //  Measured:  `179`
+    //  Estimated: `3785`
+    // Minimum execution time: 74_796_000 picoseconds.
+    Weight::from_parts(124_432_000, 3785)
+      .saturating_add(T::DbWeight::get().reads(3_u64))
+      .saturating_add(T::DbWeight::get().writes(2_u64))
+  }
+  /// Storage: `XodeStaking::Inflation` (r:0 w:1)
+  /// Proof: `XodeStaking::Inflation` (`max_values`: Some(1), `max_size`: Some(20), added: 515, mode: `MaxEncodedLen`)
+  fn set_inflation() -> Weight {
+    // Proof Size summary in bytes:
+    //  Measured:  `0`
+    //  Estimated: `0`
+    // Minimum execution time: 11_067_000 picoseconds.
+    Weight::from_parts(15_406_000, 0)
+      .saturating_add(T::DbWeight::get().writes(1_u64))
+  }
+  /// Storage: `XodeStaking::DelegatorState` (r:1 w:0)
+  /// Proof: `XodeStaking::DelegatorState` (`max_values`: None, `max_size`: Some(1001), added: 3476, mode: `MaxEncodedLen`)
+  /// Storage: `XodeStaking::RewardPerStake` (r:1 w:0)
+  /// Proof: `XodeStaking::RewardPerStake` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+  /// Storage: `XodeStaking::RewardCheckpoints` (r:1 w:1)
+  /// Proof: `XodeStaking::RewardCheckpoints` (`max_values`: None, `max_size`: Some(96), added: 2571, mode: `MaxEncodedLen`)
+  /// Storage: `XodeStaking::PendingRewards` (r:1 w:1)
+  /// Proof: `XodeStaking::PendingRewards` (`max_values`: None, `max_size`: Some(96), added: 2571, mode: `MaxEncodedLen`)
+  /// Storage: `System::Account` (r:1 w:1)
+  /// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+  fn claim_rewards() -> Weight {
+    // Proof Size summary in bytes:
+    //  Measured:  `600`
+    //  Estimated: `4466`
+    // Minimum execution time: 109_785_000 picoseconds.
+    Weight::from_parts(181_888_000, 4466)
+      .saturating_add(T::DbWeight::get().reads(5_u64))
+      .saturating_add(T::DbWeight::get().writes(3_u64))
+  }
+  /// Storage: `XodeStaking::Delegations` (r:1 w:1)
+  /// Proof: `XodeStaking::Delegations` (`max_values`: None, `max_size`: Some(4942), added: 7417, mode: `MaxEncodedLen`)
+  fn set_auto_compound() -> Weight {
+    // Proof Size summary in bytes:
+    //  Measured:  `302`
+    //  Estimated: `8407`
+    // Minimum execution time: 18_583_000 picoseconds.
+    Weight::from_parts(28_710_000, 8407)
       .saturating_add(T::DbWeight::get().reads(1_u64))
       .saturating_add(T::DbWeight::get().writes(1_u64))
   }
+  /// Storage: `Session::CurrentIndex` (r:1 w:0)
+  /// Proof: `Session::CurrentIndex` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+  /// Storage: `Aura::Authorities` (r:1 w:0)
+  /// Proof: `Aura::Authorities` (`max_values`: Some(1), `max_size`: Some(32002), added: 32497, mode: `MaxEncodedLen`)
+  /// Storage: `Session::Validators` (r:1 w:0)
+  /// Proof: `Session::Validators` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+  /// Storage: `XodeStaking::ReceivedHeartbeats` (r:1 w:1)
+  /// Proof: `XodeStaking::ReceivedHeartbeats` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+  fn heartbeat() -> Weight {
+    // Proof Size summary in bytes:
+    //  Measured:  `385`
+    //  Estimated: `33487`
+    // Minimum execution time: 93_421_000 picoseconds.
+    Weight::from_parts(109_204_000, 33487)
+      .saturating_add(T::DbWeight::get().reads(4_u64))
+      .saturating_add(T::DbWeight::get().writes(1_u64))
+  }
 }
 
 
 // For backwards compatibility and tests
 impl WeightInfo for () {
   /// Storage: `XodeStaking::ProposedCandidates` (r:1 w:1)
-  /// Proof: `XodeStaking::ProposedCandidates` (`max_values`: Some(1), `max_size`: Some(7702), added: 8197, mode: `MaxEncodedLen`)
+  /// Proof: `XodeStaking::ProposedCandidates` (`max_values`: None, `max_size`: Some(117), added: 2592, mode: `MaxEncodedLen`)
+  /// Storage: `XodeStaking::ReleasingCandidates` (r:1 w:0)
+  /// Proof: `XodeStaking::ReleasingCandidates` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+  /// Storage: `XodeStaking::SortedCandidates` (r:1 w:1)
+  /// Proof: `XodeStaking::SortedCandidates` (`max_values`: Some(1), `max_size`: Some(5302), added: 5797, mode: `MaxEncodedLen`)
   fn register_candidate() -> Weight {
     // Proof Size summary in bytes:
-    //  Measured:  `27`
-    //  Estimated: `9187`
-    // Minimum execution time: 9_422_000 picoseconds.
-    Weight::from_parts(9_795_000, 9187)
-      .saturating_add(RocksDbWeight::get().reads(1_u64))
-      .saturating_add(RocksDbWeight::get().writes(1_u64))
+    //  Measured:  `5927`
+    //  Estimated: `6787`
+    // Minimum execution time: 41_454_000 picoseconds.
+    Weight::from_parts(45_992_000, 6787)
+      .saturating_add(RocksDbWeight::get().reads(3_u64))
+      .saturating_add(RocksDbWeight::get().writes(2_u64))
   }
   /// Storage: `XodeStaking::ProposedCandidates` (r:1 w:1)
-  /// Proof: `XodeStaking::ProposedCandidates` (`max_values`: Some(1), `max_size`: Some(7702), added: 8197, mode: `MaxEncodedLen`)
+  /// Proof: `XodeStaking::ProposedCandidates` (`max_values`: None, `max_size`: Some(117), added: 2592, mode: `MaxEncodedLen`)
+  /// Storage: `Balances::Holds` (r:1 w:1)
+  /// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(157), added: 2632, mode: `MaxEncodedLen`)
+  /// Storage: `XodeStaking::SortedCandidates` (r:1 w:1)
+  /// Proof: `XodeStaking::SortedCandidates` (`max_values`: Some(1), `max_size`: Some(5302), added: 5797, mode: `MaxEncodedLen`)
   fn bond_candidate() -> Weight {
     // Proof Size summary in bytes:
-    //  Measured:  `185`
-    //  Estimated: `9187`
-    // Minimum execution time: 25_590_000 picoseconds.
-    Weight::from_parts(26_115_000, 9187)
-      .saturating_add(RocksDbWeight::get().reads(1_u64))
-      .saturating_add(RocksDbWeight::get().writes(1_u64))
+    //  Measured:  `6104`
+    //  Estimated: `6787`
+    // Minimum execution time: 91_292_000 picoseconds.
+    Weight::from_parts(152_187_000, 6787)
+      .saturating_add(RocksDbWeight::get().reads(3_u64))
+      .saturating_add(RocksDbWeight::get().writes(3_u64))
   }
   /// Storage: `XodeStaking::ProposedCandidates` (r:1 w:1)
-  /// Proof: `XodeStaking::ProposedCandidates` (`max_values`: Some(1), `max_size`: Some(7702), added: 8197, mode: `MaxEncodedLen`)
+  /// Proof: `XodeStaking::ProposedCandidates` (`max_values`: None, `max_size`: Some(117), added: 2592, mode: `MaxEncodedLen`)
+  /// Storage: `XodeStaking::SortedCandidates` (r:1 w:1)
+  /// Proof: `XodeStaking::SortedCandidates` (`max_values`: Some(1), `max_size`: Some(5302), added: 5797, mode: `MaxEncodedLen`)
   fn set_commission_of_candidate() -> Weight {
     // Proof Size summary in bytes:
-    //  Measured:  `185`
-    //  Estimated: `9187`
-    // Minimum execution time: 11_391_000 picoseconds.
-    Weight::from_parts(12_123_000, 9187)
-      .saturating_add(RocksDbWeight::get().reads(1_u64))
-      .saturating_add(RocksDbWeight::get().writes(1_u64))
+    //  Measured:  `6104`
+    //  Estimated: `6787`
+    // Minimum execution time: 53_051_000 picoseconds.
+    Weight::from_parts(58_764_000, 6787)
+      .saturating_add(RocksDbWeight::get().reads(2_u64))
+      .saturating_add(RocksDbWeight::get().writes(2_u64))
   }
   /// Storage: `XodeStaking::ProposedCandidates` (r:1 w:1)
-  /// Proof: `XodeStaking::ProposedCandidates` (`max_values`: Some(1), `max_size`: Some(7702), added: 8197, mode: `MaxEncodedLen`)
+  /// Proof: `XodeStaking::ProposedCandidates` (`max_values`: None, `max_size`: Some(117), added: 2592, mode: `MaxEncodedLen`)
+  /// Storage: `XodeStaking::SortedCandidates` (r:1 w:1)
+  /// Proof: `XodeStaking::SortedCandidates` (`max_values`: Some(1), `max_size`: Some(5302), added: 5797, mode: `MaxEncodedLen`)
   fn leave_candidate() -> Weight {
     // Proof Size summary in bytes:
-    //  Measured:  `219`
-    //  Estimated: `9187`
-    // Minimum execution time: 10_783_000 picoseconds.
-    Weight::from_parts(11_633_000, 9187)
-      .saturating_add(RocksDbWeight::get().reads(1_u64))
-      .saturating_add(RocksDbWeight::get().writes(1_u64))
+    //  Measured:  `6104`
+    //  Estimated: `6787`
+    // Minimum execution time: 33_512_000 picoseconds.
+    Weight::from_parts(37_257_000, 6787)
+      .saturating_add(RocksDbWeight::get().reads(2_u64))
+      .saturating_add(RocksDbWeight::get().writes(2_u64))
   }
   /// Storage: `XodeStaking::ProposedCandidates` (r:1 w:1)
-  /// Proof: `XodeStaking::ProposedCandidates` (`max_values`: Some(1), `max_size`: Some(7702), added: 8197, mode: `MaxEncodedLen`)
+  /// Proof: `XodeStaking::ProposedCandidates` (`max_values`: None, `max_size`: Some(117), added: 2592, mode: `MaxEncodedLen`)
+  /// Storage: `Balances::Holds` (r:1 w:1)
+  /// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(157), added: 2632, mode: `MaxEncodedLen`)
   /// Storage: `XodeStaking::Delegations` (r:1 w:1)
-  /// Proof: `XodeStaking::Delegations` (`max_values`: None, `max_size`: Some(4842), added: 7317, mode: `MaxEncodedLen`)
+  /// Proof: `XodeStaking::Delegations` (`max_values`: None, `max_size`: Some(4942), added: 7417, mode: `MaxEncodedLen`)
+  /// Storage: `XodeStaking::DelegatorState` (r:2 w:2)
+  /// Proof: `XodeStaking::DelegatorState` (`max_values`: None, `max_size`: Some(1001), added: 3476, mode: `MaxEncodedLen`)
+  /// Storage: `XodeStaking::RewardPerStake` (r:1 w:0)
+  /// Proof: `XodeStaking::RewardPerStake` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+  /// Storage: `XodeStaking::RewardCheckpoints` (r:2 w:2)
+  /// Proof: `XodeStaking::RewardCheckpoints` (`max_values`: None, `max_size`: Some(96), added: 2571, mode: `MaxEncodedLen`)
+  /// Storage: `XodeStaking::UnbondingRequests` (r:1 w:1)
+  /// Proof: `XodeStaking::UnbondingRequests` (`max_values`: None, `max_size`: Some(2761), added: 5236, mode: `MaxEncodedLen`)
+  /// Storage: `XodeStaking::SortedCandidates` (r:1 w:1)
+  /// Proof: `XodeStaking::SortedCandidates` (`max_values`: Some(1), `max_size`: Some(5302), added: 5797, mode: `MaxEncodedLen`)
+  /// Storage: `XodeStaking::UnbondingAccounts` (r:0 w:1)
+  /// Proof: `XodeStaking::UnbondingAccounts` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
   fn stake_candidate() -> Weight {
     // Proof Size summary in bytes:
-    //  Measured:  `108`
-    //  Estimated: `9187`
-    // Minimum execution time: 62_467_000 picoseconds.
-    Weight::from_parts(65_009_000, 9187)
-      .saturating_add(RocksDbWeight::get().reads(2_u64))
-      .saturating_add(RocksDbWeight::get().writes(2_u64))
+    //  Measured:  `13357`
+    //  Estimated: `8407`
+    // Minimum execution time: 224_461_000 picoseconds.
+    Weight::from_parts(236_952_000, 8407)
+      .saturating_add(RocksDbWeight::get().reads(10_u64))
+      .saturating_add(RocksDbWeight::get().writes(10_u64))
   }
   /// Storage: `XodeStaking::Delegations` (r:1 w:1)
-  /// Proof: `XodeStaking::Delegations` (`max_values`: None, `max_size`: Some(4842), added: 7317, mode: `MaxEncodedLen`)
+  /// Proof: `XodeStaking::Delegations` (`max_values`: None, `max_size`: Some(4942), added: 7417, mode: `MaxEncodedLen`)
+  /// Storage: `XodeStaking::DelegatorState` (r:1 w:1)
+  /// Proof: `XodeStaking::DelegatorState` (`max_values`: None, `max_size`: Some(1001), added: 3476, mode: `MaxEncodedLen`)
+  /// Storage: `XodeStaking::RewardPerStake` (r:1 w:0)
+  /// Proof: `XodeStaking::RewardPerStake` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+  /// Storage: `XodeStaking::RewardCheckpoints` (r:1 w:1)
+  /// Proof: `XodeStaking::RewardCheckpoints` (`max_values`: None, `max_size`: Some(96), added: 2571, mode: `MaxEncodedLen`)
+  /// Storage: `XodeStaking::UnbondingRequests` (r:1 w:1)
+  /// Proof: `XodeStaking::UnbondingRequests` (`max_values`: None, `max_size`: Some(2761), added: 5236, mode: `MaxEncodedLen`)
   /// Storage: `XodeStaking::ProposedCandidates` (r:1 w:1)
-  /// Proof: `XodeStaking::ProposedCandidates` (`max_values`: Some(1), `max_size`: Some(7702), added: 8197, mode: `MaxEncodedLen`)
+  /// Proof: `XodeStaking::ProposedCandidates` (`max_values`: None, `max_size`: Some(117), added: 2592, mode: `MaxEncodedLen`)
+  /// Storage: `XodeStaking::SortedCandidates` (r:1 w:1)
+  /// Proof: `XodeStaking::SortedCandidates` (`max_values`: Some(1), `max_size`: Some(5302), added: 5797, mode: `MaxEncodedLen`)
+  /// Storage: `XodeStaking::UnbondingAccounts` (r:0 w:1)
+  /// Proof: `XodeStaking::UnbondingAccounts` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
   fn unstake_candidate() -> Weight {
     // Proof Size summary in bytes:
-    //  Measured:  `300`
-    //  Estimated: `9187`
-    // Minimum execution time: 40_944_000 picoseconds.
-    Weight::from_parts(42_495_000, 9187)
+    //  Measured:  `6450`
+    //  Estimated: `8407`
+    // Minimum execution time: 87_651_000 picoseconds.
+    Weight::from_parts(136_291_000, 8407)
+      .saturating_add(RocksDbWeight::get().reads(7_u64))
+      .saturating_add(RocksDbWeight::get().writes(7_u64))
+  }
+  /// Storage: `XodeStaking::ProposedCandidates` (r:1 w:1)
+  /// Proof: `XodeStaking::ProposedCandidates` (`max_values`: None, `max_size`: Some(117), added: 2592, mode: `MaxEncodedLen`)
+  /// Storage: `XodeStaking::WaitingCandidates` (r:1 w:0)
+  /// Proof: `XodeStaking::WaitingCandidates` (`max_values`: Some(1), `max_size`: Some(3202), added: 3697, mode: `MaxEncodedLen`)
+  /// Storage: `XodeStaking::SortedCandidates` (r:1 w:1)
+  /// Proof: `XodeStaking::SortedCandidates` (`max_values`: Some(1), `max_size`: Some(5302), added: 5797, mode: `MaxEncodedLen`)
+  fn offline_candidate() -> Weight {
+    // Proof Size summary in bytes:
+    //  Measured:  `6105`
+    //  Estimated: `6787`
+    // Minimum execution time: 40_733_000 picoseconds.
+    Weight::from_parts(71_126_000, 6787)
+      .saturating_add(RocksDbWeight::get().reads(3_u64))
+      .saturating_add(RocksDbWeight::get().writes(2_u64))
+  }
+  /// Storage: `XodeStaking::ProposedCandidates` (r:1 w:1)
+  /// Proof: `XodeStaking::ProposedCandidates` (`max_values`: None, `max_size`: Some(117), added: 2592, mode: `MaxEncodedLen`)
+  /// Storage: `XodeStaking::SortedCandidates` (r:1 w:1)
+  /// Proof: `XodeStaking::SortedCandidates` (`max_values`: Some(1), `max_size`: Some(5302), added: 5797, mode: `MaxEncodedLen`)
+  fn online_candidate() -> Weight {
+    // Proof Size summary in bytes:
+    //  Measured:  `6051`
+    //  Estimated: `6787`
+    // Minimum execution time: 57_007_000 picoseconds.
+    Weight::from_parts(63_189_000, 6787)
       .saturating_add(RocksDbWeight::get().reads(2_u64))
       .saturating_add(RocksDbWeight::get().writes(2_u64))
   }
   /// Storage: `XodeStaking::ProposedCandidates` (r:1 w:1)
-  /// Proof: `XodeStaking::ProposedCandidates` (`max_values`: Some(1), `max_size`: Some(7702), added: 8197, mode: `MaxEncodedLen`)
-  fn offline_candidate() -> Weight {
+  /// Proof: `XodeStaking::ProposedCandidates` (`max_values`: None, `max_size`: Some(117), added: 2592, mode: `MaxEncodedLen`)
+  /// Storage: `XodeStaking::SortedCandidates` (r:1 w:1)
+  /// Proof: `XodeStaking::SortedCandidates` (`max_values`: Some(1), `max_size`: Some(5302), added: 5797, mode: `MaxEncodedLen`)
+  fn bond_correction() -> Weight {
+    // Proof Size summary in bytes:
+    //  Measured:  `6104`
+    //  Estimated: `6787`
+    // Minimum execution time: 33_798_000 picoseconds.
+    Weight::from_parts(39_840_000, 6787)
+      .saturating_add(RocksDbWeight::get().reads(2_u64))
+      .saturating_add(RocksDbWeight::get().writes(2_u64))
+  }
+  /// Storage: `XodeStaking::UnbondingRequests` (r:1 w:1)
+  /// Proof: `XodeStaking::UnbondingRequests` (`max_values`: None, `max_size`: Some(2761), added: 5236, mode: `MaxEncodedLen`)
+  /// Storage: `Balances::Holds` (r:1 w:1)
+  /// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(157), added: 2632, mode: `MaxEncodedLen`)
+  /// Storage: `XodeStaking::UnbondingAccounts` (r:0 w:32)
+  /// Proof: `XodeStaking::UnbondingAccounts` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+  fn withdraw_unbonded() -> Weight {
     // Proof Size summary in bytes:
-    //  Measured:  `108`
-    //  Estimated: `9187`
-    // Minimum execution time: 13_451_000 picoseconds.
-    Weight::from_parts(13_986_000, 9187)
+    //  Measured:  `1926`
+    //  Estimated: `6226`
+    // Minimum execution time: 171_325_000 picoseconds.
+    Weight::from_parts(180_705_000, 6226)
+      .saturating_add(RocksDbWeight::get().reads(2_u64))
+      .saturating_add(RocksDbWeight::get().writes(34_u64))
+  }
+  /// Storage: `XodeStaking::DesiredCandidates` (r:1 w:1)
+  /// Proof: `XodeStaking::DesiredCandidates` (`max_values`: Some(1), `max_size`: Some(3202), added: 3697, mode: `MaxEncodedLen`)
+  fn add_desired_candidate() -> Weight {
+    // Proof Size summary in bytes:
+    //  Measured:  `131`
+    //  Estimated: `4687`
+    // Minimum execution time: 25_937_000 picoseconds.
+    Weight::from_parts(28_703_000, 4687)
       .saturating_add(RocksDbWeight::get().reads(1_u64))
       .saturating_add(RocksDbWeight::get().writes(1_u64))
   }
-  /// Storage: `XodeStaking::ProposedCandidates` (r:1 w:1)
-  /// Proof: `XodeStaking::ProposedCandidates` (`max_values`: Some(1), `max_size`: Some(7702), added: 8197, mode: `MaxEncodedLen`)
-  fn online_candidate() -> Weight {
+  /// Storage: `XodeStaking::DesiredCandidates` (r:1 w:1)
+  /// Proof: `XodeStaking::DesiredCandidates` (`max_values`: Some(1), `max_size`: Some(3202), added: 3697, mode: `MaxEncodedLen`)
+  fn remove_desired_candidate() -> Weight {
+    // Proof Size summary in bytes:
+    //  Measured:  `163`
+    //  Estimated: `4687`
+    // Minimum execution time: 25_229_000 picoseconds.
+    Weight::from_parts(26_723_000, 4687)
+      .saturating_add(RocksDbWeight::get().reads(1_u64))
+      .saturating_add(RocksDbWeight::get().writes(1_u64))
+  }
+  /// Storage: `XodeStaking::DesiredCandidates` (r:1 w:1)
+  /// Proof: `XodeStaking::DesiredCandidates` (`max_values`: Some(1), `max_size`: Some(3202), added: 3697, mode: `MaxEncodedLen`)
+  fn swap_desired_candidate() -> Weight {
     // Proof Size summary in bytes:
-    //  Measured:  `108`
-    //  Estimated: `9187`
-    // Minimum execution time: 13_771_000 picoseconds.
-    Weight::from_parts(14_293_000, 9187)
+    //  Measured:  `163`
+    //  Estimated: `4687`
+    // Minimum execution time: 18_656_000 picoseconds.
+    Weight::from_parts(25_311_000, 4687)
       .saturating_add(RocksDbWeight::get().reads(1_u64))
       .saturating_add(RocksDbWeight::get().writes(1_u64))
   }
+  /// Storage: `XodeStaking::Delegations` (r:1 w:1)
+  /// Proof: `XodeStaking::Delegations` (`max_values`: None, `max_size`: Some(4942), added: 7417, mode: `MaxEncodedLen`)
+  /// Storage: `XodeStaking::DelegatorState` (r:1 w:1)
+  /// Proof: `XodeStaking::DelegatorState` (`max_values`: None, `max_size`: Some(1001), added: 3476, mode: `MaxEncodedLen`)
+  /// Storage: `XodeStaking::RewardPerStake` (r:1 w:0)
+  /// Proof: `XodeStaking::RewardPerStake` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+  /// Storage: `XodeStaking::RewardCheckpoints` (r:1 w:1)
+  /// Proof: `XodeStaking::RewardCheckpoints` (`max_values`: None, `max_size`: Some(96), added: 2571, mode: `MaxEncodedLen`)
+  /// Storage: `XodeStaking::UnbondingRequests` (r:1 w:1)
+  /// Proof: `XodeStaking::UnbondingRequests` (`max_values`: None, `max_size`: Some(2761), added: 5236, mode: `MaxEncodedLen`)
   /// Storage: `XodeStaking::ProposedCandidates` (r:1 w:1)
-  /// Proof: `XodeStaking::ProposedCandidates` (`max_values`: Some(1), `max_size`: Some(7702), added: 8197, mode: `MaxEncodedLen`)
-  fn bond_correction() -> Weight {
+  /// Proof: `XodeStaking::ProposedCandidates` (`max_values`: None, `max_size`: Some(117), added: 2592, mode: `MaxEncodedLen`)
+  /// Storage: `XodeStaking::SortedCandidates` (r:1 w:1)
+  /// Proof: `XodeStaking::SortedCandidates` (`max_values`: Some(1), `max_size`: Some(5302), added: 5797, mode: `MaxEncodedLen`)
+  /// Storage: `XodeStaking::UnbondingAccounts` (r:0 w:1)
+  /// Proof: `XodeStaking::UnbondingAccounts` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+  fn decrease_delegation() -> Weight {
+    // Proof Size summary in bytes:
+    //  Measured:  `6450`
+    //  Estimated: `8407`
+    // Minimum execution time: 83_445_000 picoseconds.
+    Weight::from_parts(91_248_000, 8407)
+      .saturating_add(RocksDbWeight::get().reads(7_u64))
+      .saturating_add(RocksDbWeight::get().writes(7_u64))
+  }
+  /// Storage: `XodeStaking::ProposedCandidates` (r:2 w:2)
+  /// Proof: `XodeStaking::ProposedCandidates` (`max_values`: None, `max_size`: Some(117), added: 2592, mode: `MaxEncodedLen`)
+  /// Storage: `XodeStaking::Delegations` (r:2 w:2)
+  /// Proof: `XodeStaking::Delegations` (`max_values`: None, `max_size`: Some(4942), added: 7417, mode: `MaxEncodedLen`)
+  /// Storage: `XodeStaking::DelegatorState` (r:2 w:2)
+  /// Proof: `XodeStaking::DelegatorState` (`max_values`: None, `max_size`: Some(1001), added: 3476, mode: `MaxEncodedLen`)
+  /// Storage: `XodeStaking::RewardPerStake` (r:2 w:0)
+  /// Proof: `XodeStaking::RewardPerStake` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+  /// Storage: `XodeStaking::RewardCheckpoints` (r:3 w:3)
+  /// Proof: `XodeStaking::RewardCheckpoints` (`max_values`: None, `max_size`: Some(96), added: 2571, mode: `MaxEncodedLen`)
+  /// Storage: `XodeStaking::UnbondingRequests` (r:2 w:2)
+  /// Proof: `XodeStaking::UnbondingRequests` (`max_values`: None, `max_size`: Some(2761), added: 5236, mode: `MaxEncodedLen`)
+  /// Storage: `XodeStaking::SortedCandidates` (r:1 w:1)
+  /// Proof: `XodeStaking::SortedCandidates` (`max_values`: Some(1), `max_size`: Some(5302), added: 5797, mode: `MaxEncodedLen`)
+  /// Storage: `XodeStaking::UnbondingAccounts` (r:0 w:2)
+  /// Proof: `XodeStaking::UnbondingAccounts` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+  fn redelegate() -> Weight {
+    // Proof Size summary in bytes:
+    //  Measured:  `13268`
+    //  Estimated: `15824`
+    // Minimum execution time: 211_873_000 picoseconds.
+    Weight::from_parts(224_797_000, 15824)
+      .saturating_add(RocksDbWeight::get().reads(14_u64))
+      .saturating_add(RocksDbWeight::get().writes(14_u64))
+  }
+  /// Storage: `XodeStaking::ReleasingCursor` (r:1 w:1)
+  /// Proof: `XodeStaking::ReleasingCursor` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
+  /// Storage: `XodeStaking::ReleasingCandidates` (r:2 w:1)
+  /// Proof: `XodeStaking::ReleasingCandidates` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+  /// Storage: `XodeStaking::Delegations` (r:1 w:1)
+  /// Proof: `XodeStaking::Delegations` (`max_values`: None, `max_size`: Some(4942), added: 7417, mode: `MaxEncodedLen`)
+  /// Storage: `XodeStaking::UnbondingRequests` (r:1 w:1)
+  /// Proof: `XodeStaking::UnbondingRequests` (`max_values`: None, `max_size`: Some(2761), added: 5236, mode: `MaxEncodedLen`)
+  /// Storage: `XodeStaking::DelegatorState` (r:1 w:1)
+  /// Proof: `XodeStaking::DelegatorState` (`max_values`: None, `max_size`: Some(1001), added: 3476, mode: `MaxEncodedLen`)
+  /// Storage: `XodeStaking::RewardPerStake` (r:1 w:1)
+  /// Proof: `XodeStaking::RewardPerStake` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+  /// Storage: `XodeStaking::RewardCheckpoints` (r:1 w:1)
+  /// Proof: `XodeStaking::RewardCheckpoints` (`max_values`: None, `max_size`: Some(96), added: 2571, mode: `MaxEncodedLen`)
+  /// Storage: `XodeStaking::ProposedCandidates` (r:1 w:0)
+  /// Proof: `XodeStaking::ProposedCandidates` (`max_values`: None, `max_size`: Some(117), added: 2592, mode: `MaxEncodedLen`)
+  /// Storage: `XodeStaking::UnbondingAccounts` (r:0 w:1)
+  /// Proof: `XodeStaking::UnbondingAccounts` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+  fn release_delegation() -> Weight {
+    // Proof Size summary in bytes:
+    //  Measured:  `500`
+    //  Estimated: `8407`
+    // Minimum execution time: 114_803_000 picoseconds.
+    Weight::from_parts(129_909_000, 8407)
+      .saturating_add(RocksDbWeight::get().reads(9_u64))
+      .saturating_add(RocksDbWeight::get().writes(8_u64))
+  }
+  /// Storage: `XodeStaking::ProposedCandidates` (r:1 w:0)
+  /// Proof: `XodeStaking::ProposedCandidates` (`max_values`: None, `max_size`: Some(117), added: 2592, mode: `MaxEncodedLen`)
+  /// Storage: `Session::CurrentIndex` (r:1 w:0)
+  /// Proof: `Session::CurrentIndex` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+  /// Storage: `XodeStaking::PendingCommissions` (r:1 w:1)
+  /// Proof: `XodeStaking::PendingCommissions` (`max_values`: None, `max_size`: Some(45), added: 2520, mode: `MaxEncodedLen`)
+  /// Storage: `XodeStaking::CommissionChangeQueue` (r:0 w:1)
+  /// Proof: `XodeStaking::CommissionChangeQueue` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+  fn schedule_commission_change() -> Weight {
     // Proof Size summary in bytes:
-    //  Measured:  `185`
-    //  Estimated: `9187`
-    // Minimum execution time: 13_931_000 picoseconds.
-    Weight::from_parts(15_167_000, 9187)
+    //  Measured:  `402`
+    //  Estimated: `3582`
+    // Minimum execution time: 39_677_000 picoseconds.
+    Weight::from_parts(48_189_000, 3582)
+      .saturating_add(RocksDbWeight::get().reads(3_u64))
+      .saturating_add(RocksDbWeight::get().writes(2_u64))
+  }
+  /// Storage: `XodeStaking::ProposedCandidates` (r:1 w:0)
+  /// Proof: `XodeStaking::ProposedCandidates` (`max_values`: None, `max_size`: Some(117), added: 2592, mode: `MaxEncodedLen`)
+  /// Storage: `XodeStaking::CandidateMetadata` (r:1 w:1)
+  /// Proof: `XodeStaking::CandidateMetadata` (`max_values`: None, `max_size`: Some(320), added: 2795, mode: `MaxEncodedLen`)
+  /// Storage: `Balances::Holds` (r:1 w:1)
+  /// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(157), added: 2632, mode: `MaxEncodedLen`)
+  fn set_candidate_metadata() -> Weight {
+    // Proof Size summary in bytes:
+    //  Measured:  `179`
+    //  Estimated: `3785`
+    // Minimum execution time: 74_796_000 picoseconds.
+    Weight::from_parts(124_432_000, 3785)
+      .saturating_add(RocksDbWeight::get().reads(3_u64))
+      .saturating_add(RocksDbWeight::get().writes(2_u64))
+  }
+  /// Storage: `XodeStaking::Inflation` (r:0 w:1)
+  /// Proof: `XodeStaking::Inflation` (`max_values`: Some(1), `max_size`: Some(20), added: 515, mode: `MaxEncodedLen`)
+  fn set_inflation() -> Weight {
+    // Proof Size summary in bytes:
+    //  Measured:  `0`
+    //  Estimated: `0`
+    // Minimum execution time: 11_067_000 picoseconds.
+    Weight::from_parts(15_406_000, 0)
+      .saturating_add(RocksDbWeight::get().writes(1_u64))
+  }
+  /// Storage: `XodeStaking::DelegatorState` (r:1 w:0)
+  /// Proof: `XodeStaking::DelegatorState` (`max_values`: None, `max_size`: Some(1001), added: 3476, mode: `MaxEncodedLen`)
+  /// Storage: `XodeStaking::RewardPerStake` (r:1 w:0)
+  /// Proof: `XodeStaking::RewardPerStake` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+  /// Storage: `XodeStaking::RewardCheckpoints` (r:1 w:1)
+  /// Proof: `XodeStaking::RewardCheckpoints` (`max_values`: None, `max_size`: Some(96), added: 2571, mode: `MaxEncodedLen`)
+  /// Storage: `XodeStaking::PendingRewards` (r:1 w:1)
+  /// Proof: `XodeStaking::PendingRewards` (`max_values`: None, `max_size`: Some(96), added: 2571, mode: `MaxEncodedLen`)
+  /// Storage: `System::Account` (r:1 w:1)
+  /// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+  fn claim_rewards() -> Weight {
+    // Proof Size summary in bytes:
+    //  Measured:  `600`
+    //  Estimated: `4466`
+    // Minimum execution time: 109_785_000 picoseconds.
+    Weight::from_parts(181_888_000, 4466)
+      .saturating_add(RocksDbWeight::get().reads(5_u64))
+      .saturating_add(RocksDbWeight::get().writes(3_u64))
+  }
+  /// Storage: `XodeStaking::Delegations` (r:1 w:1)
+  /// Proof: `XodeStaking::Delegations` (`max_values`: None, `max_size`: Some(4942), added: 7417, mode: `MaxEncodedLen`)
+  fn set_auto_compound() -> Weight {
+    // Proof Size summary in bytes:
+    //  Measured:  `302`
+    //  Estimated: `8407`
+    // Minimum execution time: 18_583_000 picoseconds.
+    Weight::from_parts(28_710_000, 8407)
       .saturating_add(RocksDbWeight::get().reads(1_u64))
       .saturating_add(RocksDbWeight::get().writes(1_u64))
   }
+  /// Storage: `Session::CurrentIndex` (r:1 w:0)
+  /// Proof: `Session::CurrentIndex` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+  /// Storage: `Aura::Authorities` (r:1 w:0)
+  /// Proof: `Aura::Authorities` (`max_values`: Some(1), `max_size`: Some(32002), added: 32497, mode: `MaxEncodedLen`)
+  /// Storage: `Session::Validators` (r:1 w:0)
+  /// Proof: `Session::Validators` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+  /// Storage: `XodeStaking::ReceivedHeartbeats` (r:1 w:1)
+  /// Proof: `XodeStaking::ReceivedHeartbeats` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+  fn heartbeat() -> Weight {
+    // Proof Size summary in bytes:
+    //  Measured:  `385`
+    //  Estimated: `33487`
+    // Minimum execution time: 93_421_000 picoseconds.
+    Weight::from_parts(109_204_000, 33487)
+      .saturating_add(RocksDbWeight::get().reads(4_u64))
+      .saturating_add(RocksDbWeight::get().writes(1_u64))
+  }
 }
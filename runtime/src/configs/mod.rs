@@ -56,11 +56,14 @@ use polkadot_runtime_common::{
 };
 use sp_consensus_aura::sr25519::AuthorityId as AuraId;
 use sp_runtime:: {
-	Perbill, Percent,
-	traits::{ AccountIdConversion, Zero },
+	Perbill,
+	traits::AccountIdConversion,
+	transaction_validity::TransactionPriority,
 };
 use sp_version::RuntimeVersion;
 use xcm::latest::prelude::BodyId;
+use alloc::{vec, vec::Vec};
+use hex_literal::hex;
 use pallet_collective::{EnsureProportionAtLeast, EnsureProportionMoreThan};
 
 
@@ -70,7 +73,7 @@ use super::{
 	AccountId, Aura, Balance, Balances, Block, BlockNumber, CollatorSelection, ConsensusHook, Hash,
 	MessageQueue, Nonce, PalletInfo, ParachainSystem, Runtime, RuntimeCall, RuntimeEvent,
 	RuntimeFreezeReason, RuntimeHoldReason, RuntimeOrigin, RuntimeTask, Session, SessionKeys, OriginCaller, 
-	System, WeightToFee, XcmpQueue, AVERAGE_ON_INITIALIZE_RATIO, EXISTENTIAL_DEPOSIT, DAYS, HOURS, MINUTES,
+	System, UncheckedExtrinsic, WeightToFee, XcmpQueue, AVERAGE_ON_INITIALIZE_RATIO, EXISTENTIAL_DEPOSIT, DAYS, HOURS,
 	MAXIMUM_BLOCK_WEIGHT, UNIT, MICRO_UNIT, NORMAL_DISPATCH_RATIO, SLOT_DURATION, VERSION,
 	// Governance
 	TechnicalCommittee, TreasuryCouncil,
@@ -193,7 +196,7 @@ impl pallet_balances::Config for Runtime {
 pub struct ToAuthor<R>(core::marker::PhantomData<R>);
 impl<R> OnUnbalanced<Credit<R::AccountId, pallet_balances::Pallet<R>>> for ToAuthor<R>
 where
-    R: pallet_balances::Config + pallet_authorship::Config + pallet_xode_staking::Config,
+    R: pallet_balances::Config + pallet_authorship::Config + pallet_treasury::Config + pallet_xode_staking::Config<StakingCurrency = pallet_balances::Pallet<R>>,
     <R as frame_system::Config>::AccountId: From<AccountId>,
     <R as frame_system::Config>::AccountId: Into<AccountId>,
 {
@@ -202,47 +205,24 @@ where
     ) {
         if let Some(author) = <pallet_authorship::Pallet<R>>::author() {
 
-			if let Some(candidate) = pallet_xode_staking::ProposedCandidates::<R>::get().iter().find(|c| c.who == author) {
-
-				// commission of the delegator
-				let commission = Percent::from_percent(candidate.commission.into());
-				if commission.deconstruct() > 0 {
-
-					if let Some(delegations) = pallet_xode_staking::Delegations::<R>::get(&author) {
-						
-						// remaining amount to be shared
-						let mut remaining_amount = amount;
-
-						// distribute only to delegator with stake > 0
-						for (_index, delegation) in delegations.iter().enumerate() {
-							if delegation.stake > Zero::zero() {
-								
-								// stake / total_stake
-								let delegator_share_ratio = Percent::from_rational(delegation.stake, candidate.total_stake);
-
-								// delegator_share_amount (ds)
-								let ds1 = Perbill::from_percent(delegator_share_ratio.deconstruct() as u32).mul_ceil(remaining_amount.peek());
-								let ds2 = Perbill::from_percent(commission.deconstruct() as u32).mul_ceil(ds1);
-
-								// extract the delegator_share_amount (share) from the remaining amount, then resolve it.
-								let (share, leftover) = remaining_amount.split(ds2.into());
-								let _ = <pallet_balances::Pallet<R>>::resolve(&delegation.delegator,share,);
-
-								// the remaining amount (left-over) will be given to the next delegator.
-								remaining_amount = leftover; 
-							}
-						}
-
-						// after all its delegator has been paid, the remaining amount will given to the author
-						let _ = <pallet_balances::Pallet<R>>::resolve(&author, remaining_amount);
-					}
-				} else {
-					// if the commission is zero, the author will not share
-					let _ = <pallet_balances::Pallet<R>>::resolve(&author, amount);
-				}
-			} else {
-				// if there is no delegator the author will get everything
-				let _ = <pallet_balances::Pallet<R>>::resolve(&author, amount);
+			// the delegators' portion (commission) is kept in the staking pot and claimed later
+			let portion = pallet_xode_staking::Pallet::<R>::delegators_portion(author.clone(), amount.peek());
+			let (shared, remaining_amount) = amount.split(portion);
+
+			let remaining_amount = match <pallet_balances::Pallet<R>>::resolve(&pallet_xode_staking::Pallet::<R>::account_id(), shared) {
+				Ok(()) => {
+					// accumulate the reward per stake of the author, constant regardless of the delegators
+					pallet_xode_staking::Pallet::<R>::accumulate_rewards(author.clone(), portion);
+					remaining_amount
+				},
+				// if the pot can not receive the shared amount the author will get everything
+				Err(shared) => remaining_amount.merge(shared),
+			};
+
+			// the remaining amount will be given to the author, if the author can not receive it (e.g.
+			// below the existential deposit) it goes to the treasury instead of being dropped
+			if let Err(remaining_amount) = <pallet_balances::Pallet<R>>::resolve(&author, remaining_amount) {
+				ResolveTo::<pallet_treasury::TreasuryAccountId<R>, pallet_balances::Pallet<R>>::on_unbalanced(remaining_amount);
 			}
         }
     }
@@ -254,7 +234,7 @@ pub const AUTHOR_SHARE: u32 = 80;
 pub struct DealWithFees<R>(core::marker::PhantomData<R>);
 impl<R> OnUnbalanced<Credit<R::AccountId, pallet_balances::Pallet<R>>> for DealWithFees<R>
 where
-	R: pallet_balances::Config + pallet_authorship::Config + pallet_treasury::Config + pallet_xode_staking::Config,
+	R: pallet_balances::Config + pallet_authorship::Config + pallet_treasury::Config + pallet_xode_staking::Config<StakingCurrency = pallet_balances::Pallet<R>>,
     <R as frame_system::Config>::AccountId: From<AccountId>,
     <R as frame_system::Config>::AccountId: Into<AccountId>,
 {
@@ -711,38 +691,84 @@ impl pallet_whitelist::Config for Runtime {
 /// ============
 parameter_types! {
 	pub const XodeStakingPalletId: PalletId = PalletId(*b"xd/stkng");
-	pub const MaxStalingPeriod: BlockNumber = MINUTES * 2; 
 	pub const MaxProposedCandidates: u32 = 100;
 	pub const MaxProposedCandidateDelegates: u32 = 100;
+	pub const MaxDelegationsPerDelegator: u32 = 20;
+	pub const MinDelegation: Balance = 100 * UNIT;
+	pub const MaxDelegationReleasesPerBlock: u32 = 32;
 	pub const MinProposedCandidateBond: Balance = 10_000 * UNIT;
-	pub const Nodes: &'static [&'static str] = &[
-		"0xe4340f4ced8ec17fd3c81bd0db4915cd2fc2eec87ade3583055ed7b274eb481b",
-		"0x2e38a92f3f9ca93a9f80df3745abfa698607f89235e817f9b766a34e89c0d06d",
-		"0x2871b4504a9e2c302d5f591b0a510d5fd9134dd8dbd141c91f366d7510e61309",
-		"0x72bed6c1b43998ebdb4f1460760cfd1d89512713e444e4a29d7d8e1e1307ad77",
-		"0xa8adc02652304a63002471f12ebeba61c6ba74b156be32c7f8e373983ee5dd56",  
-		"0x5c39ae0088c2244cef982148e0e5acc9a6bc10d3d4d81cdb7b20240951bc4253", 
-		"0xe8e73bb34c9394c31da71d91dbb237a0878a6e4e8755d8957de6be7215c94742", 
-		"0x48402d5c5330f3c24b9d7fd86688b6dcaa0d60f301c0c3c40dc86a67f80eea0e", 
-		"0x98ea2acefa92fb943c27bb751e40c9a3f400c045e9266fac3d410936403ba636", 
-		"0x10c7110da5e94ce09d08dd800c5d930538fa1183d518fdf20ab78d68d056a705", 
-		"0xeac97de954eb1a9f8f5b400291e0e666930ed56e5bd29d003e6ad374ff7b411a", 
-		"0x38abe047d49830936591aece873c94c8a5cb59c5f66ee2c304b2a2a923b5a60d", 
-		"0x8cc71d95e8404c16fb63f152f89ed9349727019eb92019e0030fdbb562a0c414", 
-		"0x3419ef403858b6ec86207595bcad0994bd830c82f841978f831a97240ccb9827", 
+	// Four sessions of six hours (one day)
+	pub const UnbondingDelay: u32 = 4;
+	// Four sessions of six hours (one day), at most two percent per session
+	pub const CommissionChangeDelay: u32 = 4;
+	pub const MaxCommissionChangePerSession: u8 = 2;
+	pub const MaxUnbondingRequests: u32 = 32;
+	pub const SlashFraction: Perbill = Perbill::from_percent(5);
+	// Twenty eight sessions of six hours (one week)
+	pub const SlashOffencesResetPeriod: u32 = 28;
+	pub const CandidateMetadataDepositBase: Balance = deposit(1, 0);
+	pub const CandidateMetadataDepositPerByte: Balance = deposit(0, 1);
+	pub const MaxCandidateMetadataLength: u32 = 64;
+	pub const BlocksPerYear: u32 = DAYS * 365;
+	// Twenty eight sessions of six hours (one week)
+	pub const AuthoredBlocksHistoryDepth: u32 = 28;
+	// A third of the slots of a collator in a six hours session of twelve collators
+	pub const MaxMissedSlots: u32 = 100;
+	pub const HeartbeatPriority: TransactionPriority = TransactionPriority::MAX;
+	// The Xaver nodes previously hard-coded in the runtime (Aura keys), seeded by the v5 migration
+	pub XaverNodes: Vec<AccountId> = vec![
+		AccountId::from(hex!("e4340f4ced8ec17fd3c81bd0db4915cd2fc2eec87ade3583055ed7b274eb481b")),
+		AccountId::from(hex!("2e38a92f3f9ca93a9f80df3745abfa698607f89235e817f9b766a34e89c0d06d")),
+		AccountId::from(hex!("2871b4504a9e2c302d5f591b0a510d5fd9134dd8dbd141c91f366d7510e61309")),
+		AccountId::from(hex!("72bed6c1b43998ebdb4f1460760cfd1d89512713e444e4a29d7d8e1e1307ad77")),
+		AccountId::from(hex!("a8adc02652304a63002471f12ebeba61c6ba74b156be32c7f8e373983ee5dd56")),
+		AccountId::from(hex!("5c39ae0088c2244cef982148e0e5acc9a6bc10d3d4d81cdb7b20240951bc4253")),
+		AccountId::from(hex!("e8e73bb34c9394c31da71d91dbb237a0878a6e4e8755d8957de6be7215c94742")),
+		AccountId::from(hex!("48402d5c5330f3c24b9d7fd86688b6dcaa0d60f301c0c3c40dc86a67f80eea0e")),
+		AccountId::from(hex!("98ea2acefa92fb943c27bb751e40c9a3f400c045e9266fac3d410936403ba636")),
+		AccountId::from(hex!("10c7110da5e94ce09d08dd800c5d930538fa1183d518fdf20ab78d68d056a705")),
+		AccountId::from(hex!("eac97de954eb1a9f8f5b400291e0e666930ed56e5bd29d003e6ad374ff7b411a")),
+		AccountId::from(hex!("38abe047d49830936591aece873c94c8a5cb59c5f66ee2c304b2a2a923b5a60d")),
+		AccountId::from(hex!("8cc71d95e8404c16fb63f152f89ed9349727019eb92019e0030fdbb562a0c414")),
+		AccountId::from(hex!("3419ef403858b6ec86207595bcad0994bd830c82f841978f831a97240ccb9827")),
 	];
 }
 
+impl<C> frame_system::offchain::SendTransactionTypes<C> for Runtime
+where
+	RuntimeCall: From<C>,
+{
+	type OverarchingCall = RuntimeCall;
+	type Extrinsic = UncheckedExtrinsic;
+}
+
 impl pallet_xode_staking::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type WeightInfo = pallet_xode_staking::weights::SubstrateWeight<Runtime>;
 	type MaxProposedCandidates = MaxProposedCandidates;  
 	type MaxProposedCandidateDelegates = MaxProposedCandidateDelegates;  
-	type XaverNodes = Nodes;
+	type MaxDelegationsPerDelegator = MaxDelegationsPerDelegator;
+	type MinDelegation = MinDelegation;
+	type MaxDelegationReleasesPerBlock = MaxDelegationReleasesPerBlock;
+	type RuntimeHoldReason = RuntimeHoldReason;
 	type StakingCurrency = Balances;
 	type PalletId = XodeStakingPalletId;
-	type MaxStalingPeriod = MaxStalingPeriod;
+	type MaxMissedSlots = MaxMissedSlots;
 	type MinProposedCandidateBond = MinProposedCandidateBond;
+	type UnbondingDelay = UnbondingDelay;
+	type CommissionChangeDelay = CommissionChangeDelay;
+	type MaxCommissionChangePerSession = MaxCommissionChangePerSession;
+	type MaxUnbondingRequests = MaxUnbondingRequests;
+	type SlashFraction = SlashFraction;
+	type SlashOffencesResetPeriod = SlashOffencesResetPeriod;
+	type MetadataDepositBase = CandidateMetadataDepositBase;
+	type MetadataDepositPerByte = CandidateMetadataDepositPerByte;
+	type MaxMetadataLength = MaxCandidateMetadataLength;
+	type BlocksPerYear = BlocksPerYear;
+	type AuthoredBlocksHistoryDepth = AuthoredBlocksHistoryDepth;
+	type OnSlash = ResolveTo<pallet_treasury::TreasuryAccountId<Runtime>, Balances>;
+	type UpdateOrigin = EnsureTwoThirdsTechnicalCommittee;
+	type HeartbeatPriority = HeartbeatPriority;
 }
 
 /// =======
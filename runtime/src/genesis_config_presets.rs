@@ -6,6 +6,8 @@ use crate::{
 	// Membership - Technical council (sudo replacement) and Treasury council
 	TechnicalCommitteeMembershipConfig,configs::TechnicalMembershipMaxMembers,
 	TreasuryCouncilMembershipConfig,configs::TreasuryMembershipMaxMembers,
+	// Staking Xode
+	Balance, XodeStakingConfig, UNIT,
 };
 use alloc::{vec, vec::Vec};
 use parachains_common::{genesis_config_helpers::*, AuraId};
@@ -29,6 +31,8 @@ fn testnet_genesis(
 	endowed_accounts: Vec<AccountId>,
 	technical_committee_members: Vec<AccountId>,
 	treasury_council_members: Vec<AccountId>,
+	proposed_candidates: Vec<(AccountId, Balance, u8)>,
+	delegations: Vec<(AccountId, AccountId, Balance)>,
 	id: ParaId,
 ) -> Value {
 
@@ -58,6 +62,12 @@ fn testnet_genesis(
 			candidacy_bond: EXISTENTIAL_DEPOSIT * 16,
 			..Default::default()
 		},
+		xode_staking: XodeStakingConfig {
+			// The invulnerables are the Xaver nodes (desired candidates)
+			desired_candidates: invulnerables.iter().cloned().map(|(acc, _)| acc).collect::<Vec<_>>(),
+			proposed_candidates,
+			delegations,
+		},
 		session: SessionConfig {
 			keys: invulnerables
 				.into_iter()
@@ -126,6 +136,18 @@ fn local_testnet_genesis() -> Value {
 			get_account_id_from_seed::<sr25519::Public>("Eve"),
 			get_account_id_from_seed::<sr25519::Public>("Ferdie"),
 		],
+		// proposed candidates (account, bond, commission)
+		vec![
+			(get_account_id_from_seed::<sr25519::Public>("Charlie"), 20_000 * UNIT, 10),
+		],
+		// delegations (delegator, candidate, stake)
+		vec![
+			(
+				get_account_id_from_seed::<sr25519::Public>("Dave"),
+				get_account_id_from_seed::<sr25519::Public>("Charlie"),
+				1_000 * UNIT,
+			),
+		],
 		4607.into(),
 	)
 }
@@ -167,6 +189,8 @@ fn development_config_genesis() -> Value {
 			get_account_id_from_seed::<sr25519::Public>("Eve"),
 			get_account_id_from_seed::<sr25519::Public>("Ferdie"),
 		],
+		vec![],
+		vec![],
 		4607.into(),
 	)
 }
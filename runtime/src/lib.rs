@@ -161,7 +161,13 @@ pub type UncheckedExtrinsic =
 ///
 /// This can be a tuple of types, each implementing `OnRuntimeUpgrade`.
 #[allow(unused_parens)]
-type Migrations = ();
+type Migrations = (
+	pallet_xode_staking::migrations::v1::MigrateToV1<Runtime, Balances>,
+	pallet_xode_staking::migrations::v2::MigrateToV2<Runtime>,
+	pallet_xode_staking::migrations::v3::MigrateToV3<Runtime>,
+	pallet_xode_staking::migrations::v4::MigrateToV4<Runtime>,
+	pallet_xode_staking::migrations::v5::MigrateToV5<Runtime, configs::XaverNodes>,
+);
 
 /// Executive: handles dispatch to the various modules.
 pub type Executive = frame_executive::Executive<
@@ -234,10 +240,10 @@ pub const VERSION: RuntimeVersion = RuntimeVersion {
 	spec_name: create_runtime_str!("xode-runtime"),
 	impl_name: create_runtime_str!("xode-runtime"),
 	authoring_version: 1,
-	spec_version: 12,
+	spec_version: 13,
 	impl_version: 0,
 	apis: RUNTIME_API_VERSIONS,
-	transaction_version: 1,
+	transaction_version: 2,
 	state_version: 1,
 };
 
@@ -548,6 +554,40 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl pallet_xode_staking::runtime_api::XodeStakingApi<Block, AccountId, Balance, BlockNumber> for Runtime {
+		fn candidates() -> Vec<pallet_xode_staking::CandidateSummary<AccountId, Balance, BlockNumber>> {
+			XodeStaking::candidate_summaries()
+		}
+
+		fn delegations_of(delegator: AccountId) -> Vec<(AccountId, Balance)> {
+			XodeStaking::delegations_of(delegator)
+		}
+
+		fn session_authors() -> Vec<AccountId> {
+			XodeStaking::session_authors()
+		}
+
+		fn waiting_candidates() -> Vec<AccountId> {
+			pallet_xode_staking::WaitingCandidates::<Runtime>::get().into_inner()
+		}
+
+		fn actual_authors() -> Vec<AccountId> {
+			pallet_xode_staking::ActualAuthors::<Runtime>::get().into_inner()
+		}
+
+		fn estimate_delegator_share(candidate: AccountId, delegator: AccountId, amount: Balance) -> Balance {
+			XodeStaking::delegator_share(candidate, delegator, amount)
+		}
+
+		fn authored_blocks_of(who: AccountId) -> Vec<(u32, u32)> {
+			XodeStaking::authored_blocks_of(who)
+		}
+
+		fn session_authored_blocks(session: u32) -> Vec<(AccountId, u32)> {
+			XodeStaking::session_authored_blocks(session)
+		}
+	}
+
 	impl pallet_transaction_payment_rpc_runtime_api::TransactionPaymentApi<Block, Balance> for Runtime {
 		fn query_info(
 			uxt: <Block as BlockT>::Extrinsic,